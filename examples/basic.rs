@@ -5,7 +5,7 @@ use bevy_cursor::prelude::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, TrackCursorPlugin))
+        .add_plugins((DefaultPlugins, TrackCursorPlugin::default()))
         .add_systems(Startup, setup)
         .add_systems(Update, print_cursor_location)
         .run();
@@ -20,7 +20,7 @@ fn main() {
             exit_condition: ExitCondition::OnPrimaryClosed,
             ..default()
         }))
-        .add_plugins(TrackCursorPlugin)
+        .add_plugins(TrackCursorPlugin::default())
         //
         .add_systems(Startup, setup)
         .add_systems(Update, set_camera_viewports)
@@ -0,0 +1,182 @@
+//! Arbitration between the mouse and the external pointers.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::stamp::LocationClock;
+use crate::{CameraFilter, CursorLocation, ExternalPointerLocations, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that defines which pointer the [`CursorLocation`] reports when the
+/// mouse and some [`ExternalPointers`] coexist, like a virtual cursor driven by a
+/// gamepad.
+///
+/// Without this resource, the pointers are tracked separately: the
+/// [`CursorLocation`] reports the mouse and the [`ExternalPointerLocations`]
+/// report the external pointers.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// const GAMEPAD_CURSOR: u64 = 0;
+///
+/// fn setup(mut commands: Commands) {
+///     // The gamepad cursor and the mouse take over each other when they move.
+///     commands.insert_resource(CursorArbitration::LastActive);
+/// }
+///
+/// fn move_gamepad_cursor(mut pointers: ResMut<ExternalPointers>) {
+///     pointers.set(GAMEPAD_CURSOR, ExternalPointer::Window(Vec2::new(100.0, 50.0)));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(move_gamepad_cursor);
+/// ```
+///
+/// [`ExternalPointers`]: crate::ExternalPointers
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(Resource, Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorArbitration {
+    /// The [`CursorLocation`] reports the mouse, and the external pointers are only
+    /// reported by the [`ExternalPointerLocations`].
+    #[default]
+    Separate,
+    /// The [`CursorLocation`] reports the pointer that moved last.
+    ///
+    /// The mouse wins when several pointers move in the same frame.
+    LastActive,
+    /// The [`CursorLocation`] always reports the given pointer, even if it has no
+    /// location while the others have one.
+    Primary(CursorSource),
+}
+
+/// A pointer that can be reported by the [`CursorLocation`], see [`CursorArbitration`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorSource {
+    /// The mouse cursor of the window.
+    #[default]
+    Mouse,
+    /// A pointer of the [`ExternalPointers`], by its id.
+    ///
+    /// [`ExternalPointers`]: crate::ExternalPointers
+    External(u64),
+}
+
+/// The state of the arbitration of a [`CursorLocation<F>`].
+#[derive(Resource)]
+pub(crate) struct ArbitrationState<F: CameraFilter> {
+    /// Whether the [`CursorLocation`] may report another pointer than the mouse.
+    active: bool,
+    /// The location of the mouse.
+    mouse: Option<Location>,
+    /// The pointer that moved last.
+    last_active: CursorSource,
+    /// The location reported by the [`CursorLocation`].
+    reported: Option<Location>,
+    marker: PhantomData<fn() -> F>,
+}
+
+impl<F: CameraFilter> Default for ArbitrationState<F> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            active: false,
+            mouse: None,
+            last_active: CursorSource::Mouse,
+            reported: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Puts back the location of the mouse in the [`CursorLocation`], so it is resolved
+/// from its own previous location.
+pub(crate) fn restore_mouse_location<F: CameraFilter>(
+    state: Res<ArbitrationState<F>>,
+    mut cursor: ResMut<CursorLocation<F>>,
+) {
+    if state.active {
+        cursor.bypass_change_detection().0 = state.mouse.clone();
+    }
+}
+
+/// Replaces the location of the [`CursorLocation`] by the one of the pointer
+/// selected by the [`CursorArbitration`].
+pub(crate) fn arbitrate_cursor_location<F: CameraFilter>(
+    arbitration: Option<Res<CursorArbitration>>,
+    external: Res<ExternalPointerLocations<F>>,
+    clock: LocationClock,
+    mut state: ResMut<ArbitrationState<F>>,
+    mut cursor: ResMut<CursorLocation<F>>,
+) {
+    let arbitration = arbitration.map_or(CursorArbitration::Separate, |arbitration| *arbitration);
+
+    if arbitration == CursorArbitration::Separate {
+        if state.active {
+            *state = ArbitrationState::default();
+            cursor.set_changed();
+        }
+        return;
+    }
+
+    let mouse = cursor.0.clone();
+    let (_, frame) = clock.now();
+
+    // The mouse moved if it's on another window or at another position, and an
+    // external pointer moved if its location has been stamped this frame.
+    // Note: the other fields of the location change when the camera moves.
+    let pointer = |location: &Location| (location.window, location.raw_position);
+    let mouse_moved = mouse
+        .as_ref()
+        .map(pointer)
+        .is_some_and(|current| state.mouse.as_ref().map(pointer) != Some(current));
+
+    if mouse_moved {
+        state.last_active = CursorSource::Mouse;
+    } else if let Some((id, _)) = external
+        .iter()
+        .find(|(_, location)| location.frame == frame && !location.is_stale)
+    {
+        state.last_active = CursorSource::External(id);
+    }
+
+    let source = match arbitration {
+        CursorArbitration::Separate => CursorSource::Mouse,
+        CursorArbitration::LastActive => state.last_active,
+        CursorArbitration::Primary(source) => source,
+    };
+
+    let reported = match source {
+        CursorSource::Mouse => mouse.clone(),
+        CursorSource::External(id) => external.get(id).cloned(),
+    };
+
+    state.active = true;
+    state.mouse = mouse;
+
+    if state.reported != reported {
+        state.reported = reported.clone();
+        cursor.0 = reported;
+    } else {
+        cursor.bypass_change_detection().0 = reported;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
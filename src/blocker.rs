@@ -0,0 +1,91 @@
+//! Nodes that block the cursor interactions beneath them.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::ui::DefaultUiCamera;
+use bevy::window::PrimaryWindow;
+
+use crate::derived::LocationsMut;
+use crate::ui::{ui_node_location, NodeQuery};
+use crate::{CameraFilter, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A marker component for the `bevy_ui` nodes that block the cursor interactions
+/// beneath them, like a modal panel.
+///
+/// The [`HoveredUiNode`] and the [`UiHoverStack`] don't resolve the nodes beneath a
+/// blocker, and the [`Location::blocked`] flag tells whether the cursor is over a
+/// blocker, so the world interactions can be ignored.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn spawn_modal(mut commands: Commands) {
+///     commands.spawn((Node::default(), CursorBlocker));
+/// }
+///
+/// fn select_in_world(cursor: Res<CursorLocation>) {
+///     let Some(location) = cursor.get().filter(|location| !location.blocked) else {
+///         return;
+///     };
+///     /* ... */
+/// }
+///
+/// # let _ = IntoSystem::into_system(spawn_modal);
+/// # let _ = IntoSystem::into_system(select_in_world);
+/// ```
+///
+/// [`HoveredUiNode`]: crate::HoveredUiNode
+/// [`UiHoverStack`]: crate::UiHoverStack
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CursorBlocker;
+
+/* -------------------------------------------------------------------------- */
+
+/// The [`CursorBlocker`] nodes.
+#[derive(SystemParam)]
+pub(crate) struct Blockers<'w, 's> {
+    blocker_q: Query<'w, 's, NodeQuery<'static>, With<CursorBlocker>>,
+    primary_window_q: Query<'w, 's, Entity, With<PrimaryWindow>>,
+    camera_q: Query<'w, 's, &'static Camera>,
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+}
+
+impl Blockers<'_, '_> {
+    /// Returns `true` if the location is over a [`CursorBlocker`].
+    fn contains(&self, location: &Location) -> bool {
+        let primary_window = self.primary_window_q.get_single().ok();
+        let physical_position = location.position * location.scale_factor;
+
+        self.blocker_q.iter().any(|item| {
+            ui_node_location(
+                item,
+                location.window,
+                physical_position,
+                primary_window,
+                &self.camera_q,
+                &self.default_ui_camera,
+            )
+            .is_some()
+        })
+    }
+}
+
+/// Computes the `blocked` flag of every location.
+pub(crate) fn block_cursor_location<F: CameraFilter>(
+    blockers: Blockers,
+    mut locations: LocationsMut<F>,
+) {
+    locations.for_each_location_mut(|location| {
+        let blocked = blockers.contains(location);
+        let changed = location.blocked != blocked;
+        location.blocked = blocked;
+        changed
+    });
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,81 @@
+//! Containment of the cursor in the camera viewports.
+
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that defines how the viewport of a camera is tested against the cursor.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// // A split-screen game: the seam belongs to a single viewport, and the cursor
+/// // must move 4 pixels past it before the other camera is selected.
+/// App::new().add_plugins(TrackCursorPlugin::default().with_viewport_bounds(ViewportBounds {
+///     edges: ViewportEdges::HalfOpen,
+///     hysteresis: 4.0,
+/// }));
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ViewportBounds {
+    /// Whether the right and bottom edges belong to the viewport.
+    pub edges: ViewportEdges,
+
+    /// How far, in logical pixels, the cursor can move outside the viewport of the
+    /// currently selected camera before another camera is selected.
+    ///
+    /// This prevents the selected camera from oscillating when the cursor rests on
+    /// the seam between two viewports.
+    pub hysteresis: f32,
+}
+
+/// Whether the right and bottom edges of a viewport contain the cursor.
+///
+/// See [`ViewportBounds`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ViewportEdges {
+    /// All the edges belong to the viewport.
+    ///
+    /// The border pixel between two adjacent viewports belongs to both of them.
+    #[default]
+    Inclusive,
+    /// Only the left and top edges belong to the viewport.
+    ///
+    /// Adjacent viewports don't overlap.
+    HalfOpen,
+}
+
+impl ViewportBounds {
+    /// Returns `true` if the viewport, grown by `margin` physical pixels on each side,
+    /// contains the physical position.
+    pub(crate) fn contains(&self, viewport: &Viewport, position: Vec2, margin: f32) -> bool {
+        let Vec2 { x, y } = position;
+        let Vec2 { x: vx, y: vy } = viewport.physical_position.as_vec2() - margin;
+        let Vec2 { x: vw, y: vh } = viewport.physical_size.as_vec2() + 2.0 * margin;
+
+        let inside_start = x >= vx && y >= vy;
+        let inside_end = match self.edges {
+            ViewportEdges::Inclusive => x <= (vx + vw) && y <= (vy + vh),
+            ViewportEdges::HalfOpen => x < (vx + vw) && y < (vy + vh),
+        };
+
+        inside_start && inside_end
+    }
+}
+
+/* -------------------------------------------------------------------------- */
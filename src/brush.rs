@@ -0,0 +1,322 @@
+//! Queries of the cells and the entities under a brush around the cursor.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::hover_index::HoverIndex;
+use crate::{CursorLocation, CursorSnap, HoverShape};
+
+/* -------------------------------------------------------------------------- */
+
+/// The footprint of a brush, centered on the cursor, for the area tools like the
+/// terraforming or the painting of tiles.
+///
+/// See [`CursorBrushQuery`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorBrush {
+    /// A circle, in world units.
+    Circle {
+        /// The radius of the circle.
+        radius: f32,
+    },
+    /// An axis-aligned rectangle, in world units.
+    Rect {
+        /// Half the width and the height of the rectangle.
+        half_size: Vec2,
+    },
+}
+
+impl CursorBrush {
+    /// Returns `true` if the brush contains the given offset from its center.
+    #[inline]
+    pub fn contains(&self, offset: Vec2) -> bool {
+        match *self {
+            CursorBrush::Circle { radius } => offset.length_squared() <= radius * radius,
+            CursorBrush::Rect { half_size } => offset.abs().cmple(half_size).all(),
+        }
+    }
+
+    /// The world bounds of the brush centered on the given world position.
+    #[inline]
+    pub fn bounds(&self, center: Vec2) -> Rect {
+        match *self {
+            CursorBrush::Circle { radius } => {
+                Rect::from_center_half_size(center, Vec2::splat(radius))
+            }
+            CursorBrush::Rect { half_size } => Rect::from_center_half_size(center, half_size),
+        }
+    }
+
+    /// Returns `true` if the brush centered on the given world position overlaps the
+    /// world rectangle.
+    #[inline]
+    pub fn overlaps(&self, center: Vec2, rect: Rect) -> bool {
+        let nearest = center.clamp(rect.min, rect.max);
+        self.contains(nearest - center)
+    }
+
+    /// Returns the cells of the grid whose center is under the brush centered on the
+    /// given world position, from the nearest to the farthest.
+    pub fn cells(&self, center: Vec2, snap: &CursorSnap) -> Vec<IVec2> {
+        let bounds = self.bounds(center);
+
+        // The coordinates of the cells grow with the world position, so the cells of
+        // the corners of the bounds, grown by one, cover the brush.
+        let corners = [
+            bounds.min,
+            Vec2::new(bounds.max.x, bounds.min.y),
+            bounds.max,
+            Vec2::new(bounds.min.x, bounds.max.y),
+        ]
+        .map(|corner| snap.cell(corner));
+
+        let min = corners.iter().fold(corners[0], |min, &cell| min.min(cell)) - IVec2::ONE;
+        let max = corners.iter().fold(corners[0], |max, &cell| max.max(cell)) + IVec2::ONE;
+
+        let mut cells = (min.x..=max.x)
+            .flat_map(|x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .map(|cell| (cell, snap.cell_center(cell) - center))
+            .filter(|&(_, offset)| self.contains(offset))
+            .collect::<Vec<_>>();
+
+        cells.sort_by(|(_, a), (_, b)| a.length_squared().total_cmp(&b.length_squared()));
+        cells.into_iter().map(|(cell, _)| cell).collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A [`SystemParam`] that queries the cells of the [`CursorSnap`] grid and the
+/// entities with a [`HoverShape`] under a [`CursorBrush`] around the cursor.
+///
+/// The entities are found with the spatial index of the hover shapes, so a brush
+/// only tests the shapes near it. An entity is under the brush when its shape
+/// overlaps the brush; a `HoverShape::Mesh` is tested with its world bounds.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// const BRUSH: CursorBrush = CursorBrush::Circle { radius: 48.0 };
+///
+/// fn paint(
+///     buttons: Res<ButtonInput<MouseButton>>,
+///     brush: CursorBrushQuery,
+///     mut sprite_q: Query<&mut Sprite>,
+/// ) {
+///     if !buttons.pressed(MouseButton::Left) {
+///         return;
+///     }
+///
+///     for entity in brush.entities(BRUSH) {
+///         if let Ok(mut sprite) = sprite_q.get_mut(entity) {
+///             sprite.color = Color::srgb(0.2, 0.8, 0.2);
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(paint);
+/// ```
+///
+/// [`SystemParam`]: https://docs.rs/bevy/0.15.0/bevy/ecs/system/trait.SystemParam.html
+/// [`HoverShape`]: crate::HoverShape
+#[derive(SystemParam)]
+pub struct CursorBrushQuery<'w, 's> {
+    cursor: Option<Res<'w, CursorLocation>>,
+    snap: Option<Res<'w, CursorSnap>>,
+    index: Res<'w, HoverIndex>,
+    shape_q: Query<'w, 's, (&'static HoverShape, &'static GlobalTransform)>,
+    visibility_q: Query<'w, 's, &'static InheritedVisibility>,
+}
+
+impl CursorBrushQuery<'_, '_> {
+    /// The world position of the cursor, on which the brush is centered.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area.
+    #[inline]
+    pub fn center(&self) -> Option<Vec2> {
+        self.cursor.as_ref()?.world_position()
+    }
+
+    /// Returns the visible entities with a [`HoverShape`] under the brush around the
+    /// cursor, from the nearest to the farthest.
+    ///
+    /// Returns an empty list if the cursor is outside any window area.
+    ///
+    /// [`HoverShape`]: crate::HoverShape
+    pub fn entities(&self, brush: CursorBrush) -> Vec<Entity> {
+        self.center()
+            .map(|center| self.entities_at(center, brush))
+            .unwrap_or_default()
+    }
+
+    /// Returns the visible entities with a [`HoverShape`] under the brush centered on
+    /// the given world position, from the nearest to the farthest.
+    ///
+    /// [`HoverShape`]: crate::HoverShape
+    pub fn entities_at(&self, center: Vec2, brush: CursorBrush) -> Vec<Entity> {
+        let mut entities = self
+            .index
+            .overlapping(brush.bounds(center))
+            .filter(|&(entity, bounds)| {
+                self.shape_q
+                    .get(entity)
+                    .ok()
+                    .and_then(|(shape, transform)| shape_overlaps(brush, center, shape, transform))
+                    .unwrap_or_else(|| brush.overlaps(center, bounds))
+            })
+            .filter(|&(entity, _)| {
+                self.visibility_q
+                    .get(entity)
+                    .map_or(true, |visibility| visibility.get())
+            })
+            .map(|(entity, bounds)| (entity, bounds.center().distance_squared(center)))
+            .collect::<Vec<_>>();
+
+        entities.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        entities.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    /// Returns the cells of the [`CursorSnap`] grid under the brush around the cursor,
+    /// from the nearest to the farthest.
+    ///
+    /// Returns an empty list if the cursor is outside any window area, or if there is
+    /// no [`CursorSnap`] resource.
+    pub fn cells(&self, brush: CursorBrush) -> Vec<IVec2> {
+        self.center()
+            .map(|center| self.cells_at(center, brush))
+            .unwrap_or_default()
+    }
+
+    /// Returns the cells of the [`CursorSnap`] grid under the brush centered on the
+    /// given world position, from the nearest to the farthest.
+    ///
+    /// Returns an empty list if there is no [`CursorSnap`] resource.
+    pub fn cells_at(&self, center: Vec2, brush: CursorBrush) -> Vec<IVec2> {
+        self.snap
+            .as_ref()
+            .map(|snap| brush.cells(center, snap))
+            .unwrap_or_default()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns `true` if the brush centered on the given world position overlaps the shape
+/// of an entity with the given transform.
+///
+/// Returns [`None`] for a `HoverShape::Mesh`, whose triangles are in the mesh asset.
+fn shape_overlaps(
+    brush: CursorBrush,
+    center: Vec2,
+    shape: &HoverShape,
+    transform: &GlobalTransform,
+) -> Option<bool> {
+    let z = transform.translation().z;
+    let affine = transform.affine();
+    let inverse = affine.inverse();
+    let to_local = |point: Vec2| inverse.transform_point3(point.extend(z)).truncate();
+    let to_world = |point: Vec2| affine.transform_point3(point.extend(0.0)).truncate();
+
+    let overlaps = match brush {
+        // Note: the point of the shape nearest to the center of the brush is found in
+        // the local space, which is exact for the rotated and uniformly scaled shapes.
+        CursorBrush::Circle { .. } => {
+            let local = to_local(center);
+            let nearest = match *shape {
+                HoverShape::Circle { radius } => local.clamp_length_max(radius),
+                HoverShape::Rect { half_size } => local.clamp(-half_size, half_size),
+                #[cfg(feature = "mesh2d")]
+                HoverShape::Mesh => return None,
+            };
+            brush.contains(to_world(nearest) - center)
+        }
+        // The rectangle of the brush is a parallelogram in the local space of the shape.
+        CursorBrush::Rect { half_size } => {
+            let corners = [
+                Vec2::new(-half_size.x, -half_size.y),
+                Vec2::new(half_size.x, -half_size.y),
+                Vec2::new(half_size.x, half_size.y),
+                Vec2::new(-half_size.x, half_size.y),
+            ]
+            .map(|corner| to_local(center + corner));
+
+            match *shape {
+                HoverShape::Circle { radius } => {
+                    polygon_contains(&corners, Vec2::ZERO)
+                        || (0..4).any(|i| {
+                            let nearest = segment_nearest(corners[i], corners[(i + 1) % 4]);
+                            nearest.length_squared() <= radius * radius
+                        })
+                }
+                HoverShape::Rect { half_size } => {
+                    let rect = [
+                        Vec2::new(-half_size.x, -half_size.y),
+                        Vec2::new(half_size.x, -half_size.y),
+                        Vec2::new(half_size.x, half_size.y),
+                        Vec2::new(-half_size.x, half_size.y),
+                    ];
+
+                    // Separating axis theorem: the axes of the local rectangle and the
+                    // normals of the edges of the parallelogram.
+                    [
+                        Vec2::X,
+                        Vec2::Y,
+                        (corners[1] - corners[0]).perp(),
+                        (corners[2] - corners[1]).perp(),
+                    ]
+                    .into_iter()
+                    .all(|axis| {
+                        let (min_a, max_a) = project(&corners, axis);
+                        let (min_b, max_b) = project(&rect, axis);
+                        min_a <= max_b && min_b <= max_a
+                    })
+                }
+                #[cfg(feature = "mesh2d")]
+                HoverShape::Mesh => return None,
+            }
+        }
+    };
+
+    Some(overlaps)
+}
+
+/// Returns `true` if the convex polygon contains the point.
+fn polygon_contains(polygon: &[Vec2; 4], point: Vec2) -> bool {
+    let sides = (0..4).map(|i| (polygon[(i + 1) % 4] - polygon[i]).perp_dot(point - polygon[i]));
+    let (mut positive, mut negative) = (false, false);
+    for side in sides {
+        positive |= side > 0.0;
+        negative |= side < 0.0;
+    }
+    !(positive && negative)
+}
+
+/// The point of the segment nearest to the origin.
+fn segment_nearest(a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+    a + ab * (-a.dot(ab) / length_squared).clamp(0.0, 1.0)
+}
+
+/// The interval of the projections of the points on the axis.
+fn project(points: &[Vec2; 4], axis: Vec2) -> (f32, f32) {
+    points
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), point| {
+            let projection = point.dot(axis);
+            (min.min(projection), max.max(projection))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
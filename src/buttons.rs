@@ -0,0 +1,158 @@
+//! The mouse buttons held with the location where they have been pressed.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{CameraFilter, CursorLocation, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the mouse buttons currently held, with the location of
+/// the cursor when each of them has been pressed.
+///
+/// The press locations are resolved with the same pipeline as the
+/// [`CursorLocation`], so the systems don't have to correlate the
+/// [`ButtonInput<MouseButton>`] with the location of the previous frame.
+///
+/// Unlike the [`CursorDrags`], the buttons are tracked without any settings.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_press_origin(buttons: Res<CursorButtons>) {
+///     if let Some(origin) = buttons.origin(MouseButton::Left) {
+///         info!("The left button has been pressed at {}", origin.position);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_press_origin);
+/// ```
+///
+/// [`ButtonInput<MouseButton>`]: https://docs.rs/bevy/0.15.0/bevy/input/struct.ButtonInput.html
+/// [`CursorDrags`]: crate::CursorDrags
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorButtons<F: CameraFilter = ()>(
+    Vec<ButtonPress>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
+
+impl<F: CameraFilter> Default for CursorButtons<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorButtons<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorButtons<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorButtons<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorButtons").field(&self.0).finish()
+    }
+}
+
+impl<F: CameraFilter> CursorButtons<F> {
+    /// The press of the given button.
+    ///
+    /// Returns [`None`] if the button isn't held.
+    #[inline]
+    pub fn get(&self, button: MouseButton) -> Option<&ButtonPress> {
+        self.0.iter().find(|press| press.button == button)
+    }
+
+    /// The location of the cursor when the given button has been pressed.
+    ///
+    /// Returns [`None`] if the button isn't held, or if it has been pressed while
+    /// the cursor was outside any window area.
+    #[inline]
+    pub fn origin(&self, button: MouseButton) -> Option<&Location> {
+        self.get(button).and_then(|press| press.origin.as_ref())
+    }
+
+    /// Returns `true` if the given button is held.
+    #[inline]
+    pub fn pressed(&self, button: MouseButton) -> bool {
+        self.get(button).is_some()
+    }
+
+    /// Iterates over the presses of the held buttons, in the order they have been pressed.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ButtonPress> {
+        self.0.iter()
+    }
+
+    /// Returns `true` if no button is held.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A mouse button held, with the location of the cursor when it has been pressed.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ButtonPress {
+    /// The held button.
+    pub button: MouseButton,
+
+    /// The location of the cursor when the button has been pressed.
+    ///
+    /// This is [`None`] if the cursor was outside any window area.
+    pub origin: Option<Location>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the [`CursorButtons`] with the pressed and released buttons.
+pub(crate) fn update_cursor_buttons<F: CameraFilter>(
+    buttons: Res<ButtonInput<MouseButton>>,
+    cursor: Res<CursorLocation<F>>,
+    mut held: ResMut<CursorButtons<F>>,
+) {
+    let released = held.0.iter().any(|press| !buttons.pressed(press.button));
+    if released {
+        held.0.retain(|press| buttons.pressed(press.button));
+    }
+
+    let origin = cursor.get().filter(|location| !location.is_stale);
+
+    for &button in buttons.get_just_pressed() {
+        if buttons.pressed(button) && held.get(button).is_none() {
+            held.0.push(ButtonPress {
+                button,
+                origin: origin.cloned(),
+            });
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,138 @@
+//! Capture of the pointer while a mouse button is held.
+
+use std::marker::PhantomData;
+
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+use crate::input::OptionalEventReader;
+use crate::stamp::LocationClock;
+use crate::{camera_location, CameraFilter, CameraQuery, CursorLocation, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the pointer capture.
+///
+/// By default, [`CursorLocation`] becomes empty as soon as the cursor leaves the
+/// window, which breaks drags that momentarily leave it.
+///
+/// When this resource exists, pressing a mouse button while the cursor is inside a
+/// window captures the pointer. While any mouse button is still held, the location
+/// keeps being reported after the cursor left the window: its position is extrapolated
+/// from the mouse motion and resolved through the camera that was under the cursor
+/// when the pointer has been captured. Such a location has its
+/// [`outside_window`](Location::outside_window) flag set.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(PointerCapture);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PointerCapture;
+
+/* -------------------------------------------------------------------------- */
+
+/// The state of the captured pointer.
+#[derive(Resource)]
+pub(crate) struct CapturedPointer<F: CameraFilter>(Option<Captured>, PhantomData<fn() -> F>);
+
+impl<F: CameraFilter> Default for CapturedPointer<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(None, PhantomData)
+    }
+}
+
+struct Captured {
+    window: Entity,
+    camera: Entity,
+    render_layers: RenderLayers,
+    position: Vec2,
+    scale_factor: f32,
+}
+
+/// Captures the pointer and extrapolates its location when it leaves the window.
+pub(crate) fn update_pointer_capture<F: CameraFilter>(
+    capture: Option<Res<PointerCapture>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    mut mouse_motion: OptionalEventReader<MouseMotion>,
+    camera_q: CameraQuery<F::Filter>,
+    mut captured: ResMut<CapturedPointer<F>>,
+    mut cursor: ResMut<CursorLocation<F>>,
+    clock: LocationClock,
+) {
+    let delta = mouse_motion.read().map(|motion| motion.delta).sum::<Vec2>();
+
+    // Note: the `ButtonInput` is only provided by the `InputPlugin`.
+    let Some(buttons) =
+        buttons.filter(|buttons| capture.is_some() && buttons.get_pressed().next().is_some())
+    else {
+        captured.0 = None;
+        return;
+    };
+
+    match (
+        cursor.get().filter(|location| !location.is_stale),
+        &mut captured.0,
+    ) {
+        // The cursor is inside a window: follow it.
+        (Some(location), Some(captured)) => {
+            captured.position = location.position;
+            captured.scale_factor = location.scale_factor;
+        }
+        // A button has been pressed inside a window: capture the pointer.
+        (Some(location), None) => {
+            if buttons.get_just_pressed().next().is_some() {
+                captured.0 = Some(Captured {
+                    window: location.window,
+                    camera: location.camera,
+                    render_layers: location.render_layers.clone(),
+                    position: location.position,
+                    scale_factor: location.scale_factor,
+                });
+            }
+        }
+        // The cursor left the window while captured: extrapolate its location.
+        (None, Some(captured)) => {
+            captured.position += delta;
+
+            let Ok((camera_ref, cam_t, camera, _)) = camera_q.get(captured.camera) else {
+                return;
+            };
+
+            if let Ok(location) = camera_location(
+                captured.window,
+                camera_ref,
+                cam_t,
+                camera,
+                captured.position,
+            ) {
+                let mut location = Location {
+                    outside_window: true,
+                    scale_factor: captured.scale_factor,
+                    render_layers: captured.render_layers.clone(),
+                    ..location
+                };
+                location.stamp(None, clock.now());
+                cursor.0 = Some(location);
+            }
+        }
+        (None, None) => {}
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,169 @@
+//! Windows that let the clicks pass through where nothing is hovered.
+
+use bevy::prelude::*;
+use bevy::window::WindowPosition;
+
+#[cfg(feature = "ui")]
+use crate::HoveredUiNode;
+use crate::HoveredWindowRegion;
+#[cfg(feature = "2d")]
+use crate::{CursorLocation, HoveredEntities};
+
+/* -------------------------------------------------------------------------- */
+
+/// A component for the windows that let the clicks pass through the areas without
+/// interactive content, like the overlays and the desktop widgets.
+///
+/// The [`hit_test`] of the cursor options of the window is enabled while the cursor
+/// hovers some content of the window, and disabled otherwise. The content is:
+/// - a region of the [`HoveredWindowRegion`],
+/// - the [`HoveredUiNode`], with the feature `ui`,
+/// - the [`HoveredEntities`] of the [`CursorLocation`], with the feature `2d`.
+///
+/// A window doesn't receive the cursor events while the clicks pass through it, so
+/// the cursor is then followed with the [`DesktopCursorPosition`], which the
+/// application polls from the OS. The clicks only pass through the window while this
+/// position is known.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn spawn_overlay(mut commands: Commands) {
+///     commands.spawn((
+///         Window {
+///             transparent: true,
+///             decorations: false,
+///             ..default()
+///         },
+///         ClickThrough,
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(spawn_overlay);
+/// ```
+///
+/// [`hit_test`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.CursorOptions.html#structfield.hit_test
+/// [`HoveredUiNode`]: crate::HoveredUiNode
+/// [`HoveredEntities`]: crate::HoveredEntities
+/// [`CursorLocation`]: crate::CursorLocation
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq, Default)]
+pub struct ClickThrough;
+
+/// A resource where the application sets the position of the cursor on the desktop,
+/// in physical pixels, as polled from the OS.
+///
+/// The windows that don't report the cursor, like the [`ClickThrough`] windows while
+/// the clicks pass through them, locate the cursor with this position and their
+/// [`WindowPosition`]. The position of a window is the position of its outer frame,
+/// so the decorations of the window offset the result.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// # fn poll_os_cursor() -> Option<IVec2> { None }
+/// fn poll_desktop_cursor(mut desktop: ResMut<DesktopCursorPosition>) {
+///     match poll_os_cursor() {
+///         Some(position) => desktop.set(position),
+///         None => desktop.clear(),
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(poll_desktop_cursor);
+/// ```
+///
+/// [`WindowPosition`]: https://docs.rs/bevy/0.15.0/bevy/window/enum.WindowPosition.html
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DesktopCursorPosition(Option<IVec2>);
+
+impl DesktopCursorPosition {
+    /// The position of the cursor on the desktop, in physical pixels.
+    ///
+    /// Returns [`None`] if the position isn't known.
+    #[inline]
+    pub fn get(&self) -> Option<IVec2> {
+        self.0
+    }
+
+    /// Sets the position of the cursor on the desktop, in physical pixels.
+    #[inline]
+    pub fn set(&mut self, position: IVec2) {
+        self.0 = Some(position);
+    }
+
+    /// Forgets the position of the cursor on the desktop.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+
+    /// Returns the logical position of the cursor on the given window, or [`None`]
+    /// if the cursor isn't on the window or if the position of the window is unknown.
+    pub(crate) fn on_window(&self, window: &Window) -> Option<Vec2> {
+        let WindowPosition::At(window_position) = window.position else {
+            return None;
+        };
+
+        let position = self.0? - window_position;
+        let size = window.physical_size().as_ivec2();
+        if position.cmplt(IVec2::ZERO).any() || position.cmpge(size).any() {
+            return None;
+        }
+
+        Some(position.as_vec2() / window.scale_factor())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Enables the hit test of the [`ClickThrough`] windows whose content is hovered.
+pub(crate) fn update_click_through(
+    region: Res<HoveredWindowRegion>,
+    #[cfg(feature = "ui")] hovered_node: Res<HoveredUiNode>,
+    #[cfg(feature = "2d")] cursor: Option<Res<CursorLocation>>,
+    #[cfg(feature = "2d")] hovered_entities: Res<HoveredEntities>,
+    desktop: Option<Res<DesktopCursorPosition>>,
+    mut window_q: Query<(Entity, &mut Window), With<ClickThrough>>,
+) {
+    // Note: the window can't see the cursor again once the clicks pass through it,
+    // unless the cursor is followed on the desktop.
+    let followed = desktop.is_some_and(|desktop| desktop.get().is_some());
+
+    for (window_ref, mut window) in &mut window_q {
+        let hovered = region.window() == Some(window_ref);
+
+        #[cfg(feature = "ui")]
+        let hovered = hovered
+            || hovered_node
+                .get()
+                .is_some_and(|node| node.window == window_ref);
+
+        #[cfg(feature = "2d")]
+        let hovered = hovered
+            || (!hovered_entities.is_empty()
+                && cursor.as_ref().and_then(|cursor| cursor.window()) == Some(window_ref));
+
+        let hit_test = hovered || !followed;
+        if window.cursor_options.hit_test != hit_test {
+            window.cursor_options.hit_test = hit_test;
+
+            // The window keeps the last position it has seen, which would hide the
+            // desktop position while the clicks pass through.
+            if !hit_test {
+                window.set_cursor_position(None);
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
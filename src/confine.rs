@@ -0,0 +1,119 @@
+//! Confinement of the cursor to a region of the world.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::target_window;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that confines the OS cursor to a region.
+///
+/// When this resource exists, the plugin warps the OS cursor back into the region
+/// each time it would leave it. This is useful for board-game style interactions
+/// or mini-games that must keep the focus.
+///
+/// The cursor can only be moved back while it is inside the window. Combine this
+/// with [`CursorGrabMode::Confined`] to prevent it from leaving the window.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     let camera = commands.spawn(Camera2d).id();
+///
+///     // Keep the cursor over the board.
+///     commands.insert_resource(CursorConfinement::WorldRect {
+///         camera,
+///         rect: Rect::new(-200.0, -200.0, 200.0, 200.0),
+///     });
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`CursorGrabMode::Confined`]: https://docs.rs/bevy/0.15.0/bevy/window/enum.CursorGrabMode.html#variant.Confined
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorConfinement {
+    /// Confines the cursor to a rectangle of the XY plane in world coordinates,
+    /// as seen through a camera.
+    ///
+    /// The rectangle is projected through the camera and the cursor is confined
+    /// to the bounding box of the projection.
+    WorldRect {
+        /// The entity id of the camera through which the rectangle is seen.
+        camera: Entity,
+        /// The rectangle in world coordinates.
+        rect: Rect,
+    },
+    /// Confines the cursor to the viewport of a camera.
+    Viewport(Entity),
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Warps the cursor back into the [`CursorConfinement`] region.
+pub(crate) fn confine_cursor(
+    confinement: Option<Res<CursorConfinement>>,
+    camera_q: Query<(&GlobalTransform, &Camera)>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    mut window_q: Query<&mut Window>,
+) {
+    let Some(confinement) = confinement else {
+        return;
+    };
+
+    let camera_ref = match *confinement {
+        CursorConfinement::WorldRect { camera, .. } | CursorConfinement::Viewport(camera) => camera,
+    };
+
+    let Ok((cam_t, camera)) = camera_q.get(camera_ref) else {
+        return;
+    };
+
+    let Some(win_ref) = target_window(&camera.target, primary_window_q.get_single().ok()) else {
+        return;
+    };
+
+    let Ok(mut window) = window_q.get_mut(win_ref) else {
+        return;
+    };
+
+    let Some(viewport) = camera.logical_viewport_rect() else {
+        return;
+    };
+
+    // The region in window coordinates.
+    let region = match *confinement {
+        CursorConfinement::WorldRect { rect, .. } => {
+            let Ok(a) = camera.world_to_viewport(cam_t, rect.min.extend(0.0)) else {
+                return;
+            };
+            let Ok(b) = camera.world_to_viewport(cam_t, rect.max.extend(0.0)) else {
+                return;
+            };
+            Rect::from_corners(a + viewport.min, b + viewport.min)
+        }
+        CursorConfinement::Viewport(_) => viewport,
+    };
+
+    let Some(position) = window.cursor_position() else {
+        return;
+    };
+
+    let confined = position.clamp(region.min, region.max);
+
+    if confined != position {
+        window.set_cursor_position(Some(confined));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
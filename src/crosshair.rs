@@ -0,0 +1,80 @@
+//! Synthesis of the cursor location from the center of a camera viewport.
+
+use bevy::ecs::query::Has;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::{
+    resolve_location, window_cameras, CameraFilter, CameraQuery, CameraSelection, Location,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the crosshair mode.
+///
+/// When this resource exists, the plugin ignores the position of the OS cursor.
+/// Instead, it computes the [`CursorLocation`] from the center of the viewport
+/// of the topmost active camera that renders into the focused window (or the
+/// primary window if no window is focused).
+///
+/// This is useful for FPS-style games where the OS cursor is hidden and the
+/// player aims with a crosshair.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CrosshairMode);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CrosshairMode;
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the [`Location`] of the crosshair.
+pub(crate) fn crosshair_location<F: CameraFilter>(
+    window_q: &Query<(Entity, &Window, Has<PrimaryWindow>)>,
+    camera_q: &CameraQuery<F::Filter>,
+    selection: &CameraSelection<F>,
+) -> Option<Location> {
+    // Use the focused window, or the primary window if none is focused.
+    let (win_ref, window, is_primary) = window_q
+        .iter()
+        .find(|&(_, window, _)| window.focused)
+        .or_else(|| window_q.iter().find(|&(_, _, is_primary)| is_primary))?;
+
+    let center = window_cameras(win_ref, is_primary, camera_q, selection)
+        .first()
+        .map(|&(_, _, camera, display)| match display {
+            None => camera
+                .logical_viewport_rect()
+                .map_or(window.size() / 2.0, |viewport| viewport.center()),
+            Some(rect) => {
+                // The render target is displayed on a part of the window.
+                let target_size = camera.logical_target_size().unwrap_or(rect.size());
+                let center = camera
+                    .logical_viewport_rect()
+                    .map_or(target_size / 2.0, |viewport| viewport.center());
+                rect.min + center / target_size * rect.size()
+            }
+        })?;
+
+    resolve_location(
+        win_ref, window, is_primary, center, camera_q, selection, None,
+    )
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,242 @@
+//! Explanation of the selection of the cameras that render the cursor.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use smallvec::SmallVec;
+
+use crate::{
+    camera_display, resolve_locations, CameraFilter, CameraQuery, CameraSelection,
+    CursorResolutionErrorReason,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that records, each frame, how the cameras have been selected to
+/// resolve the [`CursorLocation`].
+///
+/// When this resource exists, it lists the windows that contained the cursor, and
+/// why each camera has been selected or rejected for them. This is useful to find
+/// out why a camera is never picked.
+///
+/// Nothing is recorded in [`CrosshairMode`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.init_resource::<CursorLocationDebug>();
+/// }
+///
+/// fn explain_camera(debug: Res<CursorLocationDebug>, camera: Single<Entity, With<Camera>>) {
+///     if let Some(status) = debug.camera(*camera) {
+///         info!("Camera {}: {status:?}", *camera);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(explain_camera);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+/// [`CrosshairMode`]: crate::CrosshairMode
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorLocationDebug<F: CameraFilter = ()> {
+    windows: Vec<Entity>,
+    cameras: Vec<CameraDebug>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// How a camera has been handled for a window that contains the cursor.
+///
+/// See [`CursorLocationDebug`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CameraDebug {
+    /// The entity id of the camera.
+    pub camera: Entity,
+    /// The entity id of the window that contains the cursor.
+    pub window: Entity,
+    /// Whether the camera has been selected or why it has been rejected.
+    pub status: CameraStatus,
+}
+
+/// Whether a camera has been selected to resolve the cursor, or why it has been rejected.
+///
+/// See [`CursorLocationDebug`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CameraStatus {
+    /// The camera is the one of the [`CursorLocation`](crate::CursorLocation).
+    Selected,
+    /// The camera renders under the cursor, below the selected camera.
+    ///
+    /// See [`CursorLocations`](crate::CursorLocations).
+    Covered,
+    /// The camera doesn't render to the window.
+    WrongTarget,
+    /// The camera is not active.
+    Inactive,
+    /// The camera has been rejected by the predicate of the plugin.
+    ///
+    /// See [`TrackCursorPlugin::with_camera_predicate`](crate::TrackCursorPlugin::with_camera_predicate).
+    Rejected,
+    /// The viewport of the camera doesn't contain the cursor.
+    ViewportMiss,
+    /// The location of the cursor can't be computed through the camera.
+    ///
+    /// See [`CursorResolutionError`](crate::CursorResolutionError).
+    ConversionFailed(CursorResolutionErrorReason),
+}
+
+impl<F: CameraFilter> Default for CursorLocationDebug<F> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            windows: Vec::new(),
+            cameras: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorLocationDebug<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            windows: self.windows.clone(),
+            cameras: self.cameras.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorLocationDebug<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.windows == other.windows && self.cameras == other.cameras
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorLocationDebug<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CursorLocationDebug")
+            .field("windows", &self.windows)
+            .field("cameras", &self.cameras)
+            .finish()
+    }
+}
+
+impl<F: CameraFilter> CursorLocationDebug<F> {
+    /// The windows that contained the cursor, in the order they have been considered.
+    #[inline]
+    pub fn windows(&self) -> &[Entity] {
+        &self.windows
+    }
+
+    /// How each camera has been handled for the windows that contained the cursor.
+    #[inline]
+    pub fn cameras(&self) -> &[CameraDebug] {
+        &self.cameras
+    }
+
+    /// The status of the camera for the last window it has been considered for, if any.
+    #[inline]
+    pub fn camera(&self, camera: Entity) -> Option<CameraStatus> {
+        self.cameras
+            .iter()
+            .rev()
+            .find(|debug| debug.camera == camera)
+            .map(|debug| debug.status)
+    }
+
+    /// Clears the records of the previous frame.
+    pub(crate) fn clear(&mut self) {
+        self.windows.clear();
+        self.cameras.clear();
+    }
+
+    /// Records how the cameras are handled for a window that contains the cursor.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn record_window(
+        &mut self,
+        win_ref: Entity,
+        window: &Window,
+        is_primary: bool,
+        cursor_position: Vec2,
+        camera_q: &CameraQuery<F::Filter>,
+        selection: &CameraSelection<F>,
+        current_camera: Option<Entity>,
+    ) {
+        self.windows.push(win_ref);
+
+        let resolved = resolve_locations(
+            win_ref,
+            window,
+            is_primary,
+            cursor_position,
+            camera_q,
+            selection,
+            current_camera,
+        )
+        .map(|location| match location {
+            Ok(location) => (location.camera, None),
+            Err(error) => (error.camera, Some(error.reason)),
+        })
+        .collect::<SmallVec<[_; 4]>>();
+
+        let selected = resolved
+            .iter()
+            .find_map(|&(camera_ref, error)| error.is_none().then_some(camera_ref));
+
+        for (camera_ref, _, camera, _) in camera_q {
+            let status = if let Some(&(_, error)) = resolved
+                .iter()
+                .find(|(resolved, _)| *resolved == camera_ref)
+            {
+                match error {
+                    Some(reason) => CameraStatus::ConversionFailed(reason),
+                    None if Some(camera_ref) == selected => CameraStatus::Selected,
+                    None => CameraStatus::Covered,
+                }
+            } else if camera_display(win_ref, is_primary, camera, selection).is_none() {
+                CameraStatus::WrongTarget
+            } else if !camera.is_active {
+                CameraStatus::Inactive
+            } else if !selection.accepts(camera_ref, camera) {
+                CameraStatus::Rejected
+            } else {
+                CameraStatus::ViewportMiss
+            };
+
+            self.cameras.push(CameraDebug {
+                camera: camera_ref,
+                window: win_ref,
+                status,
+            });
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,103 @@
+//! The fields of the locations computed after they have been resolved.
+
+use bevy::ecs::system::{RunSystemOnce, SystemParam};
+use bevy::prelude::*;
+
+use crate::{
+    CameraFilter, CursorLocation, CursorLocations, CursorLocationsPerWindow, CursorSamples,
+    ExternalPointerLocations, Location,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A [`SystemParam`] that gives mutable access to every [`Location`] resolved by the
+/// [`TrackCursorPlugin<F>`]: the [`CursorLocation`], the [`CursorLocations`], the
+/// [`CursorLocationsPerWindow`], the [`CursorSamples`] and the
+/// [`ExternalPointerLocations`].
+///
+/// [`TrackCursorPlugin<F>`]: crate::TrackCursorPlugin
+#[derive(SystemParam)]
+pub(crate) struct LocationsMut<'w, F: CameraFilter> {
+    cursor: ResMut<'w, CursorLocation<F>>,
+    locations: ResMut<'w, CursorLocations<F>>,
+    per_window: Option<ResMut<'w, CursorLocationsPerWindow<F>>>,
+    samples: Option<ResMut<'w, CursorSamples<F>>>,
+    external: ResMut<'w, ExternalPointerLocations<F>>,
+}
+
+impl<F: CameraFilter> LocationsMut<'_, F> {
+    /// Calls `f` on every location, which returns `true` if it changed the location.
+    ///
+    /// Only the resources whose locations have changed are marked as changed.
+    pub(crate) fn for_each_location_mut(&mut self, mut f: impl FnMut(&mut Location) -> bool) {
+        // Note: every location is visited, even after a change.
+        let mut apply = |changed: bool, location: &mut Location| f(location) | changed;
+
+        if self
+            .cursor
+            .bypass_change_detection()
+            .0
+            .iter_mut()
+            .fold(false, &mut apply)
+        {
+            self.cursor.set_changed();
+        }
+
+        if self
+            .locations
+            .bypass_change_detection()
+            .iter_mut()
+            .fold(false, &mut apply)
+        {
+            self.locations.set_changed();
+        }
+
+        if let Some(per_window) = self.per_window.as_mut() {
+            if per_window
+                .bypass_change_detection()
+                .iter_mut()
+                .fold(false, &mut apply)
+            {
+                per_window.set_changed();
+            }
+        }
+
+        if let Some(samples) = self.samples.as_mut() {
+            if samples
+                .bypass_change_detection()
+                .iter_mut()
+                .fold(false, &mut apply)
+            {
+                samples.set_changed();
+            }
+        }
+
+        if self
+            .external
+            .bypass_change_detection()
+            .iter_mut()
+            .fold(false, &mut apply)
+        {
+            self.external.set_changed();
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the derived fields of the locations after the cursor has been warped.
+pub(crate) fn derive_warped_locations<F: CameraFilter>(world: &mut World, _: Entity, _: Vec2) {
+    #[cfg(feature = "2d")]
+    {
+        let _ = world.run_system_once(crate::snap::snap_cursor_location::<F>);
+        let _ = world.run_system_once(crate::magnet::attract_cursor_location::<F>);
+    }
+
+    #[cfg(feature = "ui")]
+    let _ = world.run_system_once(crate::blocker::block_cursor_location::<F>);
+
+    #[cfg(feature = "3d")]
+    let _ = world.run_system_once(crate::projection::project_cursor_location::<F>);
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,106 @@
+//! Diagnostics of the resolution of the cursor location.
+
+use std::time::Duration;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A plugin that registers diagnostics about the resolution of the cursor location.
+///
+/// The diagnostics are summed over all the instances of the [`TrackCursorPlugin`].
+/// Use the [`LogDiagnosticsPlugin`] or any diagnostics overlay to display them.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::diagnostic::LogDiagnosticsPlugin;
+/// # use bevy_cursor::prelude::*;
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         TrackCursorPlugin::default(),
+///         CursorDiagnosticsPlugin,
+///         LogDiagnosticsPlugin::default(),
+///     ))
+///     .run();
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+/// [`LogDiagnosticsPlugin`]: https://docs.rs/bevy/0.15.0/bevy/diagnostic/struct.LogDiagnosticsPlugin.html
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CursorDiagnosticsPlugin;
+
+impl CursorDiagnosticsPlugin {
+    /// The time spent to resolve the cursor location each frame, in milliseconds.
+    pub const RESOLUTION_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_cursor/resolution_time");
+
+    /// The number of cameras considered to resolve the cursor location each frame.
+    pub const CAMERAS_CONSIDERED: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_cursor/cameras_considered");
+
+    /// The number of cursor locations resolved per second.
+    pub const RESOLUTIONS: DiagnosticPath = DiagnosticPath::const_new("bevy_cursor/resolutions");
+}
+
+impl Plugin for CursorDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::RESOLUTION_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::CAMERAS_CONSIDERED))
+            .register_diagnostic(Diagnostic::new(Self::RESOLUTIONS).with_suffix("/s"))
+            .init_resource::<CursorStats>()
+            .add_systems(Last, measure_cursor_stats);
+    }
+}
+
+/// The measures of the resolution of the cursor location since the last frame.
+///
+/// When this resource exists, it's updated by the [`TrackCursorPlugin`]s.
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Resource, Default)]
+pub(crate) struct CursorStats {
+    time: Duration,
+    cameras: usize,
+    resolutions: usize,
+}
+
+impl CursorStats {
+    /// Records a resolution of the cursor location.
+    pub(crate) fn record(&mut self, time: Duration, cameras: usize, resolved: bool) {
+        self.time += time;
+        self.cameras += cameras;
+        self.resolutions += usize::from(resolved);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Adds the [`CursorStats`] to the diagnostics and resets them.
+fn measure_cursor_stats(
+    mut stats: ResMut<CursorStats>,
+    time: Res<Time<Real>>,
+    mut diagnostics: Diagnostics,
+) {
+    let stats = std::mem::take(&mut *stats);
+
+    diagnostics.add_measurement(&CursorDiagnosticsPlugin::RESOLUTION_TIME, || {
+        stats.time.as_secs_f64() * 1000.0
+    });
+
+    diagnostics.add_measurement(&CursorDiagnosticsPlugin::CAMERAS_CONSIDERED, || {
+        stats.cameras as f64
+    });
+
+    let delta = time.delta_secs_f64();
+    if delta > 0.0 {
+        diagnostics.add_measurement(&CursorDiagnosticsPlugin::RESOLUTIONS, || {
+            stats.resolutions as f64 / delta
+        });
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,701 @@
+//! Drag sessions of the mouse buttons.
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::stamp::LocationClock;
+use crate::{CameraFilter, CursorLocation, KeyModifiers, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the detection of drags, and defines the buttons that
+/// can drag.
+///
+/// When this resource exists, each of its buttons runs its own drag session: the
+/// session starts when the button is pressed over a window, and the drag begins
+/// once the cursor has moved farther than the threshold of the button. This way a
+/// tool can select with the left button while the middle button pans and the right
+/// button orbits.
+///
+/// The sessions are provided by the [`CursorDrags`] resource, and their progress is
+/// notified with the [`CursorDragStart`], [`CursorDragMove`] and [`CursorDragEnd`]
+/// events.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(
+///         CursorDragSettings::new()
+///             .with_button(MouseButton::Left, 4.0)
+///             .with_button(MouseButton::Middle, 0.0),
+///     );
+/// }
+///
+/// fn pan(mut drags: EventReader<CursorDragMove>) {
+///     for drag in drags.read() {
+///         if drag.button == MouseButton::Middle {
+///             info!("Pan by {:?}", drag.delta);
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(pan);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorDragSettings {
+    /// The buttons that can drag, with the distance in logical pixels the cursor
+    /// must move before their drag begins.
+    thresholds: Vec<(MouseButton, f32)>,
+
+    /// Locks the drags to an axis while some modifiers are held.
+    ///
+    /// See [`DragAxisLock`].
+    pub axis_lock: Option<DragAxisLock>,
+
+    /// The duration over which the movement of the cursor is averaged to compute
+    /// the release velocity of a drag.
+    ///
+    /// See [`CursorDragEnd::velocity`].
+    pub release_window: Duration,
+}
+
+impl Default for CursorDragSettings {
+    /// The left, middle and right buttons drag after the cursor moved 4 logical pixels.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+            .with_button(MouseButton::Left, 4.0)
+            .with_button(MouseButton::Middle, 4.0)
+            .with_button(MouseButton::Right, 4.0)
+    }
+}
+
+impl CursorDragSettings {
+    /// Creates a new [`CursorDragSettings`] without any button.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            thresholds: Vec::new(),
+            axis_lock: None,
+            release_window: Duration::from_millis(100),
+        }
+    }
+
+    /// Locks the drags to an axis while the modifiers of `axis_lock` are held.
+    #[inline]
+    pub fn with_axis_lock(mut self, axis_lock: DragAxisLock) -> Self {
+        self.axis_lock = Some(axis_lock);
+        self
+    }
+
+    /// Averages the movement of the cursor over `release_window` to compute the
+    /// release velocity of a drag.
+    #[inline]
+    pub fn with_release_window(mut self, release_window: Duration) -> Self {
+        self.release_window = release_window;
+        self
+    }
+
+    /// Allows `button` to drag once the cursor has moved farther than `threshold`,
+    /// in logical pixels.
+    #[inline]
+    pub fn with_button(mut self, button: MouseButton, threshold: f32) -> Self {
+        self.set_button(button, threshold);
+        self
+    }
+
+    /// Allows `button` to drag once the cursor has moved farther than `threshold`,
+    /// in logical pixels.
+    pub fn set_button(&mut self, button: MouseButton, threshold: f32) {
+        match self.thresholds.iter_mut().find(|(b, _)| *b == button) {
+            Some((_, t)) => *t = threshold,
+            None => self.thresholds.push((button, threshold)),
+        }
+    }
+
+    /// Prevents `button` from dragging.
+    pub fn remove_button(&mut self, button: MouseButton) {
+        self.thresholds.retain(|(b, _)| *b != button);
+    }
+
+    /// The distance the cursor must move before the drag of `button` begins.
+    ///
+    /// Returns [`None`] if this button can't drag.
+    #[inline]
+    pub fn threshold(&self, button: MouseButton) -> Option<f32> {
+        self.thresholds
+            .iter()
+            .find_map(|&(b, threshold)| (b == button).then_some(threshold))
+    }
+}
+
+/// Locks the drags to the X or Y axis while some modifiers are held, like the
+/// constrained moves of an editor.
+///
+/// When the modifiers are pressed during a drag, the axis along which the cursor
+/// moved the most since the start of the drag is locked until they are released.
+/// The locked axis is given by [`DragSession::axis`] and the constrained movement
+/// by [`DragSession::constrained_offset`] and [`CursorDragMove::constrained_delta`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(
+///         CursorDragSettings::default().with_axis_lock(DragAxisLock::default()),
+///     );
+/// }
+///
+/// fn move_selection(mut drags: EventReader<CursorDragMove>) {
+///     for drag in drags.read() {
+///         info!("Move the selection by {:?}", drag.constrained_delta);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(move_selection);
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DragAxisLock {
+    /// The modifiers that must be held to lock the axis.
+    pub modifiers: KeyModifiers,
+    /// The space in which the axis is chosen.
+    pub space: DragAxisSpace,
+}
+
+impl Default for DragAxisLock {
+    /// Locks the axis in screen space while a shift key is held.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            modifiers: KeyModifiers {
+                shift: true,
+                ..default()
+            },
+            space: DragAxisSpace::Screen,
+        }
+    }
+}
+
+/// The space in which the axis of a [`DragAxisLock`] is chosen.
+///
+/// The locked axis constrains both the logical and the world movements; the space
+/// only decides which of them picks the axis, which matters when the camera is
+/// rotated.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum DragAxisSpace {
+    /// The axis is chosen from the movement in logical pixels.
+    #[default]
+    Screen,
+    /// The axis is chosen from the movement in the world coordinates.
+    #[cfg(feature = "2d")]
+    World,
+}
+
+/// An axis to which a drag is locked.
+///
+/// See [`DragAxisLock`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum DragAxis {
+    /// The horizontal axis.
+    X,
+    /// The vertical axis.
+    Y,
+}
+
+impl DragAxis {
+    /// Returns the axis along which `offset` is the longest.
+    #[inline]
+    pub fn dominant(offset: Vec2) -> Self {
+        if offset.x.abs() >= offset.y.abs() {
+            Self::X
+        } else {
+            Self::Y
+        }
+    }
+
+    /// Keeps only the component of `vector` along this axis.
+    #[inline]
+    pub fn constrain(self, vector: Vec2) -> Vec2 {
+        match self {
+            Self::X => Vec2::new(vector.x, 0.0),
+            Self::Y => Vec2::new(0.0, vector.y),
+        }
+    }
+}
+
+/// A resource that provides the drag sessions of the mouse buttons.
+///
+/// See [`CursorDragSettings`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn draw_selection_box(drags: Res<CursorDrags>) {
+///     if let Some(drag) = drags.get(MouseButton::Left).filter(|drag| drag.is_dragging) {
+///         let area = Rect::from_corners(drag.start.position, drag.current.position);
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(draw_selection_box);
+/// ```
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorDrags<F: CameraFilter = ()>(
+    Vec<DragSession>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
+
+impl<F: CameraFilter> Default for CursorDrags<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorDrags<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorDrags<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorDrags<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorDrags").field(&self.0).finish()
+    }
+}
+
+impl<F: CameraFilter> CursorDrags<F> {
+    /// The drag session of the given button.
+    ///
+    /// Returns [`None`] if the button isn't held.
+    #[inline]
+    pub fn get(&self, button: MouseButton) -> Option<&DragSession> {
+        self.0.iter().find(|session| session.button == button)
+    }
+
+    /// Iterates over the drag sessions of the held buttons.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &DragSession> {
+        self.0.iter()
+    }
+
+    /// Returns `true` if any button is dragging.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.0.iter().any(|session| session.is_dragging)
+    }
+}
+
+/// The drag session of a mouse button, from the moment it's pressed to the moment
+/// it's released.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DragSession {
+    /// The button that drives the drag.
+    pub button: MouseButton,
+
+    /// The location of the cursor when the button has been pressed.
+    pub start: Location,
+
+    /// The last known location of the cursor during the session.
+    pub current: Location,
+
+    /// Whether the cursor has moved farther than the threshold of the button.
+    pub is_dragging: bool,
+
+    /// The axis to which the drag is locked.
+    ///
+    /// This is [`None`] if the modifiers of the [`DragAxisLock`] aren't held.
+    pub axis: Option<DragAxis>,
+
+    /// The recent positions of the cursor, to compute the release velocity.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    history: VecDeque<DragSample>,
+}
+
+/// A position of the cursor recorded during a drag session.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DragSample {
+    /// When the position has been computed.
+    timestamp: Duration,
+    /// The position in logical pixels.
+    position: Vec2,
+    /// The position in the world coordinates.
+    #[cfg(feature = "2d")]
+    world_position: Vec2,
+}
+
+impl DragSample {
+    #[inline]
+    fn new(location: &Location) -> Self {
+        Self {
+            timestamp: location.timestamp,
+            position: location.position,
+            #[cfg(feature = "2d")]
+            world_position: location.world_position,
+        }
+    }
+}
+
+impl DragSession {
+    /// How much the cursor moved since the button has been pressed, in logical pixels.
+    #[inline]
+    pub fn offset(&self) -> Vec2 {
+        self.current.position - self.start.position
+    }
+
+    /// The [`offset`](Self::offset) constrained to the locked [`axis`](Self::axis),
+    /// in logical pixels.
+    #[inline]
+    pub fn constrained_offset(&self) -> Vec2 {
+        let offset = self.offset();
+        self.axis.map_or(offset, |axis| axis.constrain(offset))
+    }
+
+    /// How much the cursor moved in the world coordinates since the button has been
+    /// pressed.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn world_offset(&self) -> Vec2 {
+        self.current.world_position - self.start.world_position
+    }
+
+    /// The [`world_offset`](Self::world_offset) constrained to the locked
+    /// [`axis`](Self::axis).
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn constrained_world_offset(&self) -> Vec2 {
+        let offset = self.world_offset();
+        self.axis.map_or(offset, |axis| axis.constrain(offset))
+    }
+
+    /// Records the current location of the cursor, and forgets the ones older than `window`.
+    fn record(&mut self, window: Duration) {
+        let sample = DragSample::new(&self.current);
+        let cutoff = sample.timestamp.saturating_sub(window);
+
+        self.history.push_back(sample);
+        while self
+            .history
+            .get(1)
+            .is_some_and(|sample| sample.timestamp <= cutoff)
+        {
+            self.history.pop_front();
+        }
+    }
+
+    /// The average velocity over the last `window` of the position given by `position`,
+    /// per second.
+    fn average_velocity(
+        &self,
+        now: Duration,
+        window: Duration,
+        position: impl Fn(&DragSample) -> Vec2,
+    ) -> Vec2 {
+        let (Some(oldest), Some(newest)) = (self.history.front(), self.history.back()) else {
+            return Vec2::ZERO;
+        };
+
+        let cutoff = now.saturating_sub(window);
+        // The position of the cursor at the beginning of the window.
+        let reference = self
+            .history
+            .iter()
+            .rev()
+            .find(|sample| sample.timestamp <= cutoff)
+            .unwrap_or(oldest);
+
+        let dt = now
+            .saturating_sub(reference.timestamp.max(cutoff))
+            .as_secs_f32();
+        if dt <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        (position(newest) - position(reference)) / dt
+    }
+
+    /// Locks or unlocks the axis of the drag depending on the held modifiers.
+    fn update_axis(&mut self, axis_lock: Option<&DragAxisLock>, modifiers: KeyModifiers) {
+        let Some(axis_lock) = axis_lock.filter(|lock| modifiers.contains(lock.modifiers)) else {
+            self.axis = None;
+            return;
+        };
+
+        if self.axis.is_none() {
+            let offset = match axis_lock.space {
+                DragAxisSpace::Screen => self.offset(),
+                #[cfg(feature = "2d")]
+                DragAxisSpace::World => self.world_offset(),
+            };
+            self.axis = Some(DragAxis::dominant(offset));
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An event sent when the cursor has moved farther than the threshold of a held
+/// button.
+///
+/// See [`CursorDragSettings`].
+#[derive(Event, Reflect, Debug, Clone, PartialEq)]
+pub struct CursorDragStart<F: CameraFilter = ()> {
+    /// The button that drives the drag.
+    pub button: MouseButton,
+    /// The location of the cursor when the button has been pressed.
+    pub location: Location,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// An event sent when the cursor moves during a drag.
+///
+/// See [`CursorDragSettings`].
+#[derive(Event, Reflect, Debug, Clone, PartialEq)]
+pub struct CursorDragMove<F: CameraFilter = ()> {
+    /// The button that drives the drag.
+    pub button: MouseButton,
+    /// The location of the cursor.
+    pub location: Location,
+    /// How much the cursor moved since the last event of this drag, in logical pixels.
+    pub delta: Vec2,
+    /// The [`delta`](Self::delta) constrained to the locked axis of the drag.
+    ///
+    /// See [`DragAxisLock`].
+    pub constrained_delta: Vec2,
+    /// The axis to which the drag is locked.
+    pub axis: Option<DragAxis>,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// An event sent when the button of a drag is released.
+///
+/// See [`CursorDragSettings`].
+#[derive(Event, Reflect, Debug, Clone, PartialEq)]
+pub struct CursorDragEnd<F: CameraFilter = ()> {
+    /// The button that drove the drag.
+    pub button: MouseButton,
+    /// The location of the cursor when the button has been pressed.
+    pub start: Location,
+    /// The last known location of the cursor during the drag.
+    pub location: Location,
+    /// The velocity of the cursor when the button has been released, in logical
+    /// pixels per second.
+    ///
+    /// It's averaged over the [`release_window`](CursorDragSettings::release_window),
+    /// so a cursor that stopped before the release has no velocity. This gives the
+    /// inertia of a pan camera or the throw of a physics object.
+    pub velocity: Vec2,
+    /// The [`velocity`](Self::velocity) of the cursor in world units per second.
+    #[cfg(feature = "2d")]
+    pub world_velocity: Vec2,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Runs the drag sessions of the buttons and sends the drag events.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_cursor_drags<F: CameraFilter>(
+    settings: Option<Res<CursorDragSettings>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    cursor: Res<CursorLocation<F>>,
+    mut drags: ResMut<CursorDrags<F>>,
+    mut drag_start: EventWriter<CursorDragStart<F>>,
+    mut drag_move: EventWriter<CursorDragMove<F>>,
+    mut drag_end: EventWriter<CursorDragEnd<F>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    clock: LocationClock,
+) {
+    let Some((settings, buttons)) = settings.zip(buttons) else {
+        if !drags.0.is_empty() {
+            drags.0.clear();
+        }
+        return;
+    };
+
+    let location = cursor.get().filter(|location| !location.is_stale);
+    let modifiers = KeyModifiers::from_keys(keys.as_deref());
+
+    // End the sessions of the released buttons.
+    let sessions = std::mem::take(&mut drags.bypass_change_detection().0);
+    let mut changed = false;
+    for session in sessions {
+        if buttons.pressed(session.button) && settings.threshold(session.button).is_some() {
+            drags.bypass_change_detection().0.push(session);
+            continue;
+        }
+
+        changed = true;
+        if session.is_dragging {
+            let (now, _) = clock.now();
+            let window = settings.release_window;
+
+            drag_end.send(CursorDragEnd {
+                button: session.button,
+                velocity: session.average_velocity(now, window, |sample| sample.position),
+                #[cfg(feature = "2d")]
+                world_velocity: session
+                    .average_velocity(now, window, |sample| sample.world_position),
+                start: session.start,
+                location: session.current,
+                modifiers,
+                marker: PhantomData,
+            });
+        }
+    }
+
+    // Start the sessions of the pressed buttons.
+    if let Some(location) = location {
+        for &button in buttons.get_just_pressed() {
+            if settings.threshold(button).is_none() || drags.get(button).is_some() {
+                continue;
+            }
+
+            changed = true;
+            let mut session = DragSession {
+                button,
+                start: location.clone(),
+                current: location.clone(),
+                is_dragging: false,
+                axis: None,
+                history: VecDeque::new(),
+            };
+            session.record(settings.release_window);
+            drags.bypass_change_detection().0.push(session);
+        }
+    }
+
+    // Follow the cursor.
+    if let Some(location) = location {
+        for session in &mut drags.bypass_change_detection().0 {
+            let axis = session.axis;
+            let constrained_offset = session.constrained_offset();
+
+            if session.is_dragging {
+                session.update_axis(settings.axis_lock.as_ref(), modifiers);
+            }
+
+            if session.current == *location && session.axis == axis {
+                continue;
+            }
+
+            changed = true;
+            let delta = location.position - session.current.position;
+            if session.current != *location {
+                session.current = location.clone();
+                session.record(settings.release_window);
+            }
+
+            if !session.is_dragging {
+                let threshold = settings.threshold(session.button).unwrap_or_default();
+                if session.offset().length() < threshold {
+                    continue;
+                }
+
+                session.is_dragging = true;
+                session.update_axis(settings.axis_lock.as_ref(), modifiers);
+                drag_start.send(CursorDragStart {
+                    button: session.button,
+                    location: session.start.clone(),
+                    modifiers,
+                    marker: PhantomData,
+                });
+                drag_move.send(CursorDragMove {
+                    button: session.button,
+                    location: location.clone(),
+                    delta: session.offset(),
+                    constrained_delta: session.constrained_offset(),
+                    axis: session.axis,
+                    modifiers,
+                    marker: PhantomData,
+                });
+                continue;
+            }
+
+            drag_move.send(CursorDragMove {
+                button: session.button,
+                location: location.clone(),
+                delta,
+                constrained_delta: session.constrained_offset() - constrained_offset,
+                axis: session.axis,
+                modifiers,
+                marker: PhantomData,
+            });
+        }
+    }
+
+    if changed {
+        drags.set_changed();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,245 @@
+//! RTS-style scrolling when the cursor approaches the edges of the screen.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::position::WindowPositions;
+use crate::region::viewport_on_window;
+use crate::CursorTracking;
+
+/* -------------------------------------------------------------------------- */
+
+/// A plugin that scrolls the view when the cursor approaches the edges of the screen,
+/// like in a real-time strategy game.
+///
+/// It inserts the [`EdgeScrollSettings`], and the [`EdgeScroll`] resource provides
+/// the direction in which the view should scroll. The cameras with the
+/// [`EdgeScrollCamera`] component are moved accordingly.
+///
+/// This plugin requires the [`TrackCursorPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// App::new().add_plugins((TrackCursorPlugin::default(), EdgeScrollPlugin::default()));
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Camera2d, EdgeScrollCamera));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Default, Debug, Clone, Copy)]
+pub struct EdgeScrollPlugin {
+    /// The settings of the edge scrolling.
+    pub settings: EdgeScrollSettings,
+}
+
+impl EdgeScrollPlugin {
+    /// Creates a new [`EdgeScrollPlugin`] with the given settings.
+    #[inline]
+    pub fn new(settings: EdgeScrollSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Plugin for EdgeScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EdgeScrollSettings>()
+            .register_type::<EdgeScrollEasing>()
+            .register_type::<EdgeScroll>()
+            .register_type::<EdgeScrollCamera>()
+            .insert_resource(self.settings)
+            .init_resource::<EdgeScroll>()
+            .add_systems(
+                Update,
+                (update_edge_scroll, move_edge_scroll_cameras)
+                    .chain()
+                    .run_if(resource_equals(CursorTracking::Enabled)),
+            );
+    }
+}
+
+/// A resource that configures the edge scrolling of the [`EdgeScrollPlugin`].
+///
+/// The view scrolls while the cursor is closer than [`margin`](Self::margin) to an
+/// edge of the focused window. The edge scrolling is disabled while this resource
+/// doesn't exist.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn scroll_faster(mut settings: ResMut<EdgeScrollSettings>) {
+///     settings.speed = 1000.0;
+/// }
+///
+/// # let _ = IntoSystem::into_system(scroll_faster);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct EdgeScrollSettings {
+    /// The distance to the edges, in logical pixels, at which the scrolling starts.
+    pub margin: f32,
+
+    /// The speed at which the [`EdgeScrollCamera`]s move, in world units per second.
+    pub speed: f32,
+
+    /// How the scrolling speeds up as the cursor gets closer to an edge.
+    pub easing: EdgeScrollEasing,
+
+    /// The camera whose viewport edges are used instead of the window edges.
+    pub viewport: Option<Entity>,
+}
+
+impl Default for EdgeScrollSettings {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            margin: 16.0,
+            speed: 500.0,
+            easing: EdgeScrollEasing::Linear,
+            viewport: None,
+        }
+    }
+}
+
+/// How the edge scrolling speeds up as the cursor gets closer to an edge.
+///
+/// See [`EdgeScrollSettings`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum EdgeScrollEasing {
+    /// Scrolls at full speed as soon as the cursor is in the margin.
+    Constant,
+    /// The speed grows linearly through the margin.
+    #[default]
+    Linear,
+    /// The speed grows quadratically through the margin.
+    Quadratic,
+}
+
+/// A resource that provides the current edge scrolling direction.
+///
+/// X points to the right and Y points up. The length of the direction is at most 1,
+/// including in the corners, and it's zero when the cursor is not in the margin.
+///
+/// See [`EdgeScrollSettings`].
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Deref)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct EdgeScroll(Vec2);
+
+/// A marker component for the cameras moved by the edge scrolling.
+///
+/// The camera moves along its local X and Y axes.
+///
+/// See [`EdgeScrollSettings`].
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq, Default)]
+pub struct EdgeScrollCamera;
+
+impl EdgeScrollEasing {
+    /// Applies the easing to `t` in `[0, 1]`.
+    fn ease(self, t: f32) -> f32 {
+        match self {
+            EdgeScrollEasing::Constant => t.ceil(),
+            EdgeScrollEasing::Linear => t,
+            EdgeScrollEasing::Quadratic => t * t,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the [`EdgeScroll`] direction.
+fn update_edge_scroll(
+    settings: Option<Res<EdgeScrollSettings>>,
+    window_q: Query<(Entity, &Window)>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    camera_q: Query<&Camera>,
+    positions: WindowPositions,
+    mut scroll: ResMut<EdgeScroll>,
+) {
+    let direction = settings
+        .and_then(|settings| {
+            let (win_ref, window, position) =
+                positions.find(window_q.iter().filter(|(_, window)| window.focused))?;
+            let position = position.position;
+
+            let area = match settings.viewport {
+                Some(camera) => viewport_on_window(
+                    camera_q.get(camera).ok()?,
+                    win_ref,
+                    primary_window_q.get_single().ok(),
+                )?,
+                None => Rect::from_corners(Vec2::ZERO, window.size()),
+            };
+
+            if !area.contains(position) || settings.margin <= 0.0 {
+                return None;
+            }
+
+            // How deep the cursor is in the margin of each edge.
+            let depth = |distance: f32| {
+                let t = (1.0 - distance / settings.margin).clamp(0.0, 1.0);
+                settings.easing.ease(t)
+            };
+
+            let x = depth(area.max.x - position.x) - depth(position.x - area.min.x);
+            // The window Y axis points down.
+            let y = depth(position.y - area.min.y) - depth(area.max.y - position.y);
+
+            // Note: the direction is as fast in the corners as along the edges.
+            Some(Vec2::new(x, y).clamp_length_max(1.0))
+        })
+        .unwrap_or(Vec2::ZERO);
+
+    if scroll.0 != direction {
+        scroll.0 = direction;
+    }
+}
+
+/// Moves the [`EdgeScrollCamera`]s according to the [`EdgeScroll`] direction.
+fn move_edge_scroll_cameras(
+    settings: Option<Res<EdgeScrollSettings>>,
+    scroll: Res<EdgeScroll>,
+    time: Res<Time<Real>>,
+    mut camera_q: Query<&mut Transform, With<EdgeScrollCamera>>,
+) {
+    let Some(settings) = settings else {
+        return;
+    };
+
+    if scroll.0 == Vec2::ZERO {
+        return;
+    }
+
+    let delta = scroll.0.extend(0.0) * settings.speed * time.delta_secs();
+
+    for mut transform in &mut camera_q {
+        let translation = transform.rotation * delta;
+        transform.translation += translation;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,73 @@
+//! Errors of the resolution of the cursor location.
+
+use bevy::prelude::*;
+use bevy::render::camera::ViewportConversionError;
+
+/* -------------------------------------------------------------------------- */
+
+/// An event sent when the location of the cursor can't be computed through a camera
+/// that renders under the cursor.
+///
+/// The camera is skipped, so the cursor may be located on a camera below it, or
+/// on no camera at all. This event explains why.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn report_errors(mut errors: EventReader<CursorResolutionError>) {
+///     for error in errors.read() {
+///         warn!("Can't locate the cursor on {}: {:?}", error.camera, error.reason);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(report_errors);
+/// ```
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorResolutionError {
+    /// The entity id of the window that contains the cursor.
+    pub window: Entity,
+    /// The entity id of the camera through which the location can't be computed.
+    pub camera: Entity,
+    /// Why the location can't be computed.
+    pub reason: CursorResolutionErrorReason,
+}
+
+/// Why the location of the cursor can't be computed through a camera.
+///
+/// See [`CursorResolutionError`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorResolutionErrorReason {
+    /// The size of the viewport of the camera is not available yet, or its render
+    /// target is misconfigured.
+    NoViewportSize,
+    /// The transform or the projection of the camera contains invalid values, like `NaN`.
+    InvalidData,
+}
+
+impl From<ViewportConversionError> for CursorResolutionErrorReason {
+    #[inline]
+    fn from(error: ViewportConversionError) -> Self {
+        match error {
+            ViewportConversionError::NoViewportSize => Self::NoViewportSize,
+            ViewportConversionError::PastNearPlane
+            | ViewportConversionError::PastFarPlane
+            | ViewportConversionError::InvalidData => Self::InvalidData,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
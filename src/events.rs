@@ -0,0 +1,194 @@
+//! Events sent when the cursor moves over or out of a window or a camera.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{CameraFilter, CursorLocation, KeyModifiers};
+
+/* -------------------------------------------------------------------------- */
+
+/// An event sent when the cursor moves over a window.
+///
+/// This event is both sent as a buffered event, readable with an [`EventReader`],
+/// and triggered on the window entity, so it can be observed with an [`Observer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn(Window::default())
+///         .observe(|trigger: Trigger<CursorOverWindow>| {
+///             info!("The cursor is over {:?}", trigger.entity());
+///         });
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`EventReader`]: https://docs.rs/bevy/0.15.0/bevy/ecs/event/struct.EventReader.html
+/// [`Observer`]: https://docs.rs/bevy/0.15.0/bevy/ecs/observer/struct.Observer.html
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorOverWindow<F: CameraFilter = ()> {
+    /// The entity id of the window.
+    pub window: Entity,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// An event sent when the cursor moves out of a window.
+///
+/// This event is both sent as a buffered event and triggered on the window entity.
+/// See [`CursorOverWindow`].
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorOutWindow<F: CameraFilter = ()> {
+    /// The entity id of the window.
+    pub window: Entity,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// An event sent when the cursor moves over a camera, that is when this camera
+/// becomes the one used to resolve the [`CursorLocation`].
+///
+/// This event is both sent as a buffered event and triggered on the camera entity.
+/// See [`CursorOverWindow`].
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorOverCamera<F: CameraFilter = ()> {
+    /// The entity id of the camera.
+    pub camera: Entity,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// An event sent when the cursor moves out of a camera, that is when this camera
+/// is no longer the one used to resolve the [`CursorLocation`].
+///
+/// This event is both sent as a buffered event and triggered on the camera entity.
+/// See [`CursorOverWindow`].
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorOutCamera<F: CameraFilter = ()> {
+    /// The entity id of the camera.
+    pub camera: Entity,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Sends and triggers the events when the window or the camera of the cursor changes.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn send_cursor_events<F: CameraFilter>(
+    cursor: Res<CursorLocation<F>>,
+    mut commands: Commands,
+    mut over_window: EventWriter<CursorOverWindow<F>>,
+    mut out_window: EventWriter<CursorOutWindow<F>>,
+    mut over_camera: EventWriter<CursorOverCamera<F>>,
+    mut out_camera: EventWriter<CursorOutCamera<F>>,
+    mut previous: Local<Option<(Entity, Entity)>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+) {
+    let modifiers = KeyModifiers::from_keys(keys.as_deref());
+
+    let current = cursor
+        .get()
+        .filter(|location| !location.is_stale)
+        .map(|location| (location.window, location.camera));
+
+    let Some(previous) = std::mem::replace(&mut *previous, current) else {
+        // There was no previous location.
+        if let Some((window, camera)) = current {
+            over_window.send(CursorOverWindow::new(window, modifiers));
+            commands.trigger_targets(CursorOverWindow::<F>::new(window, modifiers), window);
+            over_camera.send(CursorOverCamera::new(camera, modifiers));
+            commands.trigger_targets(CursorOverCamera::<F>::new(camera, modifiers), camera);
+        }
+        return;
+    };
+
+    let (previous_window, previous_camera) = previous;
+    let (current_window, current_camera) = current.unzip();
+
+    if current_camera != Some(previous_camera) {
+        out_camera.send(CursorOutCamera::new(previous_camera, modifiers));
+        commands.trigger_targets(
+            CursorOutCamera::<F>::new(previous_camera, modifiers),
+            previous_camera,
+        );
+    }
+
+    if current_window != Some(previous_window) {
+        out_window.send(CursorOutWindow::new(previous_window, modifiers));
+        commands.trigger_targets(
+            CursorOutWindow::<F>::new(previous_window, modifiers),
+            previous_window,
+        );
+
+        if let Some(window) = current_window {
+            over_window.send(CursorOverWindow::new(window, modifiers));
+            commands.trigger_targets(CursorOverWindow::<F>::new(window, modifiers), window);
+        }
+    }
+
+    if let Some(camera) = current_camera.filter(|&camera| camera != previous_camera) {
+        over_camera.send(CursorOverCamera::new(camera, modifiers));
+        commands.trigger_targets(CursorOverCamera::<F>::new(camera, modifiers), camera);
+    }
+}
+
+impl<F: CameraFilter> CursorOverWindow<F> {
+    #[inline]
+    fn new(window: Entity, modifiers: KeyModifiers) -> Self {
+        Self {
+            window,
+            modifiers,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> CursorOutWindow<F> {
+    #[inline]
+    fn new(window: Entity, modifiers: KeyModifiers) -> Self {
+        Self {
+            window,
+            modifiers,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> CursorOverCamera<F> {
+    #[inline]
+    fn new(camera: Entity, modifiers: KeyModifiers) -> Self {
+        Self {
+            camera,
+            modifiers,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> CursorOutCamera<F> {
+    #[inline]
+    fn new(camera: Entity, modifiers: KeyModifiers) -> Self {
+        Self {
+            camera,
+            modifiers,
+            marker: PhantomData,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
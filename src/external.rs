@@ -0,0 +1,505 @@
+//! Pointers injected by the application, like the cursors of remote players.
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use crate::stamp::LocationClock;
+use crate::{
+    camera_location, resolve_location, window_cameras, CameraFilter, CameraQuery, CameraSelection,
+    Location, PointerKind,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource where the application inserts the positions of pointers that aren't
+/// driven by this app, like the cursors of the other users of a collaborative tool.
+///
+/// Each pointer is identified by an id chosen by the application. Its position is
+/// resolved through the same cameras as the cursor into the
+/// [`ExternalPointerLocations`], so the remote cursors have the same semantics as
+/// the local one. The resolved [`Location`]s have the [`PointerKind::Virtual`] kind
+/// with the id of the pointer.
+///
+/// The pointers of a stereo or XR setup are inserted the same way: a pointer per eye
+/// with [`ExternalPointer::Camera`], and a pointer per controller with
+/// `ExternalPointer::Ray` (feature `3d`).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// # #[derive(Event)]
+/// # struct RemoteCursorMoved { player: u64, world_position: Vec3 }
+/// fn receive_remote_cursors(
+///     mut events: EventReader<RemoteCursorMoved>,
+///     mut pointers: ResMut<ExternalPointers>,
+/// ) {
+///     for event in events.read() {
+///         pointers.set(event.player, ExternalPointer::World(event.world_position));
+///     }
+/// }
+///
+/// fn draw_remote_cursors(locations: Res<ExternalPointerLocations>) {
+///     for (player, location) in locations.iter() {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(receive_remote_cursors);
+/// # let _ = IntoSystem::into_system(draw_remote_cursors);
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ExternalPointers {
+    pointers: Vec<(u64, ExternalPointer)>,
+}
+
+/// The position of a pointer of the [`ExternalPointers`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ExternalPointer {
+    /// A logical position on the primary window.
+    Window(Vec2),
+    /// A position in the world, seen through the topmost camera of the primary window
+    /// that renders it.
+    World(Vec3),
+    /// A logical position on the viewport of the given camera, even if the camera
+    /// doesn't render to a window.
+    ///
+    /// This gives a pointer to each eye of a stereo or XR camera rig, each with the
+    /// [`ray`] of its own camera.
+    ///
+    /// [`ray`]: crate::Location::ray
+    Camera(Entity, Vec2),
+    /// A ray in the world, like the aim of an XR controller.
+    ///
+    /// The position of the pointer is the origin of the ray, or its first point in
+    /// front of the camera if it starts behind the near plane like the ray of an eye,
+    /// seen through the topmost camera of the primary window that renders it. Its
+    /// [`ray`] is this ray instead of the ray of the camera.
+    ///
+    /// [`ray`]: crate::Location::ray
+    #[cfg(feature = "3d")]
+    Ray(Ray3d),
+}
+
+impl ExternalPointers {
+    /// Sets the position of a pointer, adding it if it doesn't exist.
+    #[inline]
+    pub fn set(&mut self, id: u64, pointer: ExternalPointer) {
+        match self.pointers.iter_mut().find(|(i, _)| *i == id) {
+            Some((_, p)) => *p = pointer,
+            None => self.pointers.push((id, pointer)),
+        }
+    }
+
+    /// Removes a pointer.
+    #[inline]
+    pub fn remove(&mut self, id: u64) {
+        self.pointers.retain(|(i, _)| *i != id);
+    }
+
+    /// Removes all the pointers.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.pointers.clear();
+    }
+
+    /// The position of a pointer.
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<ExternalPointer> {
+        self.pointers
+            .iter()
+            .find(|(i, _)| *i == id)
+            .map(|&(_, pointer)| pointer)
+    }
+
+    /// Iterates over the pointers and their id.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u64, ExternalPointer)> + '_ {
+        self.pointers.iter().copied()
+    }
+}
+
+/// A resource that enables the interpolation of the [`ExternalPointers`] between
+/// their updates.
+///
+/// The remote cursors are usually updated at a lower rate than the frame rate, and
+/// at irregular intervals. When this resource exists, the positions set in the
+/// [`ExternalPointers`] are buffered with the time they have been received, and
+/// the [`ExternalPointerLocations`] are resolved [`delay`](Self::delay) in the past,
+/// interpolated between the two surrounding positions.
+///
+/// When no position has been received for longer than the delay, the position is
+/// extrapolated from the last two positions for at most
+/// [`max_extrapolation`](Self::max_extrapolation), then stays on the last position.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     // The remote cursors are sent 10 times per second.
+///     commands.insert_resource(ExternalPointerInterpolation::new(Duration::from_millis(150)));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ExternalPointerInterpolation {
+    /// How far in the past the pointers are shown.
+    ///
+    /// It should be a bit longer than the interval between two updates, so there
+    /// is usually a position after the time shown to interpolate to.
+    pub delay: Duration,
+
+    /// How long the position is extrapolated when the updates are late.
+    pub max_extrapolation: Duration,
+}
+
+impl ExternalPointerInterpolation {
+    /// Creates a new [`ExternalPointerInterpolation`] with the given delay, that
+    /// extrapolates the positions for as long as the delay.
+    #[inline]
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            max_extrapolation: delay,
+        }
+    }
+}
+
+/// A resource that provides the [`Location`] of the [`ExternalPointers`].
+///
+/// A pointer has no location if no camera renders at its position.
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ExternalPointerLocations<F: CameraFilter = ()>(
+    Vec<(u64, Location)>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
+
+impl<F: CameraFilter> Default for ExternalPointerLocations<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for ExternalPointerLocations<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for ExternalPointerLocations<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for ExternalPointerLocations<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ExternalPointerLocations")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<F: CameraFilter> ExternalPointerLocations<F> {
+    /// The location of a pointer.
+    ///
+    /// Returns [`None`] if the pointer doesn't exist or if no camera renders at its position.
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<&Location> {
+        self.0
+            .iter()
+            .find(|(i, _)| *i == id)
+            .map(|(_, location)| location)
+    }
+
+    /// Iterates over the locations of the pointers and their id.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u64, &Location)> {
+        self.0.iter().map(|(id, location)| (*id, location))
+    }
+
+    /// Returns `true` if no pointer has a location.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates mutably over the locations of the pointers.
+    #[cfg(any(feature = "2d", feature = "3d", feature = "ui"))]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Location> {
+        self.0.iter_mut().map(|(_, location)| location)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The positions of the [`ExternalPointers`] received over time, to interpolate them.
+#[derive(Resource, Default)]
+pub(crate) struct ExternalPointerBuffers(HashMap<u64, VecDeque<(Duration, ExternalPointer)>>);
+
+impl ExternalPointerBuffers {
+    /// The position of a pointer at the given time.
+    fn sample(
+        &self,
+        id: u64,
+        time: Duration,
+        max_extrapolation: Duration,
+    ) -> Option<ExternalPointer> {
+        let buffer = self.0.get(&id)?;
+        let &(last_time, last) = buffer.back()?;
+
+        if time >= last_time {
+            let Some(&(prev_time, prev)) = buffer.iter().rev().nth(1) else {
+                return Some(last);
+            };
+            if prev_time == last_time {
+                return Some(last);
+            }
+            let elapsed = (time - last_time).min(max_extrapolation);
+            let t = 1.0 + elapsed.as_secs_f32() / (last_time - prev_time).as_secs_f32();
+            return Some(prev.lerp(last, t));
+        }
+
+        let next = buffer.iter().position(|&(t, _)| t > time)?;
+        let (b_time, b) = buffer[next];
+        let Some(&(a_time, a)) = next.checked_sub(1).map(|i| &buffer[i]) else {
+            return Some(b);
+        };
+
+        let t = (time - a_time).as_secs_f32() / (b_time - a_time).as_secs_f32();
+        Some(a.lerp(b, t))
+    }
+}
+
+impl ExternalPointer {
+    /// Interpolates between two positions, or extrapolates when `t` is greater than `1`.
+    ///
+    /// The positions of different spaces aren't interpolated: `self` is returned
+    /// until `t` reaches `1`.
+    fn lerp(self, other: Self, t: f32) -> Self {
+        match (self, other) {
+            (Self::Window(a), Self::Window(b)) => Self::Window(a.lerp(b, t)),
+            (Self::World(a), Self::World(b)) => Self::World(a.lerp(b, t)),
+            (Self::Camera(a_ref, a), Self::Camera(b_ref, b)) if a_ref == b_ref => {
+                Self::Camera(a_ref, a.lerp(b, t))
+            }
+            #[cfg(feature = "3d")]
+            (Self::Ray(a), Self::Ray(b)) => Self::Ray(Ray3d {
+                origin: a.origin.lerp(b.origin, t),
+                direction: a.direction.slerp(b.direction, t),
+            }),
+            _ if t < 1.0 => self,
+            _ => other,
+        }
+    }
+}
+
+/// Records the positions of the [`ExternalPointers`] into the [`ExternalPointerBuffers`].
+pub(crate) fn buffer_external_pointers(
+    interpolation: Option<Res<ExternalPointerInterpolation>>,
+    pointers: Res<ExternalPointers>,
+    clock: LocationClock,
+    mut buffers: ResMut<ExternalPointerBuffers>,
+) {
+    let Some(interpolation) = interpolation else {
+        buffers.0.clear();
+        return;
+    };
+
+    let (now, _) = clock.now();
+    let time = now.saturating_sub(interpolation.delay);
+
+    buffers
+        .0
+        .retain(|id, _| pointers.pointers.iter().any(|(i, _)| i == id));
+
+    for (id, pointer) in pointers.iter() {
+        let buffer = buffers.0.entry(id).or_default();
+
+        if buffer.back().is_none_or(|&(_, last)| last != pointer) {
+            buffer.push_back((now, pointer));
+        }
+
+        // Keep one position before the time shown, to interpolate from it.
+        while buffer.len() > 2 && buffer[1].0 <= time {
+            buffer.pop_front();
+        }
+    }
+}
+
+/// Resolves the [`ExternalPointers`] into the [`ExternalPointerLocations`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn resolve_external_pointers<F: CameraFilter>(
+    pointers: Res<ExternalPointers>,
+    interpolation: Option<Res<ExternalPointerInterpolation>>,
+    buffers: Res<ExternalPointerBuffers>,
+    window_q: Query<(Entity, &Window), With<PrimaryWindow>>,
+    camera_q: CameraQuery<F::Filter>,
+    selection: CameraSelection<F>,
+    clock: LocationClock,
+    mut locations: ResMut<ExternalPointerLocations<F>>,
+) {
+    if pointers.pointers.is_empty() && locations.0.is_empty() {
+        return;
+    }
+
+    let now = clock.now();
+    let primary_window = window_q.get_single().ok();
+
+    let resolved = pointers
+        .iter()
+        .filter_map(|(id, pointer)| {
+            let (win_ref, window) = primary_window?;
+
+            let pointer = match interpolation {
+                Some(ref interpolation) => buffers
+                    .sample(
+                        id,
+                        now.0.saturating_sub(interpolation.delay),
+                        interpolation.max_extrapolation,
+                    )
+                    .unwrap_or(pointer),
+                None => pointer,
+            };
+
+            // The position on the primary window of the world point seen by a camera.
+            let window_position =
+                |world_point: &dyn Fn(&GlobalTransform, &Camera) -> Option<Vec3>| {
+                    window_cameras(win_ref, true, &camera_q, &selection)
+                        .into_iter()
+                        // The cameras that render to an image have no position on the window.
+                        .filter(|&(_, _, _, display)| display.is_none())
+                        .find_map(|(_, cam_t, camera, _)| {
+                            let world_position = world_point(cam_t, camera)?;
+                            let position = camera.world_to_viewport(cam_t, world_position).ok()?;
+                            let offset = camera
+                                .logical_viewport_rect()
+                                .map_or(Vec2::ZERO, |viewport| viewport.min);
+                            Some(position + offset)
+                        })
+                };
+
+            let previous = locations.get(id);
+
+            // The location at a position on the primary window.
+            let resolve_at = |position: Vec2| {
+                resolve_location(
+                    win_ref,
+                    window,
+                    true,
+                    position,
+                    &camera_q,
+                    &selection,
+                    previous.map(|location| location.camera),
+                )
+            };
+
+            let mut location = match pointer {
+                ExternalPointer::Window(position) => resolve_at(position)?,
+                ExternalPointer::World(world_position) => {
+                    resolve_at(window_position(&|_, _| Some(world_position))?)?
+                }
+                ExternalPointer::Camera(camera_ref, position) => {
+                    let (_, cam_t, camera, _) = camera_q.get(camera_ref).ok()?;
+                    let location =
+                        camera_location(win_ref, camera_ref, cam_t, camera, position).ok()?;
+                    Location {
+                        scale_factor: camera.target_scaling_factor().unwrap_or(1.0),
+                        render_layers: selection
+                            .layers_q
+                            .get(camera_ref)
+                            .cloned()
+                            .unwrap_or_default(),
+                        ..location
+                    }
+                }
+                #[cfg(feature = "3d")]
+                ExternalPointer::Ray(ray) => Location {
+                    ray,
+                    ..resolve_at(window_position(&|cam_t, camera| {
+                        point_in_front(ray, cam_t, camera)
+                    })?)?
+                },
+            };
+            location.kind = PointerKind::Virtual(id);
+            location.stamp(
+                previous.filter(|previous| previous.camera == location.camera),
+                now,
+            );
+
+            Some((id, location))
+        })
+        .collect::<Vec<_>>();
+
+    if locations.0 != resolved {
+        locations.0 = resolved;
+    }
+}
+
+/// Returns the first point of the ray in front of the near plane of the camera, or
+/// [`None`] if the ray never is.
+///
+/// This is the origin of the ray, unless it starts at the camera, like the ray of
+/// an eye.
+#[cfg(feature = "3d")]
+fn point_in_front(ray: Ray3d, cam_t: &GlobalTransform, camera: &Camera) -> Option<Vec3> {
+    let view_from_world = cam_t.affine().inverse();
+    let origin = view_from_world.transform_point3(ray.origin);
+    let direction = view_from_world.transform_vector3(*ray.direction);
+
+    // The camera looks toward -Z, and the near plane is at the depth 1 in NDC.
+    let near = camera.clip_from_view().inverse().project_point3(Vec3::Z).z;
+    // Note: a point exactly on the near plane may be rejected by the projection.
+    let depth = near - near.abs().max(1.0) * 1e-3;
+
+    if origin.z <= depth {
+        return Some(ray.origin);
+    }
+    if direction.z >= 0.0 {
+        return None;
+    }
+
+    Some(ray.get_point((depth - origin.z) / direction.z))
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,80 @@
+//! Snapshot of the cursor location for the fixed timestep.
+
+use bevy::prelude::*;
+
+use crate::{CameraFilter, CursorLocation};
+
+/* -------------------------------------------------------------------------- */
+
+/// A snapshot of the [`CursorLocation`] for the systems that run in the fixed timestep.
+///
+/// This resource is captured at the start of each fixed step, during the [`FixedFirst`]
+/// schedule, so systems running in [`FixedUpdate`] see the same value for the whole step,
+/// regardless of how many steps run during a frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn aim_turret(cursor: Res<FixedCursorLocation>) {
+///     if let Some(position) = cursor.position() {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(aim_turret);
+/// ```
+///
+/// [`FixedFirst`]: https://docs.rs/bevy/0.15.0/bevy/app/struct.FixedFirst.html
+/// [`FixedUpdate`]: https://docs.rs/bevy/0.15.0/bevy/app/struct.FixedUpdate.html
+#[derive(Resource, Reflect, Deref)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FixedCursorLocation<F: CameraFilter = ()>(CursorLocation<F>);
+
+impl<F: CameraFilter> Default for FixedCursorLocation<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(CursorLocation::default())
+    }
+}
+
+impl<F: CameraFilter> Clone for FixedCursorLocation<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: CameraFilter> PartialEq for FixedCursorLocation<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for FixedCursorLocation<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FixedCursorLocation").field(&self.0).finish()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Copies the [`CursorLocation`] into the [`FixedCursorLocation`].
+pub(crate) fn snapshot_fixed_cursor_location<F: CameraFilter>(
+    cursor: Res<CursorLocation<F>>,
+    mut fixed: ResMut<FixedCursorLocation<F>>,
+) {
+    if fixed.0 != *cursor {
+        fixed.0.clone_from(&cursor);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,36 @@
+//! Restriction of the tracking to the focused window.
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that restricts the tracking of the cursor to the focused window.
+///
+/// When this resource exists, the [`CursorLocation`] is only resolved for the window
+/// that has the focus (see [`Window::focused`]). A background window doesn't steal
+/// the hover state just because the OS still reports the cursor position on it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(FocusedWindowOnly);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+/// [`Window::focused`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#structfield.focused
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FocusedWindowOnly;
+
+/* -------------------------------------------------------------------------- */
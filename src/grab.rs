@@ -0,0 +1,162 @@
+//! Tracking of the cursor while it is locked.
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use bevy::window::CursorGrabMode;
+
+use crate::input::OptionalEventReader;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the tracking of the cursor while it is locked.
+///
+/// When the [`CursorGrabMode::Locked`] grab mode is active, the position reported
+/// by the window stops being useful. When this resource exists, the plugin
+/// accumulates the [`MouseMotion`] deltas into a virtual position instead, so
+/// [`CursorLocation`] keeps being updated. Such a location has its
+/// [`grabbed`](crate::Location::grabbed) flag set.
+///
+/// The value of this resource defines how the virtual position behaves when it
+/// reaches the edges of the window.
+///
+/// When the cursor is unlocked, the virtual position is kept until the window reports
+/// the position of the cursor again, so the location doesn't vanish in between.
+///
+/// On the web, this resource is inserted by default with [`Clamp`](Self::Clamp), as
+/// the browser doesn't report the position of a locked pointer. When the browser
+/// releases the lock by itself, like when Escape is pressed, the grab mode of the
+/// window must be reset by the application.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(GrabbedCursorTracking::Wrap);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`CursorGrabMode::Locked`]: https://docs.rs/bevy/0.15.0/bevy/window/enum.CursorGrabMode.html#variant.Locked
+/// [`MouseMotion`]: https://docs.rs/bevy/0.15.0/bevy/input/mouse/struct.MouseMotion.html
+/// [`CursorLocation`]: crate::CursorLocation
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GrabbedCursorTracking {
+    /// The virtual position is clamped to the window area.
+    #[default]
+    Clamp,
+    /// The virtual position wraps around to the opposite edge of the window.
+    Wrap,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The virtual positions of the cursor on the windows where it is locked.
+#[derive(Resource, Default)]
+pub(crate) struct GrabbedCursors(EntityHashMap<GrabbedCursor>);
+
+/// The virtual position of a locked cursor.
+struct GrabbedCursor {
+    /// The position in logical pixels.
+    position: Vec2,
+    /// The scale factor of the window the position has been computed with.
+    scale_factor: f32,
+    /// Whether the cursor is still locked, or has been unlocked and waits for the
+    /// window to report its position.
+    locked: bool,
+}
+
+impl GrabbedCursors {
+    /// The virtual position of the cursor on the given window, in logical pixels.
+    #[inline]
+    pub(crate) fn get(&self, window: Entity) -> Option<Vec2> {
+        self.0.get(&window).map(|cursor| cursor.position)
+    }
+
+    /// Returns `true` if the cursor is locked on the given window.
+    #[inline]
+    pub(crate) fn is_locked(&self, window: Entity) -> bool {
+        self.0.get(&window).is_some_and(|cursor| cursor.locked)
+    }
+}
+
+/// Accumulates the mouse motion into the virtual position of the locked cursors.
+pub(crate) fn update_grabbed_cursors(
+    tracking: Option<Res<GrabbedCursorTracking>>,
+    window_q: Query<(Entity, &Window)>,
+    mut mouse_motion: OptionalEventReader<MouseMotion>,
+    mut grabbed: ResMut<GrabbedCursors>,
+) {
+    let delta = mouse_motion.read().map(|motion| motion.delta).sum::<Vec2>();
+
+    let Some(tracking) = tracking else {
+        grabbed.0.clear();
+        return;
+    };
+
+    // Forget the windows that are no longer locked, once they report the position of
+    // the cursor again.
+    grabbed.0.retain(|&window_ref, cursor| {
+        let Ok((_, window)) = window_q.get(window_ref) else {
+            return false;
+        };
+
+        cursor.locked = window.cursor_options.grab_mode == CursorGrabMode::Locked;
+        cursor.locked || window.cursor_position().is_none()
+    });
+
+    for (window_ref, window) in &window_q {
+        if window.cursor_options.grab_mode != CursorGrabMode::Locked {
+            continue;
+        }
+
+        let size = window.size();
+
+        // The window is minimized.
+        if size.cmple(Vec2::ZERO).any() {
+            continue;
+        }
+
+        // Starts from the last known cursor position, or the center of the window.
+        let cursor = grabbed
+            .0
+            .entry(window_ref)
+            .or_insert_with(|| GrabbedCursor {
+                position: window.cursor_position().unwrap_or(size / 2.0),
+                scale_factor: window.scale_factor(),
+                locked: true,
+            });
+        cursor.locked = true;
+
+        // Keep the same physical position if the scale factor of the window has changed.
+        if cursor.scale_factor != window.scale_factor() {
+            cursor.position *= cursor.scale_factor / window.scale_factor();
+            cursor.scale_factor = window.scale_factor();
+        }
+
+        let position = &mut cursor.position;
+
+        // The motion events are not bound to a window, only the focused one receives them.
+        if !window.focused {
+            continue;
+        }
+
+        *position += delta;
+
+        *position = match *tracking {
+            GrabbedCursorTracking::Clamp => position.clamp(Vec2::ZERO, size),
+            GrabbedCursorTracking::Wrap => position.rem_euclid(size),
+        };
+    }
+}
+
+/* -------------------------------------------------------------------------- */
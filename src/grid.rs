@@ -0,0 +1,404 @@
+//! Coordinates of the cursor on isometric and hexagonal grids.
+
+use bevy::prelude::*;
+
+use crate::Location;
+
+/* -------------------------------------------------------------------------- */
+
+/// An isometric grid of diamond-shaped tiles.
+///
+/// The X axis of the grid goes to the upper right and its Y axis goes to the upper left.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// const GRID: IsoGrid = IsoGrid::new(Vec2::new(64.0, 32.0));
+///
+/// fn hovered_tile(cursor: Res<CursorLocation>) {
+///     if let Some(tile) = cursor.get().map(|location| location.to_iso_tile(&GRID)) {
+///         info!("Hovered tile: {tile}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(hovered_tile);
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct IsoGrid {
+    /// The width and the height of a tile in world units.
+    pub tile_size: Vec2,
+
+    /// The world position of the center of the tile `(0, 0)`.
+    pub origin: Vec2,
+}
+
+impl IsoGrid {
+    /// Creates a new [`IsoGrid`] whose tile `(0, 0)` is centered on the origin.
+    #[inline]
+    pub const fn new(tile_size: Vec2) -> Self {
+        Self {
+            tile_size,
+            origin: Vec2::ZERO,
+        }
+    }
+
+    /// Returns the tile that contains the world position.
+    pub fn tile(&self, world_position: Vec2) -> IVec2 {
+        let position = (world_position - self.origin) / self.tile_size;
+
+        // Rotate the diamonds back into squares.
+        let x = position.x + position.y;
+        let y = position.y - position.x;
+        Vec2::new(x, y).round().as_ivec2()
+    }
+
+    /// Returns the world position of the center of a tile.
+    pub fn tile_center(&self, tile: IVec2) -> Vec2 {
+        let tile = tile.as_vec2();
+        let position = Vec2::new(tile.x - tile.y, tile.x + tile.y) / 2.0;
+        self.origin + position * self.tile_size
+    }
+
+    /// Returns the `(column, row)` coordinates of the tile of a staggered layout that
+    /// contains the world position.
+    ///
+    /// In a staggered layout, the diamonds form rows (or columns) that are shifted by
+    /// half a tile every other row (or column), like the staggered maps of Tiled.
+    /// The columns go to the right and the rows go upward.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// let grid = IsoGrid::new(Vec2::new(64.0, 32.0));
+    ///
+    /// // The odd rows are shifted half a tile to the right.
+    /// assert_eq!(grid.staggered_tile(Vec2::new(0.0, 0.0), IsoStagger::OddRows), IVec2::new(0, 0));
+    /// assert_eq!(grid.staggered_tile(Vec2::new(32.0, 16.0), IsoStagger::OddRows), IVec2::new(0, 1));
+    /// assert_eq!(grid.staggered_tile(Vec2::new(-32.0, 16.0), IsoStagger::OddRows), IVec2::new(-1, 1));
+    /// assert_eq!(grid.staggered_tile(Vec2::new(64.0, 32.0), IsoStagger::OddRows), IVec2::new(1, 2));
+    ///
+    /// // The even rows are shifted half a tile to the right.
+    /// assert_eq!(grid.staggered_tile(Vec2::new(32.0, 0.0), IsoStagger::EvenRows), IVec2::new(0, 0));
+    /// assert_eq!(grid.staggered_tile(Vec2::new(0.0, 16.0), IsoStagger::EvenRows), IVec2::new(0, 1));
+    ///
+    /// // The odd columns are shifted half a tile upward.
+    /// assert_eq!(grid.staggered_tile(Vec2::new(32.0, 16.0), IsoStagger::OddColumns), IVec2::new(1, 0));
+    /// assert_eq!(grid.staggered_tile(Vec2::new(64.0, 0.0), IsoStagger::OddColumns), IVec2::new(2, 0));
+    ///
+    /// // The tile contains the points near its corners.
+    /// for stagger in [
+    ///     IsoStagger::OddRows,
+    ///     IsoStagger::EvenRows,
+    ///     IsoStagger::OddColumns,
+    ///     IsoStagger::EvenColumns,
+    /// ] {
+    ///     let tile = IVec2::new(3, -5);
+    ///     let center = grid.staggered_tile_center(tile, stagger);
+    ///     for offset in [Vec2::X * 30.0, Vec2::NEG_X * 30.0, Vec2::Y * 14.0, Vec2::NEG_Y * 14.0] {
+    ///         assert_eq!(grid.staggered_tile(center + offset, stagger), tile);
+    ///     }
+    /// }
+    /// ```
+    pub fn staggered_tile(&self, world_position: Vec2, stagger: IsoStagger) -> IVec2 {
+        let half_size = self.tile_size / 2.0;
+
+        // The shifted rows (or columns) of the even layouts are the unshifted ones of
+        // the odd layouts.
+        let offset = match stagger {
+            IsoStagger::OddRows | IsoStagger::OddColumns => Vec2::ZERO,
+            IsoStagger::EvenRows => Vec2::new(half_size.x, 0.0),
+            IsoStagger::EvenColumns => Vec2::new(0.0, half_size.y),
+        };
+
+        // The diamond tile, whose center is at `(x - y, x + y)` half tiles.
+        let IVec2 { x, y } = self.tile(world_position - offset);
+        let (across, along) = (x - y, x + y);
+
+        match stagger {
+            IsoStagger::OddRows => IVec2::new((across - (along & 1)) / 2, along),
+            IsoStagger::EvenRows => IVec2::new((across + (along & 1)) / 2, along),
+            IsoStagger::OddColumns => IVec2::new(across, (along - (across & 1)) / 2),
+            IsoStagger::EvenColumns => IVec2::new(across, (along + (across & 1)) / 2),
+        }
+    }
+
+    /// Returns the world position of the center of a tile of a staggered layout.
+    ///
+    /// See [`staggered_tile`](Self::staggered_tile).
+    pub fn staggered_tile_center(&self, tile: IVec2, stagger: IsoStagger) -> Vec2 {
+        let half_size = self.tile_size / 2.0;
+        let IVec2 { x: column, y: row } = tile;
+
+        let position = match stagger {
+            IsoStagger::OddRows => Vec2::new(2.0 * column as f32 + (row & 1) as f32, row as f32),
+            IsoStagger::EvenRows => {
+                Vec2::new(2.0 * column as f32 + (1 - (row & 1)) as f32, row as f32)
+            }
+            IsoStagger::OddColumns => {
+                Vec2::new(column as f32, 2.0 * row as f32 + (column & 1) as f32)
+            }
+            IsoStagger::EvenColumns => {
+                Vec2::new(column as f32, 2.0 * row as f32 + (1 - (column & 1)) as f32)
+            }
+        };
+
+        self.origin + position * half_size
+    }
+}
+
+/// The staggered layout of an [`IsoGrid`], that tells which rows or columns are
+/// shifted by half a tile.
+///
+/// See [`IsoGrid::staggered_tile`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum IsoStagger {
+    /// The odd rows are shifted half a tile to the right.
+    #[default]
+    OddRows,
+    /// The even rows are shifted half a tile to the right.
+    EvenRows,
+    /// The odd columns are shifted half a tile upward.
+    OddColumns,
+    /// The even columns are shifted half a tile upward.
+    EvenColumns,
+}
+
+/// A grid of regular hexagons.
+///
+/// The cells are identified by their axial coordinates `(q, r)`, and the R axis
+/// goes upward.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// const GRID: HexGrid = HexGrid::new(32.0, HexOrientation::PointyTop);
+///
+/// fn hovered_hex(cursor: Res<CursorLocation>) {
+///     if let Some(hex) = cursor.get().map(|location| location.to_hex(&GRID, HexCoords::Axial)) {
+///         info!("Hovered hex: {hex}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(hovered_hex);
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HexGrid {
+    /// The distance between the center and a corner of a hexagon, in world units.
+    pub size: f32,
+
+    /// The orientation of the hexagons.
+    pub orientation: HexOrientation,
+
+    /// The world position of the center of the hexagon `(0, 0)`.
+    pub origin: Vec2,
+}
+
+/// The orientation of the hexagons of a [`HexGrid`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum HexOrientation {
+    /// The hexagons have a corner at the top, and form rows.
+    #[default]
+    PointyTop,
+    /// The hexagons have an edge at the top, and form columns.
+    FlatTop,
+}
+
+/// The coordinate system of the cells of a [`HexGrid`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum HexCoords {
+    /// The axial coordinates `(q, r)`.
+    #[default]
+    Axial,
+    /// The offset coordinates `(column, row)` where the odd rows (or columns for
+    /// [`FlatTop`](HexOrientation::FlatTop) hexagons) are shoved.
+    OddOffset,
+    /// The offset coordinates `(column, row)` where the even rows (or columns for
+    /// [`FlatTop`](HexOrientation::FlatTop) hexagons) are shoved.
+    EvenOffset,
+}
+
+pub(crate) const SQRT_3: f32 = 1.732_050_8;
+
+impl HexGrid {
+    /// Creates a new [`HexGrid`] whose hexagon `(0, 0)` is centered on the origin.
+    #[inline]
+    pub const fn new(size: f32, orientation: HexOrientation) -> Self {
+        Self {
+            size,
+            orientation,
+            origin: Vec2::ZERO,
+        }
+    }
+
+    /// Returns the axial coordinates of the hexagon that contains the world position.
+    pub fn axial(&self, world_position: Vec2) -> IVec2 {
+        let Vec2 { x, y } = (world_position - self.origin) / self.size;
+
+        let (q, r) = match self.orientation {
+            HexOrientation::PointyTop => (SQRT_3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y),
+            HexOrientation::FlatTop => (2.0 / 3.0 * x, -x / 3.0 + SQRT_3 / 3.0 * y),
+        };
+
+        axial_round(q, r)
+    }
+
+    /// Returns the coordinates, in the given coordinate system, of the hexagon that
+    /// contains the world position.
+    pub fn cell(&self, world_position: Vec2, coords: HexCoords) -> IVec2 {
+        let IVec2 { x: q, y: r } = self.axial(world_position);
+
+        match (self.orientation, coords) {
+            (_, HexCoords::Axial) => IVec2::new(q, r),
+            (HexOrientation::PointyTop, HexCoords::OddOffset) => {
+                IVec2::new(q + (r - (r & 1)) / 2, r)
+            }
+            (HexOrientation::PointyTop, HexCoords::EvenOffset) => {
+                IVec2::new(q + (r + (r & 1)) / 2, r)
+            }
+            (HexOrientation::FlatTop, HexCoords::OddOffset) => IVec2::new(q, r + (q - (q & 1)) / 2),
+            (HexOrientation::FlatTop, HexCoords::EvenOffset) => {
+                IVec2::new(q, r + (q + (q & 1)) / 2)
+            }
+        }
+    }
+
+    /// Returns the world position of the center of the hexagon with the given
+    /// coordinates, in the given coordinate system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+    ///     let grid = HexGrid::new(32.0, orientation);
+    ///
+    ///     for coords in [HexCoords::Axial, HexCoords::OddOffset, HexCoords::EvenOffset] {
+    ///         for cell in [IVec2::new(0, 0), IVec2::new(3, -5), IVec2::new(-2, 7)] {
+    ///             let center = grid.cell_center(cell, coords);
+    ///
+    ///             // The hexagon contains its center and the points near its corners.
+    ///             assert_eq!(grid.cell(center, coords), cell);
+    ///             for angle in (0..6).map(|i| i as f32 * std::f32::consts::FRAC_PI_3) {
+    ///                 let near_corner = center + Vec2::from_angle(angle) * 26.0;
+    ///                 assert_eq!(grid.cell(near_corner, coords), cell);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn cell_center(&self, cell: IVec2, coords: HexCoords) -> Vec2 {
+        let IVec2 { x, y } = cell;
+
+        let axial = match (self.orientation, coords) {
+            (_, HexCoords::Axial) => cell,
+            (HexOrientation::PointyTop, HexCoords::OddOffset) => {
+                IVec2::new(x - (y - (y & 1)) / 2, y)
+            }
+            (HexOrientation::PointyTop, HexCoords::EvenOffset) => {
+                IVec2::new(x - (y + (y & 1)) / 2, y)
+            }
+            (HexOrientation::FlatTop, HexCoords::OddOffset) => IVec2::new(x, y - (x - (x & 1)) / 2),
+            (HexOrientation::FlatTop, HexCoords::EvenOffset) => {
+                IVec2::new(x, y - (x + (x & 1)) / 2)
+            }
+        };
+
+        self.axial_center(axial)
+    }
+
+    /// Returns the world position of the center of the hexagon with the given axial
+    /// coordinates.
+    pub fn axial_center(&self, axial: IVec2) -> Vec2 {
+        let Vec2 { x: q, y: r } = axial.as_vec2();
+
+        let position = match self.orientation {
+            HexOrientation::PointyTop => Vec2::new(SQRT_3 * (q + r / 2.0), 1.5 * r),
+            HexOrientation::FlatTop => Vec2::new(1.5 * q, SQRT_3 * (r + q / 2.0)),
+        };
+
+        self.origin + position * self.size
+    }
+}
+
+/// Rounds fractional axial coordinates to the nearest hexagon.
+fn axial_round(q: f32, r: f32) -> IVec2 {
+    let s = -q - r;
+
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+
+    Vec2::new(rq, rr).as_ivec2()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Location {
+    /// Returns the tile of the isometric grid under the cursor.
+    ///
+    /// See [`IsoGrid`].
+    #[inline]
+    pub fn to_iso_tile(&self, grid: &IsoGrid) -> IVec2 {
+        grid.tile(self.world_position)
+    }
+
+    /// Returns the tile of the staggered isometric grid under the cursor.
+    ///
+    /// See [`IsoGrid::staggered_tile`].
+    #[inline]
+    pub fn to_staggered_iso_tile(&self, grid: &IsoGrid, stagger: IsoStagger) -> IVec2 {
+        grid.staggered_tile(self.world_position, stagger)
+    }
+
+    /// Returns the coordinates of the hexagon of the grid under the cursor.
+    ///
+    /// See [`HexGrid`].
+    #[inline]
+    pub fn to_hex(&self, grid: &HexGrid, coords: HexCoords) -> IVec2 {
+        grid.cell(self.world_position, coords)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
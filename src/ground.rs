@@ -0,0 +1,238 @@
+//! Hits of the cursor ray on the ground, and the walkable positions under the cursor.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// Samples a surface of the world, like a terrain or a heightmap, along the cursor
+/// [ray] to compute the [`Location::ground_hit`].
+///
+/// The projection on a flat plane of the [`CursorProjection`] misses the hills and
+/// the valleys of a terrain. A sampler finds where the ray actually hits it, and
+/// the plugin keeps the nearest hit of the registered samplers.
+///
+/// This trait is implemented for the closures with the same signature as
+/// [`sample`](Self::sample). The [`HeightfieldSampler`] implements it for the
+/// terrains defined by a height function.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn height(position: Vec2) -> f32 {
+///     (position.x * 0.1).sin() * (position.y * 0.1).cos() * 4.0
+/// }
+///
+/// App::new().add_plugins(
+///     TrackCursorPlugin::default().with_surface_sampler(HeightfieldSampler::new(height)),
+/// );
+/// ```
+///
+/// [ray]: crate::Location::ray
+/// [`Location::ground_hit`]: crate::Location::ground_hit
+/// [`CursorProjection`]: crate::CursorProjection
+pub trait CursorSurfaceSampler: Send + Sync + 'static {
+    /// Returns the point where the ray hits the surface, in world coordinates.
+    ///
+    /// Returns [`None`] if the ray doesn't hit the surface.
+    fn sample(&self, ray: Ray3d) -> Option<Vec3>;
+}
+
+impl<T> CursorSurfaceSampler for T
+where
+    T: Fn(Ray3d) -> Option<Vec3> + Send + Sync + 'static,
+{
+    #[inline]
+    fn sample(&self, ray: Ray3d) -> Option<Vec3> {
+        self(ray)
+    }
+}
+
+/// A [`CursorSurfaceSampler`] for a terrain defined by a height function, that gives
+/// the height of the ground at a position of the XZ plane.
+///
+/// The ray is marched with a fixed [`step`](Self::step) until it goes below the
+/// ground, then the hit is refined between the last two steps.
+#[derive(Debug, Clone, Copy)]
+pub struct HeightfieldSampler<H> {
+    height: H,
+    /// The distance between two samples along the ray.
+    ///
+    /// It should be smaller than the smallest feature of the terrain, or the ray may
+    /// go through it.
+    pub step: f32,
+    /// The distance along the ray after which the ground is considered not hit.
+    pub max_distance: f32,
+}
+
+impl<H> HeightfieldSampler<H>
+where
+    H: Fn(Vec2) -> f32 + Send + Sync + 'static,
+{
+    /// The number of refinements of a hit between two steps.
+    const REFINEMENTS: usize = 8;
+
+    /// Creates a new [`HeightfieldSampler`] for the given height function, with steps
+    /// of one unit up to a thousand units.
+    #[inline]
+    pub fn new(height: H) -> Self {
+        Self {
+            height,
+            step: 1.0,
+            max_distance: 1000.0,
+        }
+    }
+
+    /// Sets the distance between two samples along the ray.
+    #[inline]
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the distance along the ray after which the ground is considered not hit.
+    #[inline]
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Returns `true` if the point is below the ground.
+    #[inline]
+    fn is_below(&self, point: Vec3) -> bool {
+        point.y <= (self.height)(point.xz())
+    }
+}
+
+impl<H> CursorSurfaceSampler for HeightfieldSampler<H>
+where
+    H: Fn(Vec2) -> f32 + Send + Sync + 'static,
+{
+    fn sample(&self, ray: Ray3d) -> Option<Vec3> {
+        if self.step <= 0.0 || self.is_below(ray.origin) {
+            return None;
+        }
+
+        let mut above = 0.0;
+        let mut distance = 0.0;
+        loop {
+            distance = f32::min(distance + self.step, self.max_distance);
+            if self.is_below(ray.get_point(distance)) {
+                break;
+            }
+            if distance >= self.max_distance {
+                return None;
+            }
+            above = distance;
+        }
+
+        let mut below = distance;
+        for _ in 0..Self::REFINEMENTS {
+            let middle = (above + below) * 0.5;
+            if self.is_below(ray.get_point(middle)) {
+                below = middle;
+            } else {
+                above = middle;
+            }
+        }
+
+        Some(ray.get_point(below))
+    }
+}
+
+/// Projects a point of the world onto the nearest walkable position of a navigation
+/// mesh, to compute the [`Location::nav_target`].
+///
+/// The [`ground_hit`] of the cursor is often on an obstacle or out of the walkable
+/// area, so it's not a valid destination for a point-and-click movement. A nav mesh
+/// moves it to the nearest position where the agents can go. The plugin keeps the
+/// nearest position of the registered nav meshes.
+///
+/// This trait is implemented for the closures with the same signature as
+/// [`nearest_walkable`](Self::nearest_walkable), so it can wrap the query of any
+/// navigation crate.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// // The walkable area is a square arena.
+/// fn arena(point: Vec3) -> Option<Vec3> {
+///     Some(point.clamp(Vec3::new(-50.0, 0.0, -50.0), Vec3::new(50.0, 0.0, 50.0)))
+/// }
+///
+/// fn move_to_cursor(buttons: Res<ButtonInput<MouseButton>>, cursor: Res<CursorLocation>) {
+///     if buttons.just_pressed(MouseButton::Right) {
+///         if let Some(destination) = cursor.nav_target() {
+///             info!("Walk to {destination:?}");
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(TrackCursorPlugin::default().with_nav_mesh(arena))
+///     .add_systems(Update, move_to_cursor);
+/// ```
+///
+/// [`Location::nav_target`]: crate::Location::nav_target
+/// [`ground_hit`]: crate::Location::ground_hit
+pub trait CursorNavMesh: Send + Sync + 'static {
+    /// Returns the walkable position nearest to the point, in world coordinates.
+    ///
+    /// Returns [`None`] if there is no walkable position near the point.
+    fn nearest_walkable(&self, point: Vec3) -> Option<Vec3>;
+}
+
+impl<T> CursorNavMesh for T
+where
+    T: Fn(Vec3) -> Option<Vec3> + Send + Sync + 'static,
+{
+    #[inline]
+    fn nearest_walkable(&self, point: Vec3) -> Option<Vec3> {
+        self(point)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The registered [`CursorSurfaceSampler`]s.
+#[derive(Resource, Default)]
+pub(crate) struct CursorSurfaceSamplers(pub(crate) Vec<Arc<dyn CursorSurfaceSampler>>);
+
+impl CursorSurfaceSamplers {
+    /// Returns the nearest hit of the ray on the surfaces of the samplers.
+    pub(crate) fn sample(&self, ray: Ray3d) -> Option<Vec3> {
+        self.0
+            .iter()
+            .filter_map(|sampler| sampler.sample(ray))
+            .min_by(|a, b| {
+                let a = a.distance_squared(ray.origin);
+                let b = b.distance_squared(ray.origin);
+                a.total_cmp(&b)
+            })
+    }
+}
+
+/// The registered [`CursorNavMesh`]es.
+#[derive(Resource, Default)]
+pub(crate) struct CursorNavMeshes(pub(crate) Vec<Arc<dyn CursorNavMesh>>);
+
+impl CursorNavMeshes {
+    /// Returns the walkable position nearest to the point on the nav meshes.
+    pub(crate) fn nearest_walkable(&self, point: Vec3) -> Option<Vec3> {
+        self.0
+            .iter()
+            .filter_map(|nav_mesh| nav_mesh.nearest_walkable(point))
+            .min_by(|a, b| {
+                let a = a.distance_squared(point);
+                let b = b.distance_squared(point);
+                a.total_cmp(&b)
+            })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,252 @@
+//! Drag regions and resize edges of the undecorated windows.
+
+use bevy::math::CompassOctant;
+use bevy::prelude::*;
+
+use crate::position::WindowPositions;
+#[cfg(feature = "ui")]
+use crate::HoveredUiNode;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that declares the drag regions and the resize edges of the windows
+/// without decorations.
+///
+/// When the [`button`](Self::button) is pressed on a drag region, the window is
+/// moved with [`Window::start_drag_move`], and when it's pressed on a resize edge,
+/// the window is resized with [`Window::start_drag_resize`]. The region under the
+/// cursor is provided by the [`HoveredWindowRegion`], so the cursor icon can be
+/// updated accordingly.
+///
+/// Only the windows whose [`decorations`] are disabled are hit-tested, and only the
+/// [`resizable`] ones have resize edges.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     // The title bar is the top 32 pixels of the window.
+///     commands.insert_resource(
+///         WindowHitTest::default().with_drag_region(Rect::new(0.0, 0.0, 10000.0, 32.0)),
+///     );
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`Window::start_drag_move`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#method.start_drag_move
+/// [`Window::start_drag_resize`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#method.start_drag_resize
+/// [`decorations`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#structfield.decorations
+/// [`resizable`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#structfield.resizable
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowHitTest {
+    /// The width of the resize edges along the borders of the window, in logical
+    /// pixels.
+    ///
+    /// Set it to zero to disable the resize edges.
+    pub resize_border: f32,
+    /// The drag regions, in logical pixels, relative to the top-left corner of the
+    /// window.
+    ///
+    /// With the feature `ui`, the nodes with a [`WindowDragRegion`] are drag regions
+    /// too.
+    pub drag_regions: Vec<Rect>,
+    /// The mouse button that starts the move or the resize of the window.
+    pub button: MouseButton,
+}
+
+impl Default for WindowHitTest {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            resize_border: 5.0,
+            drag_regions: Vec::new(),
+            button: MouseButton::Left,
+        }
+    }
+}
+
+impl WindowHitTest {
+    /// Adds a drag region, in logical pixels, relative to the top-left corner of the
+    /// window.
+    #[inline]
+    pub fn with_drag_region(mut self, rect: Rect) -> Self {
+        self.drag_regions.push(rect);
+        self
+    }
+
+    /// Sets the width of the resize edges, in logical pixels.
+    #[inline]
+    pub fn with_resize_border(mut self, resize_border: f32) -> Self {
+        self.resize_border = resize_border;
+        self
+    }
+
+    /// Sets the mouse button that starts the move or the resize of the window.
+    #[inline]
+    pub fn with_button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// Returns the region of a window of size `size` at the given position, in
+    /// logical pixels.
+    ///
+    /// The resize edges take precedence over the drag regions.
+    pub fn region_at(&self, position: Vec2, size: Vec2, resizable: bool) -> Option<WindowRegion> {
+        if resizable && self.resize_border > 0.0 {
+            let west = position.x < self.resize_border;
+            let east = position.x >= size.x - self.resize_border;
+            let north = position.y < self.resize_border;
+            let south = position.y >= size.y - self.resize_border;
+
+            let octant = match (north, south, west, east) {
+                (true, _, true, _) => Some(CompassOctant::NorthWest),
+                (true, _, _, true) => Some(CompassOctant::NorthEast),
+                (_, true, true, _) => Some(CompassOctant::SouthWest),
+                (_, true, _, true) => Some(CompassOctant::SouthEast),
+                (true, ..) => Some(CompassOctant::North),
+                (_, true, ..) => Some(CompassOctant::South),
+                (_, _, true, _) => Some(CompassOctant::West),
+                (_, _, _, true) => Some(CompassOctant::East),
+                _ => None,
+            };
+
+            if let Some(octant) = octant {
+                return Some(WindowRegion::Resize(octant));
+            }
+        }
+
+        self.drag_regions
+            .iter()
+            .any(|rect| rect.contains(position))
+            .then_some(WindowRegion::Drag)
+    }
+}
+
+/// A component for the `bevy_ui` nodes that are drag regions of their window, like
+/// a custom title bar.
+///
+/// The node must be the [`HoveredUiNode`]: its children that are not buttons, like
+/// the title, should have a [`FocusPolicy::Pass`] so the hover falls through them.
+///
+/// See [`WindowHitTest`].
+///
+/// [`FocusPolicy::Pass`]: https://docs.rs/bevy/0.15.0/bevy/ui/enum.FocusPolicy.html#variant.Pass
+#[cfg(feature = "ui")]
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq, Default)]
+pub struct WindowDragRegion;
+
+/// A region of a window declared by the [`WindowHitTest`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WindowRegion {
+    /// A region that moves the window.
+    Drag,
+    /// An edge or a corner that resizes the window in the given direction.
+    Resize(CompassOctant),
+}
+
+/// A resource that provides the region of the [`WindowHitTest`] under the cursor.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_hovered_region(hovered: Res<HoveredWindowRegion>) {
+///     if let Some(WindowRegion::Resize(direction)) = hovered.get() {
+///         info!("Resize the window toward {direction:?}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_hovered_region);
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredWindowRegion(Option<(Entity, WindowRegion)>);
+
+impl HoveredWindowRegion {
+    /// The region under the cursor, if any.
+    #[inline]
+    pub fn get(&self) -> Option<WindowRegion> {
+        self.0.map(|(_, region)| region)
+    }
+
+    /// The entity id of the window of the region under the cursor, if any.
+    #[inline]
+    pub fn window(&self) -> Option<Entity> {
+        self.0.map(|(window, _)| window)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the [`HoveredWindowRegion`], and moves or resizes the window when the
+/// button of the [`WindowHitTest`] is pressed on one of its regions.
+pub(crate) fn update_window_hit_test(
+    hit_test: Option<Res<WindowHitTest>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    positions: WindowPositions,
+    #[cfg(feature = "ui")] hovered_node: Res<HoveredUiNode>,
+    #[cfg(feature = "ui")] drag_region_q: Query<(), With<WindowDragRegion>>,
+    mut window_q: Query<(Entity, &mut Window)>,
+    mut hovered: ResMut<HoveredWindowRegion>,
+) {
+    let region = hit_test.and_then(|hit_test| {
+        let (window_ref, window, position) =
+            positions.find(window_q.iter().filter(|(_, window)| !window.decorations))?;
+
+        let region = hit_test.region_at(position.position, window.size(), window.resizable);
+
+        #[cfg(feature = "ui")]
+        let region = region.or_else(|| {
+            hovered_node
+                .get()
+                .filter(|node| node.window == window_ref)
+                .filter(|node| drag_region_q.contains(node.entity))
+                .map(|_| WindowRegion::Drag)
+        });
+
+        // Note: the `ButtonInput` is only provided by the `InputPlugin`.
+        let pressed = buttons.is_some_and(|buttons| buttons.just_pressed(hit_test.button));
+
+        Some((window_ref, region?, pressed))
+    });
+
+    if let Some((window_ref, region, pressed)) = region {
+        if pressed {
+            if let Ok((_, mut window)) = window_q.get_mut(window_ref) {
+                match region {
+                    WindowRegion::Drag => window.start_drag_move(),
+                    WindowRegion::Resize(direction) => window.start_drag_resize(direction),
+                }
+            }
+        }
+    }
+
+    hovered.set_if_neq(HoveredWindowRegion(
+        region.map(|(window_ref, region, _)| (window_ref, region)),
+    ));
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,415 @@
+//! The 2D entities under the cursor.
+
+use std::cmp::Ordering;
+
+#[cfg(feature = "mesh2d")]
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::hover_index::HoverIndex;
+#[cfg(feature = "mesh2d")]
+use crate::mesh_bvh::{triangle_contains, HoverMeshBvhs};
+use crate::{CursorLocation, ExternalPointerLocations, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that gives a 2D entity a shape to be hovered by the cursor.
+///
+/// The shape is defined in the local space of the entity, so it follows its
+/// [`GlobalTransform`], and is tested against the world position of the cursor.
+///
+/// With the feature `mesh2d`, the `HoverShape::Mesh` shape is the triangles of the
+/// [`Mesh2d`] of the entity, for the vector shapes and the custom 2D meshes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Sprite::default(),
+///         Transform::from_xyz(100.0, 0.0, 0.0),
+///         HoverShape::Rect {
+///             half_size: Vec2::splat(16.0),
+///         },
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`GlobalTransform`]: https://docs.rs/bevy/0.15.0/bevy/transform/components/struct.GlobalTransform.html
+/// [`Mesh2d`]: https://docs.rs/bevy/0.15.0/bevy/sprite/struct.Mesh2d.html
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum HoverShape {
+    /// A circle centered on the entity.
+    Circle {
+        /// The radius of the circle.
+        radius: f32,
+    },
+    /// A rectangle centered on the entity.
+    Rect {
+        /// Half the width and the height of the rectangle.
+        half_size: Vec2,
+    },
+    /// The triangles of the [`Mesh2d`] of the entity.
+    ///
+    /// The mesh must have a triangle list or a triangle strip topology. Its vertices are
+    /// projected on the XY plane.
+    ///
+    /// The triangles are indexed in a bounding volume hierarchy, which is rebuilt when
+    /// the mesh is modified, so only the triangles near the cursor are tested.
+    ///
+    /// [`Mesh2d`]: https://docs.rs/bevy/0.15.0/bevy/sprite/struct.Mesh2d.html
+    #[cfg(feature = "mesh2d")]
+    Mesh,
+}
+
+impl HoverShape {
+    /// Returns `true` if the shape contains the given point, in the local space of the
+    /// entity.
+    ///
+    /// Always returns `false` for a `HoverShape::Mesh`, whose triangles are in the mesh
+    /// asset: see `HoverShape::mesh_contains`.
+    #[inline]
+    pub fn contains(&self, point: Vec2) -> bool {
+        match *self {
+            HoverShape::Circle { radius } => point.length_squared() <= radius * radius,
+            HoverShape::Rect { half_size } => point.abs().cmple(half_size).all(),
+            #[cfg(feature = "mesh2d")]
+            HoverShape::Mesh => false,
+        }
+    }
+
+    /// Returns `true` if a triangle of the mesh contains the given point, in the local
+    /// space of the mesh.
+    ///
+    /// Returns `false` if the mesh doesn't have a triangle topology.
+    #[cfg(feature = "mesh2d")]
+    pub fn mesh_contains(mesh: &Mesh, point: Vec2) -> bool {
+        let Ok(mut triangles) = mesh.triangles() else {
+            return false;
+        };
+
+        triangles.any(|triangle| {
+            triangle_contains(triangle.vertices.map(|vertex| vertex.truncate()), point)
+        })
+    }
+}
+
+/// A resource that defines which entity is considered on top when several
+/// [`HoverShape`]s overlap under the cursor.
+///
+/// The entities that are equal for the selected order are ordered by their Z, then
+/// by their entity id.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// // The entities lower on the screen are drawn in front in an isometric view.
+/// App::new().add_plugins(TrackCursorPlugin::default().with_hover_order(HoverOrder::YSort));
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(Resource, Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum HoverOrder {
+    /// The entity with the greatest world Z is on top.
+    #[default]
+    Z,
+    /// The entity with the smallest world Y is on top, like the Y-sorting of an
+    /// isometric or a top-down view.
+    YSort,
+    /// The entity with the greatest [`HoverOrderKey`] is on top. The entities without
+    /// this component have a key of zero.
+    Key,
+}
+
+/// A component that defines the order of an entity for the [`HoverOrder::Key`] order.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[reflect(Component, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoverOrderKey(pub f32);
+
+/// A resource that provides the entities with a [`HoverShape`] under the cursor,
+/// sorted from the topmost to the bottommost according to the [`HoverOrder`].
+///
+/// The entities are hovered according to the [`CursorLocation`] of the default
+/// [`TrackCursorPlugin`], so none is hovered without it. The shapes are indexed by
+/// their world bounds, so only the shapes near the cursor are tested, even in scenes
+/// with thousands of them.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_hovered_entity(hovered: Res<HoveredEntities>) {
+///     if let Some(entity) = hovered.top() {
+///         info!("Hovering {entity}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_hovered_entity);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredEntities(Vec<Entity>);
+
+impl HoveredEntities {
+    /// The topmost entity under the cursor, if any.
+    #[inline]
+    pub fn top(&self) -> Option<Entity> {
+        self.0.first().copied()
+    }
+
+    /// Iterates over the entities under the cursor, from the topmost to the bottommost.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns `true` if the given entity is under the cursor.
+    #[inline]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains(&entity)
+    }
+
+    /// Returns `true` if there is no entity under the cursor.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A resource that provides the [`HoveredEntities`] of each pointer of the
+/// [`ExternalPointers`], like the cursors of the remote players.
+///
+/// The entities are hovered according to the [`ExternalPointerLocations`] of the
+/// default [`TrackCursorPlugin`], with the same [`HoverOrder`] as the cursor, so none
+/// is hovered without it.
+///
+/// [`ExternalPointers`]: crate::ExternalPointers
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ExternalHoveredEntities(Vec<(u64, HoveredEntities)>);
+
+impl ExternalHoveredEntities {
+    /// The entities under a pointer.
+    ///
+    /// Returns [`None`] if there is no entity under the pointer.
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<&HoveredEntities> {
+        self.0
+            .iter()
+            .find(|(i, _)| *i == id)
+            .map(|(_, hovered)| hovered)
+    }
+
+    /// Iterates over the pointers that hover an entity, with their id.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u64, &HoveredEntities)> {
+        self.0.iter().map(|(id, hovered)| (*id, hovered))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl HoverOrder {
+    /// Compares two entities under the cursor.
+    ///
+    /// [`Ordering::Less`] means that `a` is on top of `b`.
+    fn compare(
+        self,
+        (a_ref, a_t, a_key): (Entity, &GlobalTransform, Option<&HoverOrderKey>),
+        (b_ref, b_t, b_key): (Entity, &GlobalTransform, Option<&HoverOrderKey>),
+    ) -> Ordering {
+        let (a_pos, b_pos) = (a_t.translation(), b_t.translation());
+        let by_z = b_pos.z.total_cmp(&a_pos.z).then(b_ref.cmp(&a_ref));
+
+        match self {
+            HoverOrder::Z => by_z,
+            HoverOrder::YSort => a_pos.y.total_cmp(&b_pos.y).then(by_z),
+            HoverOrder::Key => {
+                let a_key = a_key.map_or(0.0, |key| key.0);
+                let b_key = b_key.map_or(0.0, |key| key.0);
+                b_key.total_cmp(&a_key).then(by_z)
+            }
+        }
+    }
+}
+
+/// A [`SystemParam`] that tests the meshes of the [`HoverShape::Mesh`] shapes.
+#[cfg(feature = "mesh2d")]
+#[derive(SystemParam)]
+pub(crate) struct HoverMeshes<'w, 's> {
+    mesh_q: Query<'w, 's, &'static Mesh2d>,
+    bvhs: Res<'w, HoverMeshBvhs>,
+}
+
+#[cfg(feature = "mesh2d")]
+impl HoverMeshes<'_, '_> {
+    /// Returns `true` if a triangle of the mesh of the given entity contains the given
+    /// point, in the local space of the mesh.
+    ///
+    /// The triangles are found with the [`MeshBvh`] of the mesh, which is built when
+    /// the shape is indexed.
+    ///
+    /// [`MeshBvh`]: crate::mesh_bvh::MeshBvh
+    pub(crate) fn contains(&self, entity: Entity, point: Vec2) -> bool {
+        self.mesh_q
+            .get(entity)
+            .ok()
+            .and_then(|mesh| self.bvhs.get(mesh.id()))
+            .is_some_and(|bvh| bvh.contains(point))
+    }
+}
+
+type ShapeQuery<'a> = (
+    Entity,
+    &'a HoverShape,
+    &'a GlobalTransform,
+    Option<&'a InheritedVisibility>,
+    Option<&'a HoverOrderKey>,
+);
+
+/// Returns the entities whose shape contains the world position of the location,
+/// sorted from the topmost to the bottommost.
+fn hovered_at(
+    location: &Location,
+    order: HoverOrder,
+    shape_q: &Query<ShapeQuery>,
+    index: &HoverIndex,
+    #[cfg(feature = "mesh2d")] meshes: &HoverMeshes,
+) -> Vec<Entity> {
+    if location.is_stale {
+        return Vec::new();
+    }
+
+    #[cfg(feature = "ui")]
+    if location.blocked {
+        return Vec::new();
+    }
+
+    let point = location.world_position;
+    let mut entities = index
+        .candidates(point)
+        .filter_map(|entity| shape_q.get(entity).ok())
+        .filter(|(entity, shape, transform, visibility, _)| {
+            let _ = entity; // Note: disable the `unused_variables` warning without `mesh2d`.
+
+            if visibility.is_some_and(|visibility| !visibility.get()) {
+                return false;
+            }
+
+            let local = transform
+                .affine()
+                .inverse()
+                .transform_point3(point.extend(transform.translation().z))
+                .truncate();
+
+            match shape {
+                #[cfg(feature = "mesh2d")]
+                HoverShape::Mesh => meshes.contains(*entity, local),
+                _ => shape.contains(local),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    entities.sort_by(|(a_ref, _, a_t, _, a_key), (b_ref, _, b_t, _, b_key)| {
+        order.compare((*a_ref, a_t, *a_key), (*b_ref, b_t, *b_key))
+    });
+
+    entities.into_iter().map(|(entity, ..)| entity).collect()
+}
+
+/// Updates the [`HoveredEntities`].
+pub(crate) fn update_hovered_entities(
+    cursor: Option<Res<CursorLocation>>,
+    order: Res<HoverOrder>,
+    shape_q: Query<ShapeQuery>,
+    index: Res<HoverIndex>,
+    #[cfg(feature = "mesh2d")] meshes: HoverMeshes,
+    mut hovered: ResMut<HoveredEntities>,
+) {
+    let entities = cursor
+        .as_ref()
+        .and_then(|cursor| cursor.get())
+        .map(|location| {
+            hovered_at(
+                location,
+                *order,
+                &shape_q,
+                &index,
+                #[cfg(feature = "mesh2d")]
+                &meshes,
+            )
+        })
+        .unwrap_or_default();
+
+    if hovered.0 != entities {
+        hovered.0 = entities;
+    }
+}
+
+/// Updates the [`ExternalHoveredEntities`].
+pub(crate) fn update_external_hovered_entities(
+    locations: Option<Res<ExternalPointerLocations>>,
+    order: Res<HoverOrder>,
+    shape_q: Query<ShapeQuery>,
+    index: Res<HoverIndex>,
+    #[cfg(feature = "mesh2d")] meshes: HoverMeshes,
+    mut hovered: ResMut<ExternalHoveredEntities>,
+) {
+    let entities = locations
+        .iter()
+        .flat_map(|locations| locations.iter())
+        .map(|(id, location)| {
+            let entities = hovered_at(
+                location,
+                *order,
+                &shape_q,
+                &index,
+                #[cfg(feature = "mesh2d")]
+                &meshes,
+            );
+            (id, HoveredEntities(entities))
+        })
+        .filter(|(_, entities)| !entities.is_empty())
+        .collect::<Vec<_>>();
+
+    if hovered.0 != entities {
+        hovered.0 = entities;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
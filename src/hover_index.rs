@@ -0,0 +1,255 @@
+//! A spatial index of the hover shapes.
+
+use bevy::ecs::entity::EntityHashMap;
+#[cfg(feature = "mesh2d")]
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+#[cfg(feature = "mesh2d")]
+use crate::input::OptionalEventReader;
+#[cfg(feature = "mesh2d")]
+use crate::mesh_bvh::{HoverMeshBvhs, MeshBvh};
+use crate::HoverShape;
+
+/* -------------------------------------------------------------------------- */
+
+/// The size, in world units, of the cells of the [`HoverIndex`].
+const CELL_SIZE: f32 = 64.0;
+
+/// The number of cells above which a shape is considered too large to be indexed,
+/// and is tested for every cursor position instead.
+const MAX_CELLS: i32 = 256;
+
+/// A uniform grid that indexes the [`HoverShape`]s by their world bounds, so the
+/// cursor is only tested against the shapes of its cell.
+#[derive(Resource, Default)]
+pub(crate) struct HoverIndex {
+    /// The entities whose bounds overlap each cell.
+    cells: HashMap<IVec2, Vec<Entity>>,
+    /// The entities too large to be indexed.
+    large: Vec<Entity>,
+    /// The world bounds of each indexed entity, and the cells they cover or [`None`]
+    /// if it's too large.
+    entries: EntityHashMap<(Rect, Option<IRect>)>,
+}
+
+impl HoverIndex {
+    /// Iterates over the entities whose bounds may contain the given world point.
+    pub(crate) fn candidates(&self, point: Vec2) -> impl Iterator<Item = Entity> + '_ {
+        self.cells
+            .get(&cell(point))
+            .into_iter()
+            .flatten()
+            .chain(&self.large)
+            .copied()
+    }
+
+    /// Iterates over the entities whose bounds overlap the given world rectangle, with
+    /// their bounds.
+    pub(crate) fn overlapping(&self, rect: Rect) -> impl Iterator<Item = (Entity, Rect)> + '_ {
+        let cells = IRect::from_corners(cell(rect.min), cell(rect.max));
+        let size = cells.size() + IVec2::ONE;
+
+        // When the rectangle covers too many cells, every entity is tested instead.
+        let entities = if size.x.saturating_mul(size.y) > MAX_CELLS {
+            self.entries.keys().copied().collect::<Vec<_>>()
+        } else {
+            let mut entities = (cells.min.x..=cells.max.x)
+                .flat_map(|x| (cells.min.y..=cells.max.y).map(move |y| IVec2::new(x, y)))
+                .filter_map(|cell| self.cells.get(&cell))
+                .flatten()
+                .chain(&self.large)
+                .copied()
+                .collect::<Vec<_>>();
+            entities.sort_unstable();
+            entities.dedup();
+            entities
+        };
+
+        entities.into_iter().filter_map(move |entity| {
+            let &(bounds, _) = self.entries.get(&entity)?;
+            (!bounds.intersect(rect).is_empty()).then_some((entity, bounds))
+        })
+    }
+
+    /// Indexes an entity with the given world bounds, replacing its previous bounds.
+    fn insert(&mut self, entity: Entity, bounds: Rect) {
+        self.remove(entity);
+
+        let cells = IRect::from_corners(cell(bounds.min), cell(bounds.max));
+        let size = cells.size() + IVec2::ONE;
+
+        if size.x.saturating_mul(size.y) > MAX_CELLS {
+            self.large.push(entity);
+            self.entries.insert(entity, (bounds, None));
+            return;
+        }
+
+        for x in cells.min.x..=cells.max.x {
+            for y in cells.min.y..=cells.max.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+        self.entries.insert(entity, (bounds, Some(cells)));
+    }
+
+    /// Removes an entity from the index.
+    fn remove(&mut self, entity: Entity) {
+        match self.entries.remove(&entity) {
+            None => {}
+            Some((_, None)) => self.large.retain(|&e| e != entity),
+            Some((_, Some(cells))) => {
+                for x in cells.min.x..=cells.max.x {
+                    for y in cells.min.y..=cells.max.y {
+                        let cell = IVec2::new(x, y);
+                        if let Some(entities) = self.cells.get_mut(&cell) {
+                            entities.retain(|&e| e != entity);
+                            if entities.is_empty() {
+                                self.cells.remove(&cell);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The cell of the [`HoverIndex`] that contains the given world point.
+#[inline]
+fn cell(point: Vec2) -> IVec2 {
+    (point / CELL_SIZE).floor().as_ivec2()
+}
+
+/// The bounds of a shape in the local space of its entity, on the XY plane.
+///
+/// Returns [`None`] if the shape is a mesh that isn't loaded or has no triangle.
+fn local_bounds(
+    entity: Entity,
+    shape: &HoverShape,
+    #[cfg(feature = "mesh2d")] meshes: &mut HoverMeshBounds,
+) -> Option<Rect> {
+    let _ = entity; // Note: disable the `unused_variables` warning without `mesh2d`.
+
+    match *shape {
+        HoverShape::Circle { radius } => {
+            Some(Rect::from_center_half_size(Vec2::ZERO, Vec2::splat(radius)))
+        }
+        HoverShape::Rect { half_size } => Some(Rect::from_center_half_size(Vec2::ZERO, half_size)),
+        #[cfg(feature = "mesh2d")]
+        HoverShape::Mesh => {
+            let mesh = meshes.mesh_q.get(entity).ok()?;
+            let assets = meshes.assets.as_deref()?;
+            meshes
+                .bvhs
+                .get_or_build(mesh.id(), assets)
+                .map(MeshBvh::bounds)
+        }
+    }
+}
+
+/// The world bounds of the local bounds of a shape, on the XY plane.
+fn world_bounds(bounds: Rect, transform: &GlobalTransform) -> Rect {
+    let affine = transform.affine();
+    let corners = [
+        Vec2::new(bounds.min.x, bounds.min.y),
+        Vec2::new(bounds.max.x, bounds.min.y),
+        Vec2::new(bounds.max.x, bounds.max.y),
+        Vec2::new(bounds.min.x, bounds.max.y),
+    ]
+    .map(|corner| affine.transform_point3(corner.extend(0.0)).truncate());
+
+    corners[1..].iter().fold(
+        Rect::from_center_size(corners[0], Vec2::ZERO),
+        |bounds, &corner| bounds.union_point(corner),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A [`SystemParam`] that provides the bounds of the meshes of the `HoverShape::Mesh`
+/// shapes, from their [`MeshBvh`].
+///
+/// [`SystemParam`]: https://docs.rs/bevy/0.15.0/bevy/ecs/system/trait.SystemParam.html
+#[cfg(feature = "mesh2d")]
+#[derive(SystemParam)]
+pub(crate) struct HoverMeshBounds<'w, 's> {
+    mesh_q: Query<'w, 's, &'static Mesh2d>,
+    assets: Option<Res<'w, Assets<Mesh>>>,
+    bvhs: ResMut<'w, HoverMeshBvhs>,
+}
+
+#[cfg(not(feature = "mesh2d"))]
+type ChangedShapes = Or<(Changed<HoverShape>, Changed<GlobalTransform>)>;
+
+#[cfg(feature = "mesh2d")]
+type ChangedShapes = Or<(
+    Changed<HoverShape>,
+    Changed<GlobalTransform>,
+    Changed<Mesh2d>,
+)>;
+
+/// Updates the [`HoverIndex`] with the shapes that moved, changed, or have been removed.
+pub(crate) fn update_hover_index(
+    shape_q: Query<(Entity, &HoverShape, &GlobalTransform), ChangedShapes>,
+    mut removed: RemovedComponents<HoverShape>,
+    #[cfg(feature = "mesh2d")] mut meshes: HoverMeshBounds,
+    #[cfg(feature = "mesh2d")] mut mesh_events: OptionalEventReader<AssetEvent<Mesh>>,
+    #[cfg(feature = "mesh2d")] mesh_shape_q: Query<(
+        Entity,
+        &HoverShape,
+        &GlobalTransform,
+        &Mesh2d,
+    )>,
+    mut index: ResMut<HoverIndex>,
+) {
+    for entity in removed.read() {
+        index.remove(entity);
+    }
+
+    // The hierarchies of the meshes that have been loaded, modified or removed are
+    // dropped, and rebuilt when their shapes are updated below.
+    #[cfg(feature = "mesh2d")]
+    let changed_meshes = mesh_events
+        .read()
+        .filter_map(|event| match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } | AssetEvent::Removed { id } => {
+                Some(id)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "mesh2d")]
+    for &mesh in &changed_meshes {
+        meshes.bvhs.remove(mesh);
+    }
+
+    let mut update =
+        |entity: Entity, shape: &HoverShape, transform: &GlobalTransform| match local_bounds(
+            entity,
+            shape,
+            #[cfg(feature = "mesh2d")]
+            &mut meshes,
+        ) {
+            Some(bounds) => index.insert(entity, world_bounds(bounds, transform)),
+            None => index.remove(entity),
+        };
+
+    for (entity, shape, transform) in &shape_q {
+        update(entity, shape, transform);
+    }
+
+    // The meshes that have been loaded or modified change the bounds of their shapes.
+    #[cfg(feature = "mesh2d")]
+    if !changed_meshes.is_empty() {
+        for (entity, shape, transform, mesh) in &mesh_shape_q {
+            if *shape == HoverShape::Mesh && changed_meshes.contains(&mesh.id()) {
+                update(entity, shape, transform);
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
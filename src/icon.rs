@@ -0,0 +1,114 @@
+//! The OS cursor icon over the hovered entities.
+
+use bevy::prelude::*;
+use bevy::window::SystemCursorIcon;
+use bevy::winit::cursor::CursorIcon;
+
+#[cfg(feature = "ui")]
+use crate::HoveredUiNode;
+#[cfg(feature = "2d")]
+use crate::{CursorLocation, HoveredEntities};
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that changes the OS cursor icon while its entity is under the cursor.
+///
+/// The entity is under the cursor when it's the topmost of the [`HoveredEntities`]
+/// (with the `2d` feature) or the [`HoveredUiNode`] (with the `ui` feature). The
+/// nodes are above the world, so the world entities are only considered when no
+/// node is hovered.
+///
+/// The icon of the window is restored when the entity is no longer under the cursor.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::window::SystemCursorIcon;
+/// # use bevy_cursor::prelude::*;
+/// fn spawn_button(mut commands: Commands) {
+///     commands.spawn((Button, HoverCursorIcon(SystemCursorIcon::Pointer)));
+/// }
+///
+/// # let _ = IntoSystem::into_system(spawn_button);
+/// ```
+///
+/// [`HoveredEntities`]: crate::HoveredEntities
+/// [`HoveredUiNode`]: crate::HoveredUiNode
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoverCursorIcon(pub SystemCursorIcon);
+
+/* -------------------------------------------------------------------------- */
+
+/// The internal state of the hover cursor icon.
+#[derive(Default)]
+pub(crate) struct IconState {
+    /// The window whose icon has been changed and the icon it has been changed to.
+    current: Option<(Entity, SystemCursorIcon)>,
+    /// The icon of the window before it has been changed.
+    previous: Option<CursorIcon>,
+}
+
+/// Changes the icon of the window to the [`HoverCursorIcon`] of the hovered entity.
+pub(crate) fn update_hover_cursor_icon(
+    #[cfg(feature = "2d")] cursor: Option<Res<CursorLocation>>,
+    #[cfg(feature = "2d")] hovered_entities: Res<HoveredEntities>,
+    #[cfg(feature = "ui")] hovered_node: Res<HoveredUiNode>,
+    icon_q: Query<&HoverCursorIcon>,
+    window_icon_q: Query<&CursorIcon, With<Window>>,
+    mut commands: Commands,
+    mut state: Local<IconState>,
+) {
+    // The hovered entity and the window it's hovered on.
+    #[cfg(feature = "ui")]
+    let hovered = hovered_node.entity().zip(hovered_node.window());
+    #[cfg(not(feature = "ui"))]
+    let hovered = None;
+
+    // The nodes are above the world, so the world is only hovered when no node is.
+    #[cfg(feature = "2d")]
+    let hovered = hovered.or_else(|| {
+        let window = cursor.as_ref()?.window()?;
+        Some((hovered_entities.top()?, window))
+    });
+
+    let target = hovered.and_then(|(entity, window)| Some((window, icon_q.get(entity).ok()?.0)));
+
+    if target == state.current {
+        return;
+    }
+
+    let same_window = matches!(
+        (state.current, target),
+        (Some((a, _)), Some((b, _))) if a == b
+    );
+
+    // The original icon of the window is kept while the icon changes on the same window.
+    if !same_window {
+        if let Some((window, _)) = state.current {
+            if let Some(mut window) = commands.get_entity(window) {
+                match state.previous.take() {
+                    Some(previous) => window.insert(previous),
+                    None => window.remove::<CursorIcon>(),
+                };
+            }
+        }
+        if let Some((window, _)) = target {
+            state.previous = window_icon_q.get(window).ok().cloned();
+        }
+    }
+
+    if let Some((window, icon)) = target {
+        commands.entity(window).insert(CursorIcon::from(icon));
+    }
+
+    state.current = target;
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,160 @@
+//! Detection of an idle cursor.
+
+use std::time::Duration;
+
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use bevy::window::CursorMoved;
+
+use crate::input::OptionalEventReader;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the detection of an idle cursor.
+///
+/// When this resource exists, the cursor is considered idle once it has not moved
+/// for [`timeout`](Self::timeout). A [`CursorIdle`] event is sent when the cursor
+/// becomes idle and a [`CursorActive`] event is sent when it moves again.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     // Hide the OS cursor after 3 seconds of inactivity.
+///     commands.insert_resource(CursorIdleSettings::new(Duration::from_secs(3)));
+/// }
+///
+/// fn dim_hud(mut idle: EventReader<CursorIdle>) {
+///     for _ in idle.read() {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(dim_hud);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorIdleSettings {
+    /// How long the cursor must stay still before being considered idle.
+    pub timeout: Duration,
+
+    /// Whether the OS cursor is hidden while the cursor is idle.
+    ///
+    /// The cursor is shown again as soon as it moves.
+    pub hide_cursor: bool,
+}
+
+impl CursorIdleSettings {
+    /// Creates a new [`CursorIdleSettings`] that hides the OS cursor after `timeout`.
+    #[inline]
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            hide_cursor: true,
+        }
+    }
+}
+
+/// An event sent when the cursor becomes idle.
+///
+/// See [`CursorIdleSettings`].
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorIdle;
+
+/// An event sent when the cursor moves again after being idle.
+///
+/// See [`CursorIdleSettings`].
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorActive;
+
+/* -------------------------------------------------------------------------- */
+
+/// The internal state of the idle detection.
+#[derive(Default)]
+pub(crate) struct IdleState {
+    /// The last time the cursor moved.
+    last_activity: Duration,
+    /// Whether the cursor is currently idle.
+    idle: bool,
+    /// The windows whose cursor has been hidden by this plugin.
+    hidden_windows: Vec<Entity>,
+}
+
+/// Detects when the cursor becomes idle or active and hides or shows the OS cursor accordingly.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_cursor_idle(
+    settings: Option<Res<CursorIdleSettings>>,
+    time: Res<Time<Real>>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut mouse_motion: OptionalEventReader<MouseMotion>,
+    mut window_q: Query<(Entity, &mut Window)>,
+    mut idle_events: EventWriter<CursorIdle>,
+    mut active_events: EventWriter<CursorActive>,
+    mut state: Local<IdleState>,
+) {
+    let now = time.elapsed();
+
+    let has_moved = cursor_moved.read().count() + mouse_motion.read().count() != 0;
+
+    // Wake up when the cursor moved or when the detection has been disabled.
+    let settings = match settings {
+        Some(settings) if !has_moved => settings,
+        _ => {
+            state.last_activity = now;
+
+            if state.idle {
+                state.idle = false;
+
+                for window in state.hidden_windows.drain(..) {
+                    if let Ok((_, mut window)) = window_q.get_mut(window) {
+                        window.cursor_options.visible = true;
+                    }
+                }
+
+                active_events.send(CursorActive);
+            }
+
+            return;
+        }
+    };
+
+    if state.idle || now.saturating_sub(state.last_activity) < settings.timeout {
+        return;
+    }
+
+    state.idle = true;
+
+    if settings.hide_cursor {
+        for (window_ref, mut window) in &mut window_q {
+            if window.cursor_options.visible {
+                window.cursor_options.visible = false;
+                state.hidden_windows.push(window_ref);
+            }
+        }
+    }
+
+    idle_events.send(CursorIdle);
+}
+
+/* -------------------------------------------------------------------------- */
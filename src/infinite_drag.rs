@@ -0,0 +1,199 @@
+//! Unbounded drags that warp the cursor at the window edges.
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables unbounded drags.
+///
+/// When this resource exists, holding [`button`](Self::button) starts a drag in the
+/// window that contains the cursor. During the drag, the OS cursor is warped to the
+/// opposite edge of the window when it gets closer than [`margin`](Self::margin) to
+/// an edge, so the drag never stops. The continuous position of the drag is provided
+/// by the [`InfiniteDrag`] resource.
+///
+/// This is typically inserted when the user grabs a slider handle and removed when
+/// the drag ends.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn update_slider(drag: Res<InfiniteDrag>, mut value: Local<f32>) {
+///     if let Some(delta) = drag.delta() {
+///         *value += delta.x * 0.01;
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(update_slider);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InfiniteDragSettings {
+    /// The mouse button that drives the drag.
+    pub button: MouseButton,
+
+    /// The distance to the window edges, in logical pixels, at which the cursor is warped.
+    pub margin: f32,
+}
+
+impl Default for InfiniteDragSettings {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+            margin: 2.0,
+        }
+    }
+}
+
+/// A resource that provides the state of the current unbounded drag.
+///
+/// See [`InfiniteDragSettings`].
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InfiniteDrag(Option<InfiniteDragData>);
+
+/// The state of an unbounded drag.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InfiniteDragData {
+    /// The entity id of the window in which the drag happens.
+    pub window: Entity,
+
+    /// The virtual position of the cursor in logical pixels.
+    ///
+    /// This position is continuous: it keeps growing past the window edges
+    /// as the cursor is warped.
+    pub position: Vec2,
+
+    /// How much the virtual position moved since the last frame, in logical pixels.
+    pub delta: Vec2,
+
+    /// The offset between the virtual position and the position of the OS cursor.
+    offset: Vec2,
+}
+
+impl InfiniteDrag {
+    /// The state of the current unbounded drag.
+    ///
+    /// Returns [`None`] if there is no drag in progress.
+    #[inline]
+    pub fn get(&self) -> Option<&InfiniteDragData> {
+        self.0.as_ref()
+    }
+
+    /// The virtual position of the cursor in logical pixels.
+    ///
+    /// Returns [`None`] if there is no drag in progress.
+    #[inline]
+    pub fn position(&self) -> Option<Vec2> {
+        self.get().map(|data| data.position)
+    }
+
+    /// How much the virtual position moved since the last frame, in logical pixels.
+    ///
+    /// Returns [`None`] if there is no drag in progress.
+    #[inline]
+    pub fn delta(&self) -> Option<Vec2> {
+        self.get().map(|data| data.delta)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the [`InfiniteDrag`] resource and warps the cursor at the window edges.
+pub(crate) fn update_infinite_drag(
+    settings: Option<Res<InfiniteDragSettings>>,
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    mut window_q: Query<(Entity, &mut Window)>,
+    mut drag: ResMut<InfiniteDrag>,
+) {
+    let Some((settings, buttons)) = settings
+        .zip(buttons)
+        .filter(|(settings, buttons)| buttons.pressed(settings.button))
+    else {
+        if drag.0.is_some() {
+            drag.0 = None;
+        }
+        return;
+    };
+
+    let data = match drag.0 {
+        Some(ref mut data) => data,
+        None => {
+            // Only start a drag when the button is pressed inside a window.
+            if !buttons.just_pressed(settings.button) {
+                return;
+            }
+
+            let Some((window, position)) = window_q
+                .iter()
+                .find_map(|(window_ref, window)| Some((window_ref, window.cursor_position()?)))
+            else {
+                return;
+            };
+
+            drag.0.insert(InfiniteDragData {
+                window,
+                position,
+                delta: Vec2::ZERO,
+                offset: Vec2::ZERO,
+            })
+        }
+    };
+
+    let Ok((_, mut window)) = window_q.get_mut(data.window) else {
+        drag.0 = None;
+        return;
+    };
+
+    // The cursor moved too fast and left the window: wait for it to come back.
+    let Some(position) = window.cursor_position() else {
+        data.delta = Vec2::ZERO;
+        return;
+    };
+
+    let virtual_position = position + data.offset;
+    data.delta = virtual_position - data.position;
+    data.position = virtual_position;
+
+    // Warp the cursor to the opposite edge when it gets too close to an edge.
+    let margin = settings.margin;
+    let span = window.size() - 2.0 * margin;
+
+    let mut warped = position;
+    for axis in 0..2 {
+        if span[axis] <= 0.0 {
+            continue;
+        }
+        if position[axis] < margin {
+            warped[axis] += span[axis];
+        } else if position[axis] > margin + span[axis] {
+            warped[axis] -= span[axis];
+        }
+    }
+
+    if warped != position {
+        data.offset += position - warped;
+        window.set_cursor_position(Some(warped));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,48 @@
+//! Readers of the events that don't require the plugin that registers them.
+
+use bevy::ecs::event::EventCursor;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A [`SystemParam`] that reads the events of type `E`, like an [`EventReader`],
+/// but that reads no event instead of panicking when they aren't registered.
+///
+/// The input events are registered by the `InputPlugin` and the asset events by the
+/// plugins of their assets, which the headless apps usually don't have. The events
+/// of the default [`TrackCursorPlugin`] are only registered when it has been added.
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(SystemParam)]
+pub(crate) struct OptionalEventReader<'w, 's, E: Event> {
+    events: Option<Res<'w, Events<E>>>,
+    cursor: Local<'s, EventCursor<E>>,
+}
+
+impl<E: Event> OptionalEventReader<'_, '_, E> {
+    /// Iterates over the events this reader hasn't seen yet.
+    #[inline]
+    pub(crate) fn read(&mut self) -> impl Iterator<Item = &E> {
+        let events = self.events.as_deref();
+        events
+            .map(|events| self.cursor.read(events))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Returns `true` if there are events this reader hasn't seen yet, and marks
+    /// them as read.
+    #[inline]
+    pub(crate) fn any(&mut self) -> bool {
+        self.read().count() != 0
+    }
+
+    /// Marks the events this reader hasn't seen yet as read.
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.read().for_each(drop);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -22,17 +22,271 @@
 //! [entity id]: https://docs.rs/bevy/0.15.0/bevy/ecs/entity/struct.Entity.html
 //! [ray]: https://docs.rs/bevy/0.15.0/bevy/math/struct.Ray3d.html
 
-use bevy::ecs::query::Has;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::ecs::query::{Has, QueryFilter};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use bevy::render::camera::RenderTarget;
+use bevy::render::camera::{CameraUpdateSystem, RenderTarget};
+use bevy::render::view::RenderLayers;
+use bevy::utils::Instant;
 use bevy::window::{PrimaryWindow, WindowRef};
 use smallvec::SmallVec;
 
+mod arbitration;
+#[cfg(feature = "ui")]
+mod blocker;
+mod bounds;
+#[cfg(feature = "2d")]
+mod brush;
+mod buttons;
+mod capture;
+mod click_through;
+mod confine;
+mod crosshair;
+mod debug;
+#[cfg(any(feature = "2d", feature = "3d", feature = "ui"))]
+mod derived;
+mod diagnostic;
+mod drag;
+mod edge_scroll;
+mod error;
+mod events;
+mod external;
+mod fixed;
+mod focus;
+mod grab;
+#[cfg(feature = "2d")]
+mod grid;
+#[cfg(feature = "3d")]
+mod ground;
+mod hit_test;
+#[cfg(feature = "2d")]
+mod hover;
+#[cfg(feature = "2d")]
+mod hover_index;
+#[cfg(all(feature = "cursor_icon", any(feature = "2d", feature = "ui")))]
+mod icon;
+mod idle;
+mod infinite_drag;
+mod input;
+mod locations;
+#[cfg(feature = "2d")]
+mod magnet;
+#[cfg(any(feature = "2d", feature = "3d"))]
+mod measure;
+#[cfg(feature = "mesh2d")]
+mod mesh_bvh;
+mod modifiers;
+#[cfg(feature = "3d")]
+mod orbit;
+mod over;
+#[cfg(feature = "debug_overlay")]
+mod overlay;
+#[cfg(feature = "2d")]
+mod pan;
+mod parallax;
+mod pause;
+mod per_window;
+mod pixel;
+mod pointer;
+mod portal;
+mod position;
+mod prediction;
+mod priority;
+#[cfg(feature = "3d")]
+mod projection;
+mod region;
+mod samples;
+mod screen;
+mod script;
+mod scroll;
+#[cfg(feature = "2d")]
+mod selection;
+mod simulator;
+mod smoothing;
+#[cfg(feature = "2d")]
+mod snap;
+mod stabilization;
+mod stale;
+mod stamp;
+mod stroke;
+#[cfg(any(feature = "2d", feature = "3d"))]
+mod surface;
+mod target;
+#[cfg(feature = "text")]
+mod text;
+#[cfg(any(feature = "2d", feature = "ui"))]
+mod throttle;
+mod tie_break;
+mod touch;
+mod tracking;
+#[cfg(feature = "ui")]
+mod ui;
+mod warp;
+#[cfg(feature = "2d")]
+mod zoom;
+
+pub use arbitration::{CursorArbitration, CursorSource};
+#[cfg(feature = "ui")]
+pub use blocker::CursorBlocker;
+pub use bounds::{ViewportBounds, ViewportEdges};
+#[cfg(feature = "2d")]
+pub use brush::{CursorBrush, CursorBrushQuery};
+pub use buttons::{ButtonPress, CursorButtons};
+pub use capture::PointerCapture;
+pub use click_through::{ClickThrough, DesktopCursorPosition};
+pub use confine::CursorConfinement;
+pub use crosshair::CrosshairMode;
+pub use debug::{CameraDebug, CameraStatus, CursorLocationDebug};
+pub use diagnostic::CursorDiagnosticsPlugin;
+pub use drag::{
+    CursorDragEnd, CursorDragMove, CursorDragSettings, CursorDragStart, CursorDrags, DragAxis,
+    DragAxisLock, DragAxisSpace, DragSession,
+};
+pub use edge_scroll::{
+    EdgeScroll, EdgeScrollCamera, EdgeScrollEasing, EdgeScrollPlugin, EdgeScrollSettings,
+};
+pub use error::{CursorResolutionError, CursorResolutionErrorReason};
+pub use events::{CursorOutCamera, CursorOutWindow, CursorOverCamera, CursorOverWindow};
+pub use external::{
+    ExternalPointer, ExternalPointerInterpolation, ExternalPointerLocations, ExternalPointers,
+};
+pub use fixed::FixedCursorLocation;
+pub use focus::FocusedWindowOnly;
+pub use grab::GrabbedCursorTracking;
+#[cfg(feature = "2d")]
+pub use grid::{HexCoords, HexGrid, HexOrientation, IsoGrid, IsoStagger};
+#[cfg(feature = "3d")]
+pub use ground::{CursorNavMesh, CursorSurfaceSampler, HeightfieldSampler};
+#[cfg(feature = "ui")]
+pub use hit_test::WindowDragRegion;
+pub use hit_test::{HoveredWindowRegion, WindowHitTest, WindowRegion};
+#[cfg(feature = "2d")]
+pub use hover::{ExternalHoveredEntities, HoverOrder, HoverOrderKey, HoverShape, HoveredEntities};
+#[cfg(all(feature = "cursor_icon", any(feature = "2d", feature = "ui")))]
+pub use icon::HoverCursorIcon;
+pub use idle::{CursorActive, CursorIdle, CursorIdleSettings};
+pub use infinite_drag::{InfiniteDrag, InfiniteDragData, InfiniteDragSettings};
+pub use locations::CursorLocations;
+#[cfg(feature = "2d")]
+pub use magnet::{CursorMagnet, CursorMagnetism};
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub use measure::CursorMeasurement;
+pub use modifiers::KeyModifiers;
+#[cfg(feature = "3d")]
+pub use orbit::CursorOrbitCamera;
+pub use over::CursorOver;
+#[cfg(feature = "debug_overlay")]
+pub use overlay::CursorDebugOverlayPlugin;
+#[cfg(feature = "2d")]
+pub use pan::CursorPanCamera;
+pub use parallax::CursorParallax;
+pub use pause::PauseAwareTracking;
+pub use per_window::CursorLocationsPerWindow;
+#[cfg(feature = "2d")]
+pub use pixel::CursorPixelSnap;
+pub use pixel::PixelAlignment;
+pub use pointer::{LastPointerSource, PointerKind, PointerSource};
+#[cfg(feature = "ui")]
+pub use portal::{CursorPortal, CursorPortals};
+pub use prediction::{CursorPrediction, PredictedCursorLocation};
+pub use priority::CursorPriority;
+#[cfg(feature = "3d")]
+pub use projection::{CursorProjection, RaySegment};
+pub use region::{CursorRegion, CursorRegionEnter, CursorRegionLeave, CursorRegions};
+pub use samples::{CursorSamples, HighRateSampling};
+pub use screen::{CursorScreenLocation, ScreenLocation};
+pub use script::CursorScript;
+pub use scroll::{HoveredCameraScroll, ScrollAt};
+#[cfg(feature = "2d")]
+pub use selection::{Selected, SelectionChanged, SelectionPlugin};
+pub use simulator::{CursorSimulator, CursorTestPlugin};
+pub use smoothing::{CursorSmoothing, SmoothedCursorLocation};
+#[cfg(feature = "2d")]
+pub use snap::{CellPosition, CursorCellChanged, CursorSnap, SnapMode};
+pub use stabilization::CursorStabilization;
+pub use stale::KeepStaleLocation;
+pub use stroke::{StrokeInterpolator, StrokeSmoothing};
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub use surface::{NestedCursorLocations, PortalSurface};
+pub use target::{CursorTargetResolver, TargetDisplays};
+#[cfg(feature = "text")]
+pub use text::{GlyphLocation, HoveredGlyph};
+#[cfg(any(feature = "2d", feature = "ui"))]
+pub use throttle::HoverThrottle;
+pub use tie_break::CameraTieBreak;
+pub use touch::TouchAsCursor;
+pub use tracking::CursorTracking;
+#[cfg(feature = "ui")]
+pub use ui::{HoveredUiNode, UiHoverStack, UiNodeLocation};
+pub use warp::{WarpCursor, WarpCursorExt};
+#[cfg(feature = "2d")]
+pub use zoom::ZoomToCursor;
+
+use grab::GrabbedCursors;
+#[cfg(feature = "3d")]
+use ground::{CursorNavMeshes, CursorSurfaceSamplers};
+use target::CursorTargetResolvers;
+use touch::TouchCursor;
+use warp::WarpHooks;
+
 /* -------------------------------------------------------------------------- */
 
 #[allow(missing_docs)]
 pub mod prelude {
-    pub use crate::{CursorLocation, TrackCursorPlugin, UpdateCursorLocation};
+    #[cfg(feature = "debug_overlay")]
+    pub use crate::CursorDebugOverlayPlugin;
+    #[cfg(all(feature = "cursor_icon", any(feature = "2d", feature = "ui")))]
+    pub use crate::HoverCursorIcon;
+    #[cfg(any(feature = "2d", feature = "ui"))]
+    pub use crate::HoverThrottle;
+    pub use crate::{
+        ButtonPress, CameraDebug, CameraFilter, CameraStatus, CameraTieBreak, ClickThrough,
+        CrosshairMode, CursorActive, CursorArbitration, CursorButtons, CursorConfinement,
+        CursorDiagnosticsPlugin, CursorDragEnd, CursorDragMove, CursorDragSettings,
+        CursorDragStart, CursorDrags, CursorIdle, CursorIdleSettings, CursorLocation,
+        CursorLocationDebug, CursorLocations, CursorLocationsPerWindow, CursorOutCamera,
+        CursorOutWindow, CursorOver, CursorOverCamera, CursorOverWindow, CursorParallax,
+        CursorPrediction, CursorPriority, CursorRegion, CursorRegionEnter, CursorRegionLeave,
+        CursorRegions, CursorResolutionError, CursorResolutionErrorReason, CursorSamples,
+        CursorScreenLocation, CursorScript, CursorSimulator, CursorSmoothing, CursorSource,
+        CursorStabilization, CursorTargetResolver, CursorTestPlugin, CursorTracking,
+        DesktopCursorPosition, DragAxis, DragAxisLock, DragAxisSpace, DragSession, EdgeScroll,
+        EdgeScrollCamera, EdgeScrollEasing, EdgeScrollPlugin, EdgeScrollSettings, ExternalPointer,
+        ExternalPointerInterpolation, ExternalPointerLocations, ExternalPointers,
+        FixedCursorLocation, FocusedWindowOnly, GrabbedCursorTracking, HighRateSampling,
+        HoveredCameraScroll, HoveredWindowRegion, InfiniteDrag, InfiniteDragSettings,
+        KeepStaleLocation, KeyModifiers, LastPointerSource, PauseAwareTracking, PixelAlignment,
+        PointerCapture, PointerKind, PointerSource, PredictedCursorLocation, ScreenLocation,
+        ScrollAt, SmoothedCursorLocation, StrokeInterpolator, StrokeSmoothing, TargetDisplays,
+        TouchAsCursor, TrackCursorPlugin, UpdateCursorLocation, ViewportBounds, ViewportEdges,
+        WarpCursorExt, WindowHitTest, WindowRegion,
+    };
+    #[cfg(feature = "2d")]
+    pub use crate::{
+        CellPosition, CursorBrush, CursorBrushQuery, CursorCellChanged, CursorMagnet,
+        CursorMagnetism, CursorPanCamera, CursorPixelSnap, CursorSnap, ExternalHoveredEntities,
+        HexCoords, HexGrid, HexOrientation, HoverOrder, HoverOrderKey, HoverShape, HoveredEntities,
+        IsoGrid, IsoStagger, Selected, SelectionChanged, SelectionPlugin, SnapMode, ZoomToCursor,
+    };
+    #[cfg(feature = "ui")]
+    pub use crate::{
+        CursorBlocker, CursorPortal, CursorPortals, HoveredUiNode, UiHoverStack, UiNodeLocation,
+        WindowDragRegion,
+    };
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    pub use crate::{CursorMeasurement, NestedCursorLocations, PortalSurface};
+    #[cfg(feature = "3d")]
+    pub use crate::{
+        CursorNavMesh, CursorOrbitCamera, CursorProjection, CursorSurfaceSampler,
+        HeightfieldSampler, RaySegment,
+    };
+    #[cfg(feature = "text")]
+    pub use crate::{GlyphLocation, HoveredGlyph};
 }
 
 /* -------------------------------------------------------------------------- */
@@ -40,20 +294,959 @@ pub mod prelude {
 /// This plugin adds support to track the cursor's position, window, and camera.
 ///
 /// Those values are provided by the [`CursorLocation`] resource.
-pub struct TrackCursorPlugin;
+///
+/// The optional behaviors of the plugin can be enabled with the builder methods.
+/// Each of them inserts the corresponding resource, which can still be modified,
+/// inserted or removed at runtime.
+///
+/// The plugin only resolves the cursor with the cameras selected by its
+/// [`CameraFilter`]. Several instances of the plugin can be added with different
+/// filters, each of them providing its own [`CursorLocation<F>`] resource.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// App::new().add_plugins(
+///     TrackCursorPlugin::default()
+///         .with_idle(CursorIdleSettings::new(Duration::from_secs(3)))
+///         .with_pointer_capture(),
+/// );
+/// ```
+pub struct TrackCursorPlugin<F: CameraFilter = ()> {
+    schedule: InternedScheduleLabel,
+    idle: Option<CursorIdleSettings>,
+    grabbed_tracking: Option<GrabbedCursorTracking>,
+    crosshair: bool,
+    infinite_drag: Option<InfiniteDragSettings>,
+    pointer_capture: bool,
+    touch_as_cursor: Option<TouchAsCursor>,
+    stale_location: bool,
+    pause_aware: bool,
+    focused_only: bool,
+    tie_break: Option<CameraTieBreak>,
+    viewport_bounds: Option<ViewportBounds>,
+    camera_predicate: Option<CameraPredicateFn>,
+    target_resolvers: Vec<Arc<dyn CursorTargetResolver>>,
+    location_debug: bool,
+    per_window_locations: bool,
+    cursor_samples: bool,
+    high_rate_sampling: bool,
+    pixel_alignment: bool,
+    late_resolution: Option<InternedScheduleLabel>,
+    smoothing: Option<CursorSmoothing>,
+    prediction: Option<CursorPrediction>,
+    stabilization: Option<CursorStabilization>,
+    drag: Option<CursorDragSettings>,
+    #[cfg(feature = "2d")]
+    snap: Option<CursorSnap>,
+    #[cfg(feature = "2d")]
+    hover_order: Option<HoverOrder>,
+    #[cfg(feature = "3d")]
+    projection: Option<CursorProjection>,
+    #[cfg(feature = "3d")]
+    surface_samplers: Vec<Arc<dyn CursorSurfaceSampler>>,
+    #[cfg(feature = "3d")]
+    nav_meshes: Vec<Arc<dyn CursorNavMesh>>,
+    marker: PhantomData<fn() -> F>,
+}
+
+impl Default for TrackCursorPlugin {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: CameraFilter> TrackCursorPlugin<F> {
+    /// Creates a new [`TrackCursorPlugin`] that resolves the cursor with the cameras
+    /// selected by `F`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            schedule: First.intern(),
+            idle: None,
+            // The browser doesn't report the position of a locked pointer.
+            grabbed_tracking: cfg!(target_arch = "wasm32").then_some(GrabbedCursorTracking::Clamp),
+            crosshair: false,
+            infinite_drag: None,
+            pointer_capture: false,
+            touch_as_cursor: None,
+            stale_location: false,
+            pause_aware: false,
+            focused_only: false,
+            tie_break: None,
+            viewport_bounds: None,
+            camera_predicate: None,
+            target_resolvers: Vec::new(),
+            location_debug: false,
+            per_window_locations: false,
+            cursor_samples: false,
+            high_rate_sampling: false,
+            pixel_alignment: false,
+            late_resolution: None,
+            smoothing: None,
+            prediction: None,
+            stabilization: None,
+            drag: None,
+            #[cfg(feature = "2d")]
+            snap: None,
+            #[cfg(feature = "2d")]
+            hover_order: None,
+            #[cfg(feature = "3d")]
+            projection: None,
+            #[cfg(feature = "3d")]
+            surface_samplers: Vec::new(),
+            #[cfg(feature = "3d")]
+            nav_meshes: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the schedule in which the [`CursorLocation`] is updated.
+    ///
+    /// Defaults to [`First`]. The [`UpdateCursorLocation`] system set is placed in
+    /// that schedule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// // Update the cursor location after the input events have been processed.
+    /// App::new().add_plugins(TrackCursorPlugin::default().with_schedule(PreUpdate));
+    /// ```
+    ///
+    /// [`First`]: https://docs.rs/bevy/0.15.0/bevy/app/struct.First.html
+    #[inline]
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Resolves the locations a second time, in the given schedule, after the cameras
+    /// have been updated.
+    ///
+    /// The locations are resolved with the viewports of the cameras as they are when
+    /// the [`UpdateCursorLocation`] system set runs. If the viewports are changed later
+    /// in the frame, like when the window is resized, the locations report a wrong
+    /// camera until the next frame. The second pass resolves them again with the new
+    /// viewports. In that schedule, the [`UpdateCursorLocation`] system set runs after
+    /// the [`CameraUpdateSystem`].
+    ///
+    /// The events and the behaviors that depend on the locations, like the drags, are
+    /// only updated in the first pass.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// App::new().add_plugins(TrackCursorPlugin::default().with_late_resolution(PostUpdate));
+    /// ```
+    ///
+    /// [`CameraUpdateSystem`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/struct.CameraUpdateSystem.html
+    #[inline]
+    pub fn with_late_resolution(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.late_resolution = Some(schedule.intern());
+        self
+    }
+
+    /// Enables the detection of an idle cursor.
+    ///
+    /// See [`CursorIdleSettings`].
+    #[inline]
+    pub fn with_idle(mut self, settings: CursorIdleSettings) -> Self {
+        self.idle = Some(settings);
+        self
+    }
+
+    /// Enables the tracking of the cursor while it is locked.
+    ///
+    /// It's enabled by default on the web.
+    ///
+    /// See [`GrabbedCursorTracking`].
+    #[inline]
+    pub fn with_grabbed_tracking(mut self, tracking: GrabbedCursorTracking) -> Self {
+        self.grabbed_tracking = Some(tracking);
+        self
+    }
+
+    /// Enables the crosshair mode.
+    ///
+    /// See [`CrosshairMode`].
+    #[inline]
+    pub fn with_crosshair(mut self) -> Self {
+        self.crosshair = true;
+        self
+    }
+
+    /// Enables unbounded drags.
+    ///
+    /// See [`InfiniteDragSettings`].
+    #[inline]
+    pub fn with_infinite_drag(mut self, settings: InfiniteDragSettings) -> Self {
+        self.infinite_drag = Some(settings);
+        self
+    }
+
+    /// Enables the pointer capture.
+    ///
+    /// See [`PointerCapture`].
+    #[inline]
+    pub fn with_pointer_capture(mut self) -> Self {
+        self.pointer_capture = true;
+        self
+    }
+
+    /// Mirrors the most recent touch into the cursor.
+    ///
+    /// See [`TouchAsCursor`].
+    #[inline]
+    pub fn with_touch_as_cursor(mut self, mode: TouchAsCursor) -> Self {
+        self.touch_as_cursor = Some(mode);
+        self
+    }
+
+    /// Keeps the last known location of the cursor when it leaves the windows.
+    ///
+    /// See [`KeepStaleLocation`].
+    #[inline]
+    pub fn with_stale_location(mut self) -> Self {
+        self.stale_location = true;
+        self
+    }
+
+    /// Freezes the world location of the cursor while the virtual time is paused.
+    ///
+    /// See [`PauseAwareTracking`].
+    #[inline]
+    pub fn with_pause_awareness(mut self) -> Self {
+        self.pause_aware = true;
+        self
+    }
+
+    /// Only tracks the cursor on the focused window.
+    ///
+    /// See [`FocusedWindowOnly`].
+    #[inline]
+    pub fn with_focused_window_only(mut self) -> Self {
+        self.focused_only = true;
+        self
+    }
+
+    /// Sets how the cameras that share the same order are sorted.
+    ///
+    /// See [`CameraTieBreak`].
+    #[inline]
+    pub fn with_tie_break(mut self, tie_break: CameraTieBreak) -> Self {
+        self.tie_break = Some(tie_break);
+        self
+    }
+
+    /// Sets how the viewports of the cameras are tested against the cursor.
+    ///
+    /// See [`ViewportBounds`].
+    #[inline]
+    pub fn with_viewport_bounds(mut self, bounds: ViewportBounds) -> Self {
+        self.viewport_bounds = Some(bounds);
+        self
+    }
+
+    /// Only resolves the cursor with the cameras for which `predicate` returns `true`.
+    ///
+    /// The predicate applies on top of the [`CameraFilter`]. Inactive cameras are always
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// // Ignore the cameras that render behind everything else.
+    /// App::new().add_plugins(
+    ///     TrackCursorPlugin::default().with_camera_predicate(|_, camera| camera.order >= 0),
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_camera_predicate(
+        mut self,
+        predicate: impl Fn(Entity, &Camera) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.camera_predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Adds a resolver for the cameras that render into an image or a texture view.
+    ///
+    /// See [`CursorTargetResolver`].
+    #[inline]
+    pub fn with_target_resolver(mut self, resolver: impl CursorTargetResolver) -> Self {
+        self.target_resolvers.push(Arc::new(resolver));
+        self
+    }
+
+    /// Records how the cameras are selected to resolve the cursor.
+    ///
+    /// See [`CursorLocationDebug`].
+    #[inline]
+    pub fn with_location_debug(mut self) -> Self {
+        self.location_debug = true;
+        self
+    }
+
+    /// Resolves the cursor on every window that contains it.
+    ///
+    /// See [`CursorLocationsPerWindow`].
+    #[inline]
+    pub fn with_per_window_locations(mut self) -> Self {
+        self.per_window_locations = true;
+        self
+    }
+
+    /// Resolves every position of the cursor reported during a frame.
+    ///
+    /// See [`CursorSamples`].
+    #[inline]
+    pub fn with_cursor_samples(mut self) -> Self {
+        self.cursor_samples = true;
+        self
+    }
+
+    /// Resolves every position of the cursor reported during a frame, reconstructed
+    /// from the raw mouse motion.
+    ///
+    /// See [`HighRateSampling`].
+    #[inline]
+    pub fn with_high_rate_sampling(mut self) -> Self {
+        self.cursor_samples = true;
+        self.high_rate_sampling = true;
+        self
+    }
+
+    /// Aligns the position of the cursor to the center of the physical pixels.
+    ///
+    /// See [`PixelAlignment`].
+    #[inline]
+    pub fn with_pixel_alignment(mut self) -> Self {
+        self.pixel_alignment = true;
+        self
+    }
+
+    /// Enables the smoothing of the cursor location.
+    ///
+    /// See [`CursorSmoothing`].
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: CursorSmoothing) -> Self {
+        self.smoothing = Some(smoothing);
+        self
+    }
+
+    /// Enables the prediction of the cursor location.
+    ///
+    /// See [`CursorPrediction`].
+    #[inline]
+    pub fn with_prediction(mut self, prediction: CursorPrediction) -> Self {
+        self.prediction = Some(prediction);
+        self
+    }
 
-impl Plugin for TrackCursorPlugin {
+    /// Enables the averaging of the cursor position over the last frames.
+    ///
+    /// See [`CursorStabilization`].
+    #[inline]
+    pub fn with_stabilization(mut self, stabilization: CursorStabilization) -> Self {
+        self.stabilization = Some(stabilization);
+        self
+    }
+
+    /// Enables the drag sessions of the mouse buttons.
+    ///
+    /// See [`CursorDragSettings`].
+    #[inline]
+    pub fn with_drag(mut self, settings: CursorDragSettings) -> Self {
+        self.drag = Some(settings);
+        self
+    }
+
+    /// Enables the snapping of the cursor world position to a grid.
+    ///
+    /// See [`CursorSnap`].
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn with_snap(mut self, snap: CursorSnap) -> Self {
+        self.snap = Some(snap);
+        self
+    }
+
+    /// Sets how the overlapping entities under the cursor are sorted.
+    ///
+    /// See [`HoverOrder`].
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn with_hover_order(mut self, hover_order: HoverOrder) -> Self {
+        self.hover_order = Some(hover_order);
+        self
+    }
+
+    /// Enables the projection of the cursor ray to a 3D point.
+    ///
+    /// See [`CursorProjection`].
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn with_projection(mut self, projection: CursorProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Adds a sampler of the ground hit by the cursor ray.
+    ///
+    /// See [`CursorSurfaceSampler`].
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn with_surface_sampler(mut self, sampler: impl CursorSurfaceSampler) -> Self {
+        self.surface_samplers.push(Arc::new(sampler));
+        self
+    }
+
+    /// Adds a nav mesh onto which the ground hit of the cursor is projected.
+    ///
+    /// See [`CursorNavMesh`].
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn with_nav_mesh(mut self, nav_mesh: impl CursorNavMesh) -> Self {
+        self.nav_meshes.push(Arc::new(nav_mesh));
+        self
+    }
+}
+
+impl<F: CameraFilter> Plugin for TrackCursorPlugin<F> {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CursorLocation>().add_systems(
-            First,
-            update_cursor_location_res.in_set(UpdateCursorLocation),
+        if let Some(ref idle) = self.idle {
+            app.insert_resource(idle.clone());
+        }
+        if let Some(grabbed_tracking) = self.grabbed_tracking {
+            app.insert_resource(grabbed_tracking);
+        }
+        if self.crosshair {
+            app.insert_resource(CrosshairMode);
+        }
+        if let Some(ref infinite_drag) = self.infinite_drag {
+            app.insert_resource(infinite_drag.clone());
+        }
+        if self.pointer_capture {
+            app.insert_resource(PointerCapture);
+        }
+        if let Some(touch_as_cursor) = self.touch_as_cursor {
+            app.insert_resource(touch_as_cursor);
+        }
+        if self.stale_location {
+            app.insert_resource(KeepStaleLocation);
+        }
+        if self.pause_aware {
+            app.insert_resource(PauseAwareTracking);
+        }
+        if self.focused_only {
+            app.insert_resource(FocusedWindowOnly);
+        }
+        if let Some(tie_break) = self.tie_break {
+            app.insert_resource(tie_break);
+        }
+        if let Some(viewport_bounds) = self.viewport_bounds {
+            app.insert_resource(viewport_bounds);
+        }
+        if let Some(smoothing) = self.smoothing {
+            app.insert_resource(smoothing);
+        }
+        if let Some(prediction) = self.prediction {
+            app.insert_resource(prediction);
+        }
+        if let Some(stabilization) = self.stabilization {
+            app.insert_resource(stabilization);
+        }
+        if let Some(ref drag) = self.drag {
+            app.insert_resource(drag.clone());
+        }
+        #[cfg(feature = "2d")]
+        if let Some(snap) = self.snap {
+            app.insert_resource(snap);
+        }
+        #[cfg(feature = "2d")]
+        if let Some(hover_order) = self.hover_order {
+            app.insert_resource(hover_order);
+        }
+        #[cfg(feature = "3d")]
+        if let Some(projection) = self.projection {
+            app.insert_resource(projection);
+        }
+
+        // The behaviors that don't depend on the cameras are shared by all the instances
+        // of the plugin and must be added only once.
+        if !app.world().contains_resource::<GrabbedCursors>() {
+            build_shared(app, self.schedule);
+        }
+
+        if let Some(ref predicate) = self.camera_predicate {
+            app.insert_resource(CameraPredicate::<F>(predicate.clone(), PhantomData));
+        }
+        if self.location_debug {
+            app.init_resource::<CursorLocationDebug<F>>();
+        }
+        if self.per_window_locations {
+            app.init_resource::<CursorLocationsPerWindow<F>>();
+        }
+        if self.cursor_samples {
+            app.init_resource::<CursorSamples<F>>();
+        }
+        if self.high_rate_sampling {
+            app.insert_resource(HighRateSampling);
+        }
+        if self.pixel_alignment {
+            app.insert_resource(PixelAlignment);
+        }
+
+        app.register_type::<CursorLocation<F>>()
+            .register_type::<FixedCursorLocation<F>>()
+            .register_type::<SmoothedCursorLocation<F>>()
+            .register_type::<PredictedCursorLocation<F>>()
+            .register_type::<CursorButtons<F>>()
+            .register_type::<ExternalPointerLocations<F>>()
+            .register_type::<CursorDrags<F>>()
+            .register_type::<CursorDragStart<F>>()
+            .register_type::<CursorDragMove<F>>()
+            .register_type::<CursorDragEnd<F>>()
+            .register_type::<CursorLocations<F>>()
+            .register_type::<CursorLocationDebug<F>>()
+            .register_type::<CursorLocationsPerWindow<F>>()
+            .register_type::<CursorSamples<F>>()
+            .register_type::<CursorOver<F>>()
+            .register_type::<CursorOverWindow<F>>()
+            .register_type::<CursorOutWindow<F>>()
+            .register_type::<CursorOverCamera<F>>()
+            .register_type::<CursorOutCamera<F>>()
+            .register_type::<ScrollAt<F>>()
+            .register_type::<HoveredCameraScroll<F>>()
+            .init_resource::<CursorLocation<F>>()
+            .init_resource::<FixedCursorLocation<F>>()
+            .init_resource::<SmoothedCursorLocation<F>>()
+            .init_resource::<PredictedCursorLocation<F>>()
+            .init_resource::<CursorButtons<F>>()
+            .init_resource::<arbitration::ArbitrationState<F>>()
+            .init_resource::<ExternalPointerLocations<F>>()
+            .init_resource::<CursorDrags<F>>()
+            .init_resource::<HoveredCameraScroll<F>>()
+            .init_resource::<CursorLocations<F>>()
+            .init_resource::<capture::CapturedPointer<F>>()
+            .add_event::<CursorOverWindow<F>>()
+            .add_event::<CursorOutWindow<F>>()
+            .add_event::<CursorOverCamera<F>>()
+            .add_event::<CursorOutCamera<F>>()
+            .add_event::<CursorDragStart<F>>()
+            .add_event::<CursorDragMove<F>>()
+            .add_event::<CursorDragEnd<F>>()
+            .add_event::<ScrollAt<F>>()
+            .add_systems(
+                self.schedule,
+                (
+                    events::send_cursor_events::<F>,
+                    over::update_cursor_over::<F>,
+                    smoothing::smooth_cursor_location::<F>,
+                    prediction::predict_cursor_location::<F>,
+                    buttons::update_cursor_buttons::<F>
+                        .run_if(resource_exists::<ButtonInput<MouseButton>>),
+                    drag::update_cursor_drags::<F>,
+                    (
+                        scroll::send_scroll_at::<F>,
+                        scroll::update_hovered_camera_scroll::<F>,
+                    )
+                        .chain(),
+                )
+                    .after(UpdateCursorLocation),
+            )
+            .add_systems(FixedFirst, fixed::snapshot_fixed_cursor_location::<F>);
+
+        add_resolution_systems::<F>(app, self.schedule);
+
+        if let Some(late_schedule) = self.late_resolution {
+            app.configure_sets(
+                late_schedule,
+                UpdateCursorLocation.after(CameraUpdateSystem),
+            );
+            add_resolution_systems::<F>(app, late_schedule);
+        }
+
+        app.world_mut()
+            .resource_mut::<WarpHooks>()
+            .0
+            .push(warp::resolve_warped_location::<F>);
+
+        #[cfg(any(feature = "2d", feature = "3d", feature = "ui"))]
+        app.world_mut()
+            .resource_mut::<WarpHooks>()
+            .0
+            .push(derived::derive_warped_locations::<F>);
+
+        #[cfg(feature = "2d")]
+        app.register_type::<CursorCellChanged<F>>()
+            .add_event::<CursorCellChanged<F>>()
+            .add_systems(
+                self.schedule,
+                snap::send_cell_changed::<F>.after(UpdateCursorLocation),
+            );
+
+        app.world_mut()
+            .resource_mut::<CursorTargetResolvers>()
+            .0
+            .extend(self.target_resolvers.iter().cloned());
+
+        #[cfg(feature = "3d")]
+        app.world_mut()
+            .resource_mut::<CursorSurfaceSamplers>()
+            .0
+            .extend(self.surface_samplers.iter().cloned());
+
+        #[cfg(feature = "3d")]
+        app.world_mut()
+            .resource_mut::<CursorNavMeshes>()
+            .0
+            .extend(self.nav_meshes.iter().cloned());
+    }
+}
+
+/// Adds the systems that resolve the locations of a [`TrackCursorPlugin`] to the
+/// given schedule.
+fn add_resolution_systems<F: CameraFilter>(app: &mut App, schedule: InternedScheduleLabel) {
+    app.add_systems(
+        schedule,
+        (
+            (
+                arbitration::restore_mouse_location::<F>,
+                update_cursor_location_res::<F>,
+                capture::update_pointer_capture::<F>.run_if(
+                    resource_exists::<PointerCapture>.or(resource_removed::<PointerCapture>),
+                ),
+                per_window::update_cursor_locations_per_window::<F>,
+                external::resolve_external_pointers::<F>,
+                arbitration::arbitrate_cursor_location::<F>,
+                samples::update_cursor_samples::<F>,
+                stabilization::stabilize_cursor_location::<F>,
+            )
+                .chain()
+                .run_if(resource_equals(CursorTracking::Enabled)),
+            tracking::clear_cursor_location::<F>.run_if(resource_equals(CursorTracking::Disabled)),
+        )
+            .after(PrepareCursorLocation)
+            .in_set(UpdateCursorLocation),
+    );
+
+    #[cfg(feature = "2d")]
+    app.add_systems(
+        schedule,
+        (
+            snap::snap_cursor_location::<F>,
+            magnet::attract_cursor_location::<F>,
+        )
+            .after(samples::update_cursor_samples::<F>)
+            .in_set(UpdateCursorLocation),
+    );
+
+    #[cfg(feature = "ui")]
+    app.add_systems(
+        schedule,
+        blocker::block_cursor_location::<F>
+            .after(samples::update_cursor_samples::<F>)
+            .in_set(UpdateCursorLocation),
+    );
+
+    #[cfg(feature = "3d")]
+    app.add_systems(
+        schedule,
+        projection::project_cursor_location::<F>
+            .after(samples::update_cursor_samples::<F>)
+            .in_set(UpdateCursorLocation),
+    );
+}
+
+/// Adds the behaviors shared by all the instances of [`TrackCursorPlugin`].
+fn build_shared(app: &mut App, schedule: InternedScheduleLabel) {
+    app.register_type::<Location>()
+        .register_type::<CursorIdleSettings>()
+        .register_type::<CursorIdle>()
+        .register_type::<CursorActive>()
+        .register_type::<GrabbedCursorTracking>()
+        .register_type::<CrosshairMode>()
+        .register_type::<InfiniteDragSettings>()
+        .register_type::<InfiniteDrag>()
+        .register_type::<InfiniteDragData>()
+        .register_type::<PointerCapture>()
+        .register_type::<KeepStaleLocation>()
+        .register_type::<PauseAwareTracking>()
+        .register_type::<FocusedWindowOnly>()
+        .register_type::<CursorConfinement>()
+        .register_type::<CursorTracking>()
+        .register_type::<CameraTieBreak>()
+        .register_type::<CursorPriority>()
+        .register_type::<ViewportBounds>()
+        .register_type::<ViewportEdges>()
+        .register_type::<CursorRegions>()
+        .register_type::<CursorRegion>()
+        .register_type::<CursorRegionEnter>()
+        .register_type::<CursorRegionLeave>()
+        .register_type::<CursorResolutionError>()
+        .register_type::<CursorResolutionErrorReason>()
+        .register_type::<CameraDebug>()
+        .register_type::<CameraStatus>()
+        .register_type::<CursorScreenLocation>()
+        .register_type::<ScreenLocation>()
+        .register_type::<CursorSimulator>()
+        .register_type::<CursorScript>()
+        .register_type::<CursorParallax>()
+        .register_type::<StrokeInterpolator>()
+        .register_type::<CursorSmoothing>()
+        .register_type::<CursorPrediction>()
+        .register_type::<CursorStabilization>()
+        .register_type::<CursorDragSettings>()
+        .register_type::<KeyModifiers>()
+        .register_type::<DragAxisLock>()
+        .register_type::<DragAxisSpace>()
+        .register_type::<DragAxis>()
+        .register_type::<ButtonPress>()
+        .register_type::<PointerKind>()
+        .register_type::<PointerSource>()
+        .register_type::<LastPointerSource>()
+        .register_type::<ExternalPointers>()
+        .register_type::<ExternalPointer>()
+        .register_type::<ExternalPointerInterpolation>()
+        .register_type::<CursorArbitration>()
+        .register_type::<TargetDisplays>()
+        .register_type::<CursorSource>()
+        .register_type::<DragSession>()
+        .register_type::<StrokeSmoothing>()
+        .register_type::<WindowHitTest>()
+        .register_type::<WindowRegion>()
+        .register_type::<HoveredWindowRegion>()
+        .register_type::<ClickThrough>()
+        .register_type::<DesktopCursorPosition>()
+        .register_type::<TouchAsCursor>()
+        .register_type::<HighRateSampling>()
+        .register_type::<PixelAlignment>()
+        .init_resource::<CursorTracking>()
+        .init_resource::<CameraTieBreak>()
+        .init_resource::<ViewportBounds>()
+        .init_resource::<CursorRegions>()
+        .init_resource::<CursorScreenLocation>()
+        .init_resource::<DesktopCursorPosition>()
+        .init_resource::<CursorSimulator>()
+        .init_resource::<CursorScript>()
+        .init_resource::<GrabbedCursors>()
+        .init_resource::<InfiniteDrag>()
+        .init_resource::<WarpHooks>()
+        .init_resource::<CursorTargetResolvers>()
+        .init_resource::<TargetDisplays>()
+        .init_resource::<LastPointerSource>()
+        .init_resource::<ExternalPointers>()
+        .init_resource::<HoveredWindowRegion>()
+        .init_resource::<TouchCursor>()
+        .init_resource::<external::ExternalPointerBuffers>()
+        .add_event::<CursorIdle>()
+        .add_event::<CursorActive>()
+        .add_event::<CursorRegionEnter>()
+        .add_event::<CursorRegionLeave>()
+        .add_event::<CursorResolutionError>()
+        .configure_sets(schedule, PrepareCursorLocation.in_set(UpdateCursorLocation))
+        .add_systems(
+            schedule,
+            (
+                (
+                    script::play_cursor_script
+                        .run_if(|script: Res<CursorScript>| !script.is_finished()),
+                    simulator::apply_cursor_simulator.run_if(resource_changed::<CursorSimulator>),
+                )
+                    .chain()
+                    .before(PrepareCursorLocation),
+                external::buffer_external_pointers.before(PrepareCursorLocation),
+                (
+                    touch::update_touch_cursor.run_if(resource_exists::<TouchAsCursor>),
+                    touch::clear_touch_cursor.run_if(resource_removed::<TouchAsCursor>),
+                )
+                    .before(PrepareCursorLocation),
+                (
+                    grab::update_grabbed_cursors,
+                    infinite_drag::update_infinite_drag,
+                    confine::confine_cursor,
+                )
+                    .chain()
+                    .run_if(resource_equals(CursorTracking::Enabled))
+                    .in_set(PrepareCursorLocation),
+                screen::update_cursor_screen_location
+                    .run_if(resource_equals(CursorTracking::Enabled))
+                    .after(PrepareCursorLocation)
+                    .in_set(UpdateCursorLocation),
+                idle::update_cursor_idle
+                    .run_if(
+                        resource_exists::<CursorIdleSettings>
+                            .or(resource_removed::<CursorIdleSettings>),
+                    )
+                    .after(UpdateCursorLocation),
+                pointer::update_last_pointer_source.after(UpdateCursorLocation),
+                region::update_cursor_regions
+                    .run_if(resource_equals(CursorTracking::Enabled))
+                    .after(UpdateCursorLocation),
+                parallax::update_cursor_parallax.after(UpdateCursorLocation),
+                click_through::update_click_through
+                    .run_if(resource_equals(CursorTracking::Enabled))
+                    .after(hit_test::update_window_hit_test),
+            ),
+        );
+
+    #[cfg(feature = "2d")]
+    app.register_type::<CursorSnap>()
+        .register_type::<SnapMode>()
+        .register_type::<CellPosition>()
+        .register_type::<CursorBrush>()
+        .register_type::<IsoGrid>()
+        .register_type::<IsoStagger>()
+        .register_type::<HexGrid>()
+        .register_type::<HexOrientation>()
+        .register_type::<HexCoords>()
+        .register_type::<ZoomToCursor>()
+        .register_type::<CursorPanCamera>()
+        .register_type::<HoverShape>()
+        .register_type::<HoverOrder>()
+        .register_type::<HoverOrderKey>()
+        .register_type::<HoveredEntities>()
+        .register_type::<ExternalHoveredEntities>()
+        .register_type::<CursorMagnetism>()
+        .register_type::<CursorMagnet>()
+        .register_type::<CursorPixelSnap>()
+        .init_resource::<HoverOrder>()
+        .init_resource::<HoveredEntities>()
+        .init_resource::<ExternalHoveredEntities>()
+        .init_resource::<hover_index::HoverIndex>()
+        .add_systems(
+            schedule,
+            (
+                (zoom::zoom_to_cursor, pan::pan_cursor_cameras).chain(),
+                (
+                    hover_index::update_hover_index,
+                    hover::update_hovered_entities.run_if(throttle::hover_throttle),
+                    hover::update_external_hovered_entities,
+                )
+                    .chain()
+                    .before(click_through::update_click_through),
+            )
+                .run_if(resource_equals(CursorTracking::Enabled))
+                .after(UpdateCursorLocation),
+        );
+
+    #[cfg(feature = "mesh2d")]
+    app.init_resource::<mesh_bvh::HoverMeshBvhs>();
+
+    #[cfg(feature = "3d")]
+    app.register_type::<CursorProjection>()
+        .register_type::<RaySegment>()
+        .register_type::<CursorOrbitCamera>()
+        .init_resource::<CursorSurfaceSamplers>()
+        .init_resource::<CursorNavMeshes>()
+        .add_systems(
+            schedule,
+            (
+                orbit::orbit_cursor_cameras,
+                orbit::zoom_cursor_orbit_cameras,
+            )
+                .chain()
+                .run_if(resource_equals(CursorTracking::Enabled))
+                .after(drag::update_cursor_drags::<()>)
+                .after(scroll::send_scroll_at::<()>),
         );
+
+    #[cfg(any(feature = "2d", feature = "ui"))]
+    app.register_type::<HoverThrottle>();
+
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    app.register_type::<CursorMeasurement>()
+        .register_type::<PortalSurface>()
+        .register_type::<NestedCursorLocations>()
+        .init_resource::<NestedCursorLocations>()
+        .add_systems(
+            schedule,
+            (
+                measure::update_cursor_measurement.run_if(resource_exists::<CursorMeasurement>),
+                surface::update_nested_cursor_locations,
+            )
+                .run_if(resource_equals(CursorTracking::Enabled))
+                .after(UpdateCursorLocation),
+        );
+
+    #[cfg(feature = "ui")]
+    app.register_type::<HoveredUiNode>()
+        .register_type::<CursorBlocker>()
+        .register_type::<CursorPortal>()
+        .register_type::<WindowDragRegion>()
+        .register_type::<UiHoverStack>()
+        .register_type::<UiNodeLocation>()
+        .init_resource::<HoveredUiNode>()
+        .init_resource::<UiHoverStack>()
+        .add_systems(
+            schedule,
+            ui::update_hovered_ui_node
+                .run_if(resource_equals(CursorTracking::Enabled))
+                .run_if(throttle::hover_throttle)
+                .after(UpdateCursorLocation),
+        );
+
+    // The drag regions of the nodes are hit-tested with the hovered node.
+    #[cfg(feature = "ui")]
+    app.add_systems(
+        schedule,
+        hit_test::update_window_hit_test
+            .run_if(resource_exists::<WindowHitTest>.or(resource_removed::<WindowHitTest>))
+            .run_if(resource_equals(CursorTracking::Enabled))
+            .after(ui::update_hovered_ui_node),
+    );
+    #[cfg(not(feature = "ui"))]
+    app.add_systems(
+        schedule,
+        hit_test::update_window_hit_test
+            .run_if(resource_exists::<WindowHitTest>.or(resource_removed::<WindowHitTest>))
+            .run_if(resource_equals(CursorTracking::Enabled))
+            .after(UpdateCursorLocation),
+    );
+
+    #[cfg(all(feature = "cursor_icon", any(feature = "2d", feature = "ui")))]
+    {
+        let icon_system = icon::update_hover_cursor_icon
+            .run_if(resource_equals(CursorTracking::Enabled))
+            .after(UpdateCursorLocation);
+        #[cfg(feature = "2d")]
+        let icon_system = icon_system.after(hover::update_hovered_entities);
+        #[cfg(feature = "ui")]
+        let icon_system = icon_system.after(ui::update_hovered_ui_node);
+
+        app.register_type::<HoverCursorIcon>()
+            .add_systems(schedule, icon_system);
     }
+
+    #[cfg(feature = "text")]
+    app.register_type::<HoveredGlyph>()
+        .register_type::<GlyphLocation>()
+        .init_resource::<HoveredGlyph>()
+        .add_systems(
+            schedule,
+            text::update_hovered_glyph
+                .run_if(resource_equals(CursorTracking::Enabled))
+                .after(ui::update_hovered_ui_node),
+        );
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// A [`SystemSet`] in which [`CursorLocation`] is updated during the [`First`] schedule.
+/// A [`SystemSet`] in which [`CursorLocation`] is updated.
+///
+/// This set is placed in the [`First`] schedule, unless another schedule has been
+/// selected with [`TrackCursorPlugin::with_schedule`].
 ///
 /// # Example
 ///
@@ -79,12 +1272,66 @@ impl Plugin for TrackCursorPlugin {
 #[derive(SystemSet, Hash, Debug, PartialEq, Eq, Clone, Copy)]
 pub struct UpdateCursorLocation;
 
+/// A [`SystemSet`], inside [`UpdateCursorLocation`], in which the OS cursor is moved
+/// before the [`CursorLocation`]s are resolved.
+#[derive(SystemSet, Hash, Debug, PartialEq, Eq, Clone, Copy)]
+struct PrepareCursorLocation;
+
+/* -------------------------------------------------------------------------- */
+
+/// A marker that selects the cameras with which a [`TrackCursorPlugin`] resolves the cursor.
+///
+/// Each [`CameraFilter`] has its own instance of [`TrackCursorPlugin`] and its own
+/// [`CursorLocation`] resource. This way, one instance can track the UI cameras
+/// while another one tracks the world cameras.
+///
+/// The unit type `()` selects all the cameras and is the default filter.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// #[derive(Component)]
+/// struct WorldCamera;
+///
+/// #[derive(TypePath)]
+/// struct WorldCameras;
+///
+/// impl CameraFilter for WorldCameras {
+///     type Filter = With<WorldCamera>;
+/// }
+///
+/// App::new().add_plugins((
+///     TrackCursorPlugin::default(),
+///     TrackCursorPlugin::<WorldCameras>::new(),
+/// ));
+///
+/// fn print_world_cursor(cursor: Res<CursorLocation<WorldCameras>>) {
+///     /* ... */
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_world_cursor);
+/// ```
+pub trait CameraFilter: TypePath + Send + Sync + 'static {
+    /// The [`QueryFilter`] applied to the cameras.
+    ///
+    /// [`QueryFilter`]: https://docs.rs/bevy/0.15.0/bevy/ecs/query/trait.QueryFilter.html
+    type Filter: QueryFilter + 'static;
+}
+
+impl CameraFilter for () {
+    type Filter = ();
+}
+
 /* -------------------------------------------------------------------------- */
 
 /// A resource that provides the [`Location`] data of the cursor.
 ///
 /// The [`Location`] is available only if the cursor is currently inside one
-/// of the windows area.
+/// of the windows area, unless the [`KeepStaleLocation`] resource exists.
+///
+/// The location is resolved with the cameras selected by the [`CameraFilter`] `F`.
 ///
 /// # Example
 ///
@@ -101,11 +1348,29 @@ pub struct UpdateCursorLocation;
 ///
 /// # let _ = IntoSystem::into_system(print_cursor_location);
 /// ```
-#[derive(Resource, Default)]
-pub struct CursorLocation(Option<Location>);
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorLocation<F: CameraFilter = ()>(
+    Option<Location>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
 
 /// The location of the cursor (its position, window, and camera).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
 pub struct Location {
     /// The cursor position in the window in logical pixels.
     ///
@@ -114,12 +1379,96 @@ pub struct Location {
     /// [`Window::cursor_position`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#method.cursor_position
     pub position: Vec2,
 
+    /// The cursor position in the window in logical pixels, before its alignment by the
+    /// [`PixelAlignment`].
+    ///
+    /// It's the same as [`position`](Self::position) if the resource doesn't exist.
+    pub raw_position: Vec2,
+
     /// The entity id of the window that contains the cursor.
     pub window: Entity,
 
     /// The entity id of the camera used to compute the world position of the cursor.
     pub camera: Entity,
 
+    /// The [`order`] of the [`camera`](Self::camera).
+    ///
+    /// [`order`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/struct.Camera.html#structfield.order
+    pub camera_order: isize,
+
+    /// The viewport of the [`camera`](Self::camera) on its render target, in logical
+    /// pixels.
+    ///
+    /// This is [`None`] if the size of the render target is unknown.
+    pub viewport_rect: Option<Rect>,
+
+    /// The viewport of the [`camera`](Self::camera) on its render target, in physical
+    /// pixels.
+    ///
+    /// This is [`None`] if the size of the render target is unknown.
+    pub physical_viewport_rect: Option<URect>,
+
+    /// The [`RenderLayers`] of the [`camera`](Self::camera), to filter the entities
+    /// that the camera can see.
+    ///
+    /// [`RenderLayers`]: https://docs.rs/bevy/0.15.0/bevy/render/view/struct.RenderLayers.html
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub render_layers: RenderLayers,
+
+    /// The kind of pointer that drives the cursor.
+    pub kind: PointerKind,
+
+    /// Whether the cursor is locked and [`position`](Self::position) is a virtual position.
+    ///
+    /// See [`GrabbedCursorTracking`].
+    pub grabbed: bool,
+
+    /// Whether the cursor left the window while it is captured, in which case
+    /// [`position`](Self::position) is extrapolated from the mouse motion.
+    ///
+    /// See [`PointerCapture`].
+    pub outside_window: bool,
+
+    /// The scale factor of the [`window`](Self::window) when this location has been
+    /// computed, to convert the [`position`](Self::position) to physical pixels.
+    ///
+    /// See [`Window::scale_factor`].
+    ///
+    /// [`Window::scale_factor`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Window.html#method.scale_factor
+    pub scale_factor: f32,
+
+    /// The [`position`](Self::position) averaged over the last frames.
+    ///
+    /// This is [`None`] if the [`CursorStabilization`] resource doesn't exist.
+    pub stabilized_position: Option<Vec2>,
+
+    /// Whether the cursor is over a [`CursorBlocker`], in which case the interactions
+    /// beneath it should be ignored.
+    #[cfg(feature = "ui")]
+    pub blocked: bool,
+
+    /// Whether the cursor has left every window and this is its last known location.
+    ///
+    /// See [`KeepStaleLocation`].
+    pub is_stale: bool,
+
+    /// The time elapsed since the startup of the app when this location has been
+    /// computed, as given by [`Time<Real>`].
+    ///
+    /// It's only updated when the location changes, so it can be used to detect
+    /// that the cursor hasn't moved for a while, or to measure the input latency.
+    /// For a [stale](Self::is_stale) location, it's the time when the cursor left.
+    ///
+    /// [`Time<Real>`]: https://docs.rs/bevy/0.15.0/bevy/time/struct.Real.html
+    pub timestamp: Duration,
+
+    /// The [`FrameCount`] when this location has been computed.
+    ///
+    /// Like the [`timestamp`](Self::timestamp), it's only updated when the location changes.
+    ///
+    /// [`FrameCount`]: https://docs.rs/bevy/0.15.0/bevy/core/struct.FrameCount.html
+    pub frame: u32,
+
     /// The position of the cursor in the world coordinates.
     ///
     /// This value is computed with [`Camera::viewport_to_world_2d`].
@@ -128,6 +1477,39 @@ pub struct Location {
     #[cfg(feature = "2d")]
     pub world_position: Vec2,
 
+    /// The cell of the [`CursorSnap`] grid that contains the
+    /// [`world_position`](Self::world_position).
+    ///
+    /// This is [`None`] if the resource doesn't exist.
+    #[cfg(feature = "2d")]
+    pub snapped_cell: Option<IVec2>,
+
+    /// The world position of the center of the [`snapped_cell`](Self::snapped_cell).
+    ///
+    /// This is [`None`] if the [`CursorSnap`] resource doesn't exist.
+    #[cfg(feature = "2d")]
+    pub snapped_world_position: Option<Vec2>,
+
+    /// Where the [`world_position`](Self::world_position) is within the
+    /// [`snapped_cell`](Self::snapped_cell).
+    ///
+    /// This is [`None`] if the [`CursorSnap`] resource doesn't exist.
+    #[cfg(feature = "2d")]
+    pub snapped_cell_position: Option<CellPosition>,
+
+    /// The nearest entity with a [`CursorMagnet`] within the radius of the
+    /// [`CursorMagnetism`] around the [`world_position`](Self::world_position).
+    ///
+    /// This is [`None`] if the resource doesn't exist or if no magnet is close enough.
+    #[cfg(feature = "2d")]
+    pub magnet_target: Option<Entity>,
+
+    /// The world position of the [`magnet_target`](Self::magnet_target).
+    ///
+    /// This is [`None`] if the resource doesn't exist or if no magnet is close enough.
+    #[cfg(feature = "2d")]
+    pub magnet_world_position: Option<Vec2>,
+
     /// The [`Ray3d`] emitted by the cursor from the camera.
     ///
     /// This value is computed with [`Camera::viewport_to_world`].
@@ -136,9 +1518,149 @@ pub struct Location {
     /// [`Camera::viewport_to_world`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/struct.Camera.html#method.viewport_to_world
     #[cfg(feature = "3d")]
     pub ray: Ray3d,
+
+    /// The projection of the [`ray`](Self::ray) defined by the [`CursorProjection`] resource.
+    ///
+    /// This is [`None`] if the resource doesn't exist or if the ray doesn't reach the
+    /// projection plane.
+    #[cfg(feature = "3d")]
+    pub projected_position: Option<Vec3>,
+
+    /// The part of the [`ray`](Self::ray) between the near and the far planes of the
+    /// camera.
+    ///
+    /// This is [`None`] if the camera has no [`Frustum`] or if the ray doesn't cross
+    /// its view volume.
+    ///
+    /// [`Frustum`]: https://docs.rs/bevy/0.15.0/bevy/render/primitives/struct.Frustum.html
+    #[cfg(feature = "3d")]
+    pub ray_segment: Option<RaySegment>,
+
+    /// The nearest hit of the [`ray`](Self::ray) on the surfaces of the registered
+    /// [`CursorSurfaceSampler`]s, like a terrain.
+    ///
+    /// This is [`None`] if no sampler is registered or if the ray doesn't hit any
+    /// surface.
+    #[cfg(feature = "3d")]
+    pub ground_hit: Option<Vec3>,
+
+    /// The walkable position of the registered [`CursorNavMesh`]es nearest to the
+    /// [`ground_hit`](Self::ground_hit), or to the
+    /// [`projected_position`](Self::projected_position) without ground hit.
+    ///
+    /// This is [`None`] if no nav mesh is registered or if there is no walkable
+    /// position near the cursor.
+    #[cfg(feature = "3d")]
+    pub nav_target: Option<Vec3>,
+}
+
+impl Location {
+    /// Returns the intersection of the [`ray`](Self::ray) with the XY plane of the given
+    /// transform, in world coordinates.
+    ///
+    /// This is useful to point at rotated boards, tilted UI panels in 3D or
+    /// isometric-in-3D layouts.
+    ///
+    /// Returns [`None`] if the ray doesn't intersect the plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// #[derive(Component)]
+    /// struct Board;
+    ///
+    /// fn point_at_board(cursor: Res<CursorLocation>, board: Single<&GlobalTransform, With<Board>>) {
+    ///     if let Some(point) = cursor.get().and_then(|location| location.project_onto(*board)) {
+    ///         info!("Pointing at {point:?}");
+    ///     }
+    /// }
+    ///
+    /// # let _ = IntoSystem::into_system(point_at_board);
+    /// ```
+    #[cfg(feature = "3d")]
+    pub fn project_onto(&self, transform: &GlobalTransform) -> Option<Vec3> {
+        let plane_origin = transform.translation();
+        let plane = InfinitePlane3d::new(transform.back());
+        let distance = self.ray.intersect_plane(plane_origin, plane)?;
+        Some(self.ray.get_point(distance))
+    }
+
+    /// Returns the intersection of the [`ray`](Self::ray) with the XY plane of the given
+    /// transform, expressed in the local space of that transform.
+    ///
+    /// See [`project_onto`](Self::project_onto).
+    ///
+    /// Returns [`None`] if the ray doesn't intersect the plane.
+    #[cfg(feature = "3d")]
+    pub fn project_onto_local(&self, transform: &GlobalTransform) -> Option<Vec3> {
+        let point = self.project_onto(transform)?;
+        Some(transform.affine().inverse().transform_point3(point))
+    }
+
+    /// Returns the [`world_position`](Self::world_position) expressed in the local space
+    /// of the given transform.
+    ///
+    /// This is useful to find the cell of a tilemap, a board or a canvas under the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// const TILE_SIZE: f32 = 16.0;
+    ///
+    /// #[derive(Component)]
+    /// struct Tilemap;
+    ///
+    /// fn hovered_tile(cursor: Res<CursorLocation>, tilemap: Single<&GlobalTransform, With<Tilemap>>) {
+    ///     if let Some(position) = cursor.get().map(|location| location.relative_to(*tilemap)) {
+    ///         let tile = (position / TILE_SIZE).floor().as_ivec2();
+    ///         info!("Hovered tile: {tile}");
+    ///     }
+    /// }
+    ///
+    /// # let _ = IntoSystem::into_system(hovered_tile);
+    /// ```
+    #[cfg(feature = "2d")]
+    pub fn relative_to(&self, transform: &GlobalTransform) -> Vec2 {
+        transform
+            .affine()
+            .inverse()
+            .transform_point3(self.world_position.extend(0.0))
+            .truncate()
+    }
+}
+
+impl<F: CameraFilter> Default for CursorLocation<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(None, PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorLocation<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorLocation<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorLocation<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorLocation").field(&self.0).finish()
+    }
 }
 
-impl CursorLocation {
+impl<F: CameraFilter> CursorLocation<F> {
     /// The [`Location`] of the cursor.
     ///
     /// Returns [`None`] if the cursor is outside any window area.
@@ -175,6 +1697,31 @@ impl CursorLocation {
         self.get().map(|data| data.camera)
     }
 
+    /// The order of the camera used to compute the world position of the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area.
+    #[inline]
+    pub fn camera_order(&self) -> Option<isize> {
+        self.get().map(|data| data.camera_order)
+    }
+
+    /// The viewport of the camera on its render target, in logical pixels.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if the size of
+    /// the render target of the camera is unknown.
+    #[inline]
+    pub fn viewport_rect(&self) -> Option<Rect> {
+        self.get().and_then(|data| data.viewport_rect)
+    }
+
+    /// The render layers of the camera used to compute the world position of the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area.
+    #[inline]
+    pub fn render_layers(&self) -> Option<&RenderLayers> {
+        self.get().map(|data| &data.render_layers)
+    }
+
     /// The position of the cursor in the world coordinates.
     ///
     /// This value is computed with [`Camera::viewport_to_world_2d`].
@@ -188,6 +1735,56 @@ impl CursorLocation {
         self.get().map(|data| data.world_position)
     }
 
+    /// The cell of the [`CursorSnap`] grid under the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if there is
+    /// no [`CursorSnap`] resource.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn snapped_cell(&self) -> Option<IVec2> {
+        self.get().and_then(|data| data.snapped_cell)
+    }
+
+    /// The world position of the center of the [`CursorSnap`] cell under the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if there is
+    /// no [`CursorSnap`] resource.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn snapped_world_position(&self) -> Option<Vec2> {
+        self.get().and_then(|data| data.snapped_world_position)
+    }
+
+    /// Where the cursor is within the [`CursorSnap`] cell under it.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if there is
+    /// no [`CursorSnap`] resource.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn snapped_cell_position(&self) -> Option<CellPosition> {
+        self.get().and_then(|data| data.snapped_cell_position)
+    }
+
+    /// The nearest [`CursorMagnet`] entity that attracts the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, if there is
+    /// no [`CursorMagnetism`] resource, or if no magnet is close enough.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn magnet_target(&self) -> Option<Entity> {
+        self.get().and_then(|data| data.magnet_target)
+    }
+
+    /// The world position of the [`CursorMagnet`] entity that attracts the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, if there is
+    /// no [`CursorMagnetism`] resource, or if no magnet is close enough.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub fn magnet_world_position(&self) -> Option<Vec2> {
+        self.get().and_then(|data| data.magnet_world_position)
+    }
+
     /// The [`Ray3d`] emitted by the cursor from the camera.
     ///
     /// This value is computed with [`Camera::viewport_to_world`].
@@ -201,90 +1798,530 @@ impl CursorLocation {
     pub fn ray(&self) -> Option<Ray3d> {
         self.get().map(|data| data.ray)
     }
+
+    /// The projection of the [`Ray3d`] defined by the [`CursorProjection`] resource.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if there is
+    /// no projected position.
+    ///
+    /// [`Ray3d`]: https://docs.rs/bevy/0.15.0/bevy/math/struct.Ray3d.html
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn projected_position(&self) -> Option<Vec3> {
+        self.get().and_then(|data| data.projected_position)
+    }
+
+    /// The part of the [`Ray3d`] between the near and the far planes of the camera.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if the ray
+    /// doesn't cross the view volume of the camera.
+    ///
+    /// [`Ray3d`]: https://docs.rs/bevy/0.15.0/bevy/math/struct.Ray3d.html
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn ray_segment(&self) -> Option<RaySegment> {
+        self.get().and_then(|data| data.ray_segment)
+    }
+
+    /// The nearest hit of the [`Ray3d`] on the surfaces of the registered
+    /// [`CursorSurfaceSampler`]s.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if the ray
+    /// doesn't hit any surface.
+    ///
+    /// [`Ray3d`]: https://docs.rs/bevy/0.15.0/bevy/math/struct.Ray3d.html
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn ground_hit(&self) -> Option<Vec3> {
+        self.get().and_then(|data| data.ground_hit)
+    }
+
+    /// The walkable position of the registered [`CursorNavMesh`]es nearest to the
+    /// cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if there is no
+    /// walkable position near the cursor.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub fn nav_target(&self) -> Option<Vec3> {
+        self.get().and_then(|data| data.nav_target)
+    }
 }
 
 /* -------------------------------------------------------------------------- */
 
+/// The query used to get the cameras that may render the cursor.
+type CameraQuery<'w, 's, F = ()> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static GlobalTransform,
+        &'static Camera,
+        Option<&'static CursorPriority>,
+    ),
+    F,
+>;
+
+/// The resources that define how the cameras that render the cursor are selected.
+#[derive(SystemParam)]
+struct CameraSelection<'w, 's, F: CameraFilter> {
+    tie_break: Res<'w, CameraTieBreak>,
+    bounds: Res<'w, ViewportBounds>,
+    predicate: Option<Res<'w, CameraPredicate<F>>>,
+    resolvers: Res<'w, CursorTargetResolvers>,
+    displays: Res<'w, TargetDisplays>,
+    layers_q: Query<'w, 's, &'static RenderLayers>,
+    #[cfg(feature = "2d")]
+    pixel_snap_q: Query<'w, 's, &'static CursorPixelSnap>,
+}
+
+impl<F: CameraFilter> CameraSelection<'_, '_, F> {
+    /// Returns `true` if the [`CameraPredicate`] accepts the camera.
+    #[inline]
+    fn accepts(&self, camera_ref: Entity, camera: &Camera) -> bool {
+        self.predicate
+            .as_ref()
+            .is_none_or(|predicate| (predicate.0)(camera_ref, camera))
+    }
+}
+
+/// A predicate on the cameras with which a [`TrackCursorPlugin`] resolves the cursor.
+///
+/// See [`TrackCursorPlugin::with_camera_predicate`].
+#[derive(Resource)]
+struct CameraPredicate<F: CameraFilter>(CameraPredicateFn, PhantomData<fn() -> F>);
+
+/// The function of a [`CameraPredicate`].
+type CameraPredicateFn = Arc<dyn Fn(Entity, &Camera) -> bool + Send + Sync>;
+
 /// Reads the current cursor position and update the [`CursorLocation`] resource.
-fn update_cursor_location_res(
+#[allow(clippy::too_many_arguments)]
+fn update_cursor_location_res<F: CameraFilter>(
     window_q: Query<(Entity, &Window, Has<PrimaryWindow>)>,
-    camera_q: Query<(Entity, &GlobalTransform, &Camera)>,
-    cursor: ResMut<CursorLocation>,
+    camera_q: CameraQuery<F::Filter>,
+    positions: position::WindowPositions,
+    crosshair: Option<Res<CrosshairMode>>,
+    selection: CameraSelection<F>,
+    cursor: ResMut<CursorLocation<F>>,
+    mut locations: ResMut<CursorLocations<F>>,
+    mut errors: EventWriter<CursorResolutionError>,
+    mut debug: Option<ResMut<CursorLocationDebug<F>>>,
+    mut stats: Option<ResMut<diagnostic::CursorStats>>,
+    clock: stamp::LocationClock,
+    keep_stale: Option<Res<KeepStaleLocation>>,
+    pause: pause::PauseState,
+    focused_only: Option<Res<FocusedWindowOnly>>,
 ) {
     let mut cursor = cursor.map_unchanged(|cursor| &mut cursor.0);
+    let now = clock.now();
+    let frozen = pause.is_frozen();
 
-    for (win_ref, window, is_primary) in &window_q {
-        // Get the window that contains the cursor.
-        let Some(cursor_position) = window.cursor_position() else {
-            continue;
-        };
-        let Some(physical_cursor_position) = window.physical_cursor_position() else {
-            continue;
-        };
-
-        // Get the cameras that render into the current window.
-        let mut cameras = camera_q
-            .iter()
-            .filter(|&(_, _, camera)| match camera.target {
-                RenderTarget::Window(WindowRef::Primary) => is_primary,
-                RenderTarget::Window(WindowRef::Entity(target_ref)) => target_ref == win_ref,
-                RenderTarget::Image(_) | RenderTarget::TextureView(_) => false,
-            })
-            // PERF: this is unlikely to have more than 4 cameras on the same window.
-            .collect::<SmallVec<[_; 4]>>();
-
-        // Cameras with a higher order are rendered later, and thus on top of lower order cameras.
-        // We want to handle them first.
-        cameras.sort_unstable_by_key(|&(_, _, camera)| camera.order);
-        let cameras = cameras.into_iter().rev();
+    if let Some(debug) = debug.as_mut() {
+        debug.clear();
+    }
 
-        for (camera_ref, cam_t, camera) in cameras {
-            let _ = cam_t; // Note: disable the `unused_variables` warning in no-default-feature.
+    let start = stats.is_some().then(Instant::now);
+    let mut considered_cameras = 0;
 
-            // Does the camera viewport contain the cursor ?
-            let contain_cursor = match camera.viewport {
-                Some(ref viewport) => {
-                    let Vec2 { x, y } = physical_cursor_position;
-                    let Vec2 { x: vx, y: vy } = viewport.physical_position.as_vec2();
-                    let Vec2 { x: vw, y: vh } = viewport.physical_size.as_vec2();
-                    x >= vx && x <= (vx + vw) && y >= vy && y <= (vy + vh)
+    let resolved = 'resolve: {
+        if crosshair.is_some() {
+            let mut location = crosshair::crosshair_location(&window_q, &camera_q, &selection);
+            if let Some(location) = location.as_mut() {
+                if frozen {
+                    location.freeze_world(locations.get(location.camera));
                 }
-                None => true,
-            };
+                location.stamp(locations.get(location.camera), now);
+            }
+            let resolved = location.is_some();
+            locations.set_if_neq(CursorLocations::resolved(
+                location.iter().cloned().collect(),
+            ));
+            cursor.set_if_neq(location);
+            break 'resolve resolved;
+        }
 
-            if !contain_cursor {
+        let current_camera = cursor.as_ref().as_ref().map(|location| location.camera);
+
+        for (win_ref, window, is_primary) in &window_q {
+            if focused_only.is_some() && !window.focused {
                 continue;
             }
 
-            #[cfg(feature = "2d")]
-            let Ok(world_position) = camera.viewport_to_world_2d(cam_t, cursor_position) else {
+            // Get the window that contains the cursor.
+            let Some(position) = positions.get(win_ref, window) else {
                 continue;
             };
+            let cursor_position = position.position;
 
-            #[cfg(feature = "3d")]
-            let Ok(ray) = camera.viewport_to_world(cam_t, cursor_position) else {
+            if stats.is_some() {
+                considered_cameras +=
+                    window_cameras(win_ref, is_primary, &camera_q, &selection).len();
+            }
+
+            if let Some(debug) = debug.as_mut() {
+                debug.record_window(
+                    win_ref,
+                    window,
+                    is_primary,
+                    cursor_position,
+                    &camera_q,
+                    &selection,
+                    current_camera,
+                );
+            }
+
+            let stack = resolve_locations(
+                win_ref,
+                window,
+                is_primary,
+                cursor_position,
+                &camera_q,
+                &selection,
+                current_camera,
+            )
+            .filter_map(|location| match location {
+                Ok(location) => Some(Location {
+                    raw_position: position.raw_position,
+                    kind: position.kind,
+                    grabbed: position.grabbed,
+                    ..location
+                }),
+                Err(error) => {
+                    errors.send(error);
+                    None
+                }
+            })
+            .map(|mut location| {
+                if frozen {
+                    location.freeze_world(locations.get(location.camera));
+                }
+                location.stamp(locations.get(location.camera), now);
+                location
+            })
+            .collect::<Vec<_>>();
+
+            if stack.is_empty() {
                 continue;
-            };
+            }
+
+            cursor.set_if_neq(stack.first().cloned());
+            locations.set_if_neq(CursorLocations::resolved(stack));
+
+            // We found the correct window and cameras, we can stop here.
+            break 'resolve true;
+        }
+
+        // The cursor is outside of every windows.
+        let stale = keep_stale
+            .is_some()
+            .then(|| cursor.clone())
+            .flatten()
+            .map(|location| location.into_stale(now));
+        cursor.set_if_neq(stale);
+        locations.set_if_neq(CursorLocations::default());
+        false
+    };
+
+    if let (Some(stats), Some(start)) = (stats.as_mut(), start) {
+        stats.record(start.elapsed(), considered_cameras, resolved);
+    }
+}
+
+/// A camera that renders into a window.
+///
+/// The [`Rect`] is the area of the window, in logical pixels, where the render target
+/// of the camera is displayed when it is not the window itself.
+/// See [`CursorTargetResolver`].
+type WindowCamera<'a> = (Entity, &'a GlobalTransform, &'a Camera, Option<Rect>);
+
+/// Returns the cameras that render into the given window,
+/// sorted from the topmost to the bottommost.
+///
+/// Inactive cameras and cameras rejected by the [`CameraPredicate`] are skipped.
+fn window_cameras<'a, F: CameraFilter>(
+    win_ref: Entity,
+    is_primary: bool,
+    camera_q: &'a CameraQuery<F::Filter>,
+    selection: &CameraSelection<F>,
+) -> SmallVec<[WindowCamera<'a>; 4]> {
+    let tie_break = *selection.tie_break;
+
+    let mut cameras = camera_q
+        .iter()
+        .filter_map(|(camera_ref, cam_t, camera, priority)| {
+            let display = camera_display(win_ref, is_primary, camera, selection)?;
+            Some((camera_ref, cam_t, camera, priority, display))
+        })
+        .filter(|&(camera_ref, _, camera, _, _)| {
+            camera.is_active && selection.accepts(camera_ref, camera)
+        })
+        // PERF: this is unlikely to have more than 4 cameras on the same window.
+        .collect::<SmallVec<[_; 4]>>();
+
+    // Cameras with a higher order are rendered later, and thus on top of lower order cameras.
+    // We want to handle them first, unless a `CursorPriority` says otherwise.
+    cameras.sort_unstable_by(
+        |&(a_ref, _, a, a_priority, _), &(b_ref, _, b, b_priority, _)| {
+            CursorPriority::of(b_priority, b)
+                .cmp(&CursorPriority::of(a_priority, a))
+                .then_with(|| tie_break.compare((a_ref, a), (b_ref, b)))
+        },
+    );
+
+    cameras
+        .into_iter()
+        .map(|(camera_ref, cam_t, camera, _, display)| (camera_ref, cam_t, camera, display))
+        .collect()
+}
+
+/// Returns whether the camera renders into the given window.
+///
+/// The inner [`Rect`] is the area of the window where the render target of the camera
+/// is displayed when it is not the window itself. See [`WindowCamera`].
+fn camera_display<F: CameraFilter>(
+    win_ref: Entity,
+    is_primary: bool,
+    camera: &Camera,
+    selection: &CameraSelection<F>,
+) -> Option<Option<Rect>> {
+    match camera.target {
+        RenderTarget::Window(WindowRef::Primary) => is_primary.then_some(None),
+        RenderTarget::Window(WindowRef::Entity(target_ref)) => {
+            (target_ref == win_ref).then_some(None)
+        }
+        RenderTarget::Image(_) | RenderTarget::TextureView(_) => {
+            let (target_ref, rect) = selection
+                .resolvers
+                .resolve(&camera.target)
+                .or_else(|| selection.displays.resolve(&camera.target))?;
+            (target_ref == win_ref).then_some(Some(rect))
+        }
+    }
+}
+
+/// Returns the entity id of the window a camera renders into.
+fn target_window(target: &RenderTarget, primary_window: Option<Entity>) -> Option<Entity> {
+    match *target {
+        RenderTarget::Window(WindowRef::Primary) => primary_window,
+        RenderTarget::Window(WindowRef::Entity(window)) => Some(window),
+        RenderTarget::Image(_) | RenderTarget::TextureView(_) => None,
+    }
+}
+
+/// Finds the camera that renders the cursor at `cursor_position` on the given window
+/// and computes its [`Location`].
+///
+/// Returns [`None`] if no camera renders at this position.
+fn resolve_location<F: CameraFilter>(
+    win_ref: Entity,
+    window: &Window,
+    is_primary: bool,
+    cursor_position: Vec2,
+    camera_q: &CameraQuery<F::Filter>,
+    selection: &CameraSelection<F>,
+    current_camera: Option<Entity>,
+) -> Option<Location> {
+    resolve_locations(
+        win_ref,
+        window,
+        is_primary,
+        cursor_position,
+        camera_q,
+        selection,
+        current_camera,
+    )
+    .find_map(Result::ok)
+}
 
-            cursor.set_if_neq(Some(Location {
-                position: cursor_position,
-                window: win_ref,
-                camera: camera_ref,
+/// Computes the [`Location`] of the cursor at `cursor_position` on the given window
+/// through every camera that renders at this position, from the topmost to the bottommost.
+///
+/// The cameras through which the location can't be computed yield an error.
+///
+/// `current_camera` is the camera currently selected, whose viewport is grown by the
+/// [`ViewportBounds::hysteresis`]. It is tried before the other cameras with the same
+/// priority, so that it keeps the cursor while the cursor is within this margin.
+fn resolve_locations<'a, F: CameraFilter>(
+    win_ref: Entity,
+    window: &Window,
+    is_primary: bool,
+    cursor_position: Vec2,
+    camera_q: &'a CameraQuery<F::Filter>,
+    selection: &'a CameraSelection<F>,
+    current_camera: Option<Entity>,
+) -> impl Iterator<Item = Result<Location, CursorResolutionError>> + 'a {
+    let window_scale_factor = window.scale_factor();
+    let bounds = *selection.bounds;
 
-                #[cfg(feature = "2d")]
-                world_position,
+    let mut cameras = window_cameras(win_ref, is_primary, camera_q, selection);
 
-                #[cfg(feature = "3d")]
-                ray,
-            }));
+    if bounds.hysteresis > 0.0 {
+        let priority = |camera_ref: Entity| {
+            camera_q
+                .get(camera_ref)
+                .ok()
+                .map(|(_, _, camera, priority)| CursorPriority::of(priority, camera))
+        };
 
-            // We found the correct window and camera, we can stop here.
-            return;
+        // Note: the cameras are sorted by priority, so the cameras with the same
+        // priority as the current camera are contiguous.
+        if let Some(current) = current_camera.and_then(|current| {
+            cameras
+                .iter()
+                .position(|&(camera_ref, ..)| camera_ref == current)
+        }) {
+            let current_priority = priority(cameras[current].0);
+            let first = cameras
+                .iter()
+                .position(|&(camera_ref, ..)| priority(camera_ref) == current_priority)
+                .unwrap_or(current);
+            cameras[first..=current].rotate_right(1);
         }
     }
 
-    // The cursor is outside of every windows.
-    cursor.set_if_neq(None);
+    cameras
+        .into_iter()
+        .filter_map(move |(camera_ref, cam_t, camera, display)| {
+            // The position of the cursor on the render target of the camera.
+            let (target_position, scale_factor) = match display {
+                None => (cursor_position, window_scale_factor),
+                Some(rect) => {
+                    if !rect.contains(cursor_position) {
+                        return None;
+                    }
+                    let target_size = camera.logical_target_size().unwrap_or(rect.size());
+                    (
+                        (cursor_position - rect.min) / rect.size() * target_size,
+                        camera.target_scaling_factor().unwrap_or(1.0),
+                    )
+                }
+            };
+
+            // Does the camera viewport contain the cursor ?
+            if let Some(ref viewport) = camera.viewport {
+                let margin = if Some(camera_ref) == current_camera {
+                    bounds.hysteresis * scale_factor
+                } else {
+                    0.0
+                };
+                if !bounds.contains(viewport, target_position * scale_factor, margin) {
+                    return None;
+                }
+            }
+
+            #[cfg(feature = "2d")]
+            let pixel_snap = selection.pixel_snap_q.get(camera_ref).ok();
+
+            #[cfg(feature = "2d")]
+            let target_position = pixel_snap.map_or(target_position, |pixel_snap| {
+                pixel_snap.align_target(target_position, scale_factor)
+            });
+
+            let location = camera_location(win_ref, camera_ref, cam_t, camera, target_position)
+                .map(|location| Location {
+                    position: cursor_position,
+                    raw_position: cursor_position,
+                    scale_factor: window_scale_factor,
+                    render_layers: selection
+                        .layers_q
+                        .get(camera_ref)
+                        .cloned()
+                        .unwrap_or_default(),
+                    #[cfg(feature = "2d")]
+                    world_position: pixel_snap.map_or(location.world_position, |pixel_snap| {
+                        pixel_snap.snap_world(location.world_position)
+                    }),
+                    ..location
+                });
+
+            Some(location)
+        })
+}
+
+/// Computes the [`Location`] of the cursor at `cursor_position` on the given window
+/// through the given camera.
+///
+/// Returns an error if the world conversions fail.
+fn camera_location(
+    win_ref: Entity,
+    camera_ref: Entity,
+    cam_t: &GlobalTransform,
+    camera: &Camera,
+    cursor_position: Vec2,
+) -> Result<Location, CursorResolutionError> {
+    let _ = cam_t; // Note: disable the `unused_variables` warning in no-default-feature.
+
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    let error = |error: bevy::render::camera::ViewportConversionError| CursorResolutionError {
+        window: win_ref,
+        camera: camera_ref,
+        reason: error.into(),
+    };
+
+    #[cfg(feature = "2d")]
+    let world_position = camera
+        .viewport_to_world_2d(cam_t, cursor_position)
+        .map_err(error)?;
+
+    #[cfg(feature = "3d")]
+    let ray = camera
+        .viewport_to_world(cam_t, cursor_position)
+        .map_err(error)?;
+
+    Ok(Location {
+        position: cursor_position,
+        raw_position: cursor_position,
+        window: win_ref,
+        camera: camera_ref,
+        camera_order: camera.order,
+        viewport_rect: camera.logical_viewport_rect(),
+        physical_viewport_rect: camera.physical_viewport_rect(),
+        render_layers: RenderLayers::default(),
+        kind: PointerKind::Mouse,
+        grabbed: false,
+        outside_window: false,
+        scale_factor: 1.0,
+        stabilized_position: None,
+        #[cfg(feature = "ui")]
+        blocked: false,
+        is_stale: false,
+        timestamp: Duration::ZERO,
+        frame: 0,
+
+        #[cfg(feature = "2d")]
+        world_position,
+
+        #[cfg(feature = "2d")]
+        snapped_cell: None,
+
+        #[cfg(feature = "2d")]
+        snapped_world_position: None,
+
+        #[cfg(feature = "2d")]
+        snapped_cell_position: None,
+
+        #[cfg(feature = "2d")]
+        magnet_target: None,
+
+        #[cfg(feature = "2d")]
+        magnet_world_position: None,
+
+        #[cfg(feature = "3d")]
+        ray,
+
+        #[cfg(feature = "3d")]
+        projected_position: None,
+
+        #[cfg(feature = "3d")]
+        ray_segment: None,
+
+        #[cfg(feature = "3d")]
+        ground_hit: None,
+
+        #[cfg(feature = "3d")]
+        nav_target: None,
+    })
 }
 
 /* -------------------------------------------------------------------------- */
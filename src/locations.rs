@@ -0,0 +1,142 @@
+//! Locations of the cursor through every camera under it.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+use crate::{CameraFilter, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the [`Location`] of the cursor through every camera whose
+/// viewport contains it, sorted from the topmost to the bottommost camera.
+///
+/// The first location is the one provided by [`CursorLocation`]. The others are useful
+/// when several cameras render on top of each other, for instance an editor overlay
+/// over the scene view.
+///
+/// The locations are resolved with the cameras selected by the [`CameraFilter`] `F`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_cursor_locations(cursor: Res<CursorLocations>) {
+///     for location in cursor.iter() {
+///         info!("Camera {:?} at {:?}", location.camera, location.position);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_cursor_locations);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorLocations<F: CameraFilter = ()>(
+    Vec<Location>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
+
+impl<F: CameraFilter> Default for CursorLocations<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorLocations<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorLocations<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorLocations<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorLocations").field(&self.0).finish()
+    }
+}
+
+impl<F: CameraFilter> CursorLocations<F> {
+    /// Iterates over the [`Location`]s of the cursor, from the topmost to the bottommost camera.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Location> {
+        self.0.iter()
+    }
+
+    /// The [`Location`] of the cursor through the given camera.
+    ///
+    /// Returns [`None`] if the viewport of this camera doesn't contain the cursor.
+    #[inline]
+    pub fn get(&self, camera: Entity) -> Option<&Location> {
+        self.0.iter().find(|location| location.camera == camera)
+    }
+
+    /// The [`Location`] of the cursor through the topmost camera that renders
+    /// any of the given layers.
+    ///
+    /// This is useful when several cameras cover the same viewport, for instance
+    /// a gameplay camera and a HUD camera.
+    ///
+    /// Returns [`None`] if none of the cameras under the cursor renders these layers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::render::view::RenderLayers;
+    /// # use bevy_cursor::prelude::*;
+    /// const HUD_LAYER: usize = 2;
+    ///
+    /// fn print_hud_cursor(cursor: Res<CursorLocations>) {
+    ///     if let Some(location) = cursor.for_layers(&RenderLayers::layer(HUD_LAYER)) {
+    ///         info!("HUD cursor: {:?}", location.position);
+    ///     }
+    /// }
+    ///
+    /// # let _ = IntoSystem::into_system(print_hud_cursor);
+    /// ```
+    pub fn for_layers(&self, layers: &RenderLayers) -> Option<&Location> {
+        self.0
+            .iter()
+            .find(|location| location.render_layers.intersects(layers))
+    }
+
+    /// Returns `true` if the cursor is not over any camera.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates mutably over the [`Location`]s of the cursor.
+    #[cfg(any(feature = "2d", feature = "3d", feature = "ui"))]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Location> {
+        self.0.iter_mut()
+    }
+
+    /// Creates the [`CursorLocations`] from the resolved locations, sorted from the
+    /// topmost to the bottommost camera.
+    pub(crate) fn resolved(locations: Vec<Location>) -> Self {
+        Self(locations, PhantomData)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,114 @@
+//! Attraction of the cursor world position by the magnet entities.
+
+use bevy::prelude::*;
+
+use crate::derived::LocationsMut;
+use crate::CameraFilter;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that attracts the world position of the cursor to the nearest
+/// [`CursorMagnet`] entity.
+///
+/// When this resource exists, the [`Location::magnet_target`] and
+/// [`Location::magnet_world_position`] are the nearest entity with a [`CursorMagnet`]
+/// within the [`radius`](Self::radius) of the
+/// [`world_position`](crate::Location::world_position), and its world position.
+///
+/// This is a snap assist: the actual cursor isn't moved, but a gamepad cursor or an
+/// accessibility setting can target the
+/// [`magnet_world_position`](crate::Location::magnet_world_position) to reach the small
+/// targets easily.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorMagnetism::new(24.0));
+///     commands.spawn((Sprite::default(), Transform::default(), CursorMagnet));
+/// }
+///
+/// fn print_target(cursor: Res<CursorLocation>) {
+///     if let Some(entity) = cursor.magnet_target() {
+///         info!("Targeting {entity}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(print_target);
+/// ```
+///
+/// [`Location::magnet_target`]: crate::Location::magnet_target
+/// [`Location::magnet_world_position`]: crate::Location::magnet_world_position
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorMagnetism {
+    /// The distance, in world units, under which a [`CursorMagnet`] attracts the cursor.
+    pub radius: f32,
+}
+
+impl CursorMagnetism {
+    /// Creates a new [`CursorMagnetism`] with the given radius, in world units.
+    #[inline]
+    pub fn new(radius: f32) -> Self {
+        Self { radius }
+    }
+}
+
+/// A marker component for the entities that attract the cursor when the
+/// [`CursorMagnetism`] resource exists.
+///
+/// The cursor is attracted to the world position of the entity, defined by its
+/// [`GlobalTransform`]. The hidden entities don't attract the cursor.
+///
+/// [`GlobalTransform`]: https://docs.rs/bevy/0.15.0/bevy/transform/components/struct.GlobalTransform.html
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CursorMagnet;
+
+/* -------------------------------------------------------------------------- */
+
+type MagnetQuery<'a> = (Entity, &'a GlobalTransform, Option<&'a InheritedVisibility>);
+
+/// Computes the `magnet_target` and the `magnet_world_position` of every location.
+pub(crate) fn attract_cursor_location<F: CameraFilter>(
+    magnetism: Option<Res<CursorMagnetism>>,
+    magnet_q: Query<MagnetQuery, With<CursorMagnet>>,
+    mut locations: LocationsMut<F>,
+) {
+    let radius = magnetism.map(|magnetism| magnetism.radius);
+
+    locations.for_each_location_mut(|location| {
+        let target = radius.and_then(|radius| {
+            magnet_q
+                .iter()
+                .filter(|(_, _, visibility)| {
+                    !visibility.is_some_and(|visibility| !visibility.get())
+                })
+                .map(|(entity, transform, _)| {
+                    let position = transform.translation().truncate();
+                    (entity, position, position.distance(location.world_position))
+                })
+                .filter(|&(_, _, distance)| distance <= radius)
+                .min_by(|(a_ref, _, a), (b_ref, _, b)| a.total_cmp(b).then(a_ref.cmp(b_ref)))
+        });
+
+        let entity = target.map(|(entity, ..)| entity);
+        let position = target.map(|(_, position, _)| position);
+
+        let changed =
+            location.magnet_target != entity || location.magnet_world_position != position;
+        location.magnet_target = entity;
+        location.magnet_world_position = position;
+        changed
+    });
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,169 @@
+//! Measurement of distances and angles between world points picked with the cursor.
+
+use bevy::prelude::*;
+
+use crate::{CursorLocation, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that records the world points clicked with the cursor, and measures
+/// the distances and the angles between them.
+///
+/// When this resource exists, each press of the [`button`](Self::button) records the
+/// world point under the cursor, according to the [`CursorLocation`] of the default
+/// [`TrackCursorPlugin`]: the projected position if the feature `3d` is enabled and
+/// the [`CursorProjection`] resource exists, the world position otherwise. Once
+/// [`max_points`](Self::max_points) points have been recorded, the next click starts
+/// a new measurement.
+///
+/// The [`CursorDebugOverlayPlugin`] draws the measurement with gizmos.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorMeasurement::default());
+/// }
+///
+/// fn print_measurement(measurement: Res<CursorMeasurement>) {
+///     if measurement.is_changed() && measurement.is_complete() {
+///         info!("Distance: {}", measurement.total_distance());
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(print_measurement);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+/// [`CursorProjection`]: crate::CursorProjection
+/// [`CursorDebugOverlayPlugin`]: crate::CursorDebugOverlayPlugin
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorMeasurement {
+    /// The mouse button that records the points.
+    pub button: MouseButton,
+
+    /// The number of points of a measurement.
+    pub max_points: usize,
+
+    /// The recorded points, in world coordinates.
+    points: Vec<Vec3>,
+}
+
+impl Default for CursorMeasurement {
+    /// Measures the distance between two points clicked with the left button.
+    #[inline]
+    fn default() -> Self {
+        Self::new(MouseButton::Left, 2)
+    }
+}
+
+impl CursorMeasurement {
+    /// Creates a new [`CursorMeasurement`] that records up to `max_points` points
+    /// clicked with `button`.
+    #[inline]
+    pub fn new(button: MouseButton, max_points: usize) -> Self {
+        Self {
+            button,
+            max_points,
+            points: Vec::new(),
+        }
+    }
+
+    /// The recorded points, in world coordinates.
+    #[inline]
+    pub fn points(&self) -> &[Vec3] {
+        &self.points
+    }
+
+    /// Returns `true` if [`max_points`](Self::max_points) points have been recorded.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.points.len() >= self.max_points
+    }
+
+    /// Forgets the recorded points.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Iterates over the lengths of the segments between the consecutive points.
+    #[inline]
+    pub fn distances(&self) -> impl Iterator<Item = f32> + '_ {
+        self.points.windows(2).map(|pair| pair[0].distance(pair[1]))
+    }
+
+    /// The length of the path through all the points.
+    #[inline]
+    pub fn total_distance(&self) -> f32 {
+        self.distances().sum()
+    }
+
+    /// Iterates over the angles, in radians, between the consecutive segments, at
+    /// each point except the first and the last one.
+    ///
+    /// The angle is [`None`] if one of the segments has a zero length.
+    #[inline]
+    pub fn angles(&self) -> impl Iterator<Item = Option<f32>> + '_ {
+        self.points.windows(3).map(|triple| {
+            let a = (triple[0] - triple[1]).try_normalize()?;
+            let b = (triple[2] - triple[1]).try_normalize()?;
+            Some(a.angle_between(b))
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The world point of the location recorded by the [`CursorMeasurement`].
+#[inline]
+fn world_point(location: &Location) -> Option<Vec3> {
+    #[cfg(feature = "3d")]
+    if let Some(position) = location.projected_position {
+        return Some(position);
+    }
+
+    #[cfg(feature = "2d")]
+    return Some(location.world_position.extend(0.0));
+
+    #[cfg(not(feature = "2d"))]
+    return None;
+}
+
+/// Records the points of the [`CursorMeasurement`].
+pub(crate) fn update_cursor_measurement(
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    cursor: Option<Res<CursorLocation>>,
+    mut measurement: ResMut<CursorMeasurement>,
+) {
+    // Note: the `ButtonInput` is only provided by the `InputPlugin`.
+    if !buttons.is_some_and(|buttons| buttons.just_pressed(measurement.button)) {
+        return;
+    }
+
+    let Some(point) = cursor
+        .as_ref()
+        .and_then(|cursor| cursor.get())
+        .filter(|location| !location.is_stale)
+        .and_then(world_point)
+    else {
+        return;
+    };
+
+    if measurement.is_complete() {
+        measurement.points.clear();
+    }
+
+    measurement.points.push(point);
+}
+
+/* -------------------------------------------------------------------------- */
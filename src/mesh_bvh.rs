@@ -0,0 +1,189 @@
+//! Bounding volume hierarchies of the triangles of the hover meshes.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/* -------------------------------------------------------------------------- */
+
+/// The maximum number of triangles in a leaf of a [`MeshBvh`].
+const LEAF_SIZE: usize = 4;
+
+/// A bounding volume hierarchy of the triangles of a mesh, projected on the XY plane,
+/// so a point is only tested against the triangles whose bounds contain it.
+pub(crate) struct MeshBvh {
+    /// The nodes, in depth-first order: the left child of a branch follows it.
+    nodes: Vec<BvhNode>,
+    /// The triangles, grouped by leaf.
+    triangles: Vec<[Vec2; 3]>,
+}
+
+/// A node of a [`MeshBvh`].
+struct BvhNode {
+    /// The bounds of the triangles of the node.
+    bounds: Rect,
+    /// The triangles of a leaf, or the index of the right child of a branch.
+    kind: BvhNodeKind,
+}
+
+/// The content of a [`BvhNode`].
+enum BvhNodeKind {
+    Leaf { start: usize, end: usize },
+    Branch { right: usize },
+}
+
+impl MeshBvh {
+    /// Builds the hierarchy of the triangles of the mesh.
+    ///
+    /// Returns [`None`] if the mesh doesn't have a triangle topology or has no
+    /// triangle.
+    pub(crate) fn new(mesh: &Mesh) -> Option<Self> {
+        let mut triangles = mesh
+            .triangles()
+            .ok()?
+            .map(|triangle| triangle.vertices.map(|vertex| vertex.truncate()))
+            .filter(|[a, b, c]| (*b - *a).perp_dot(*c - *a) != 0.0)
+            .collect::<Vec<_>>();
+
+        if triangles.is_empty() {
+            return None;
+        }
+
+        let mut nodes = Vec::new();
+        build(&mut nodes, &mut triangles, 0);
+
+        Some(Self { nodes, triangles })
+    }
+
+    /// The bounds of the triangles, in the local space of the mesh.
+    #[inline]
+    pub(crate) fn bounds(&self) -> Rect {
+        self.nodes[0].bounds
+    }
+
+    /// Returns `true` if a triangle contains the given point, in the local space of
+    /// the mesh.
+    pub(crate) fn contains(&self, point: Vec2) -> bool {
+        let mut stack = vec![0];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds.contains(point) {
+                continue;
+            }
+
+            match node.kind {
+                BvhNodeKind::Leaf { start, end } => {
+                    if self.triangles[start..end]
+                        .iter()
+                        .any(|&triangle| triangle_contains(triangle, point))
+                    {
+                        return true;
+                    }
+                }
+                BvhNodeKind::Branch { right } => {
+                    stack.push(right);
+                    stack.push(index + 1);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Builds the node of the triangles, which start at `offset` in the hierarchy, and
+/// its children.
+fn build(nodes: &mut Vec<BvhNode>, triangles: &mut [[Vec2; 3]], offset: usize) {
+    let bounds = triangles.iter().flatten().fold(
+        Rect::from_center_size(triangles[0][0], Vec2::ZERO),
+        |bounds, &vertex| bounds.union_point(vertex),
+    );
+
+    let index = nodes.len();
+    nodes.push(BvhNode {
+        bounds,
+        kind: BvhNodeKind::Leaf {
+            start: offset,
+            end: offset + triangles.len(),
+        },
+    });
+
+    if triangles.len() <= LEAF_SIZE {
+        return;
+    }
+
+    // The triangles are split at the median of their centers, along the longest axis.
+    let centroid = |[a, b, c]: &[Vec2; 3]| (*a + *b + *c) / 3.0;
+    let axis = if bounds.width() >= bounds.height() {
+        0
+    } else {
+        1
+    };
+    let middle = triangles.len() / 2;
+    triangles.select_nth_unstable_by(middle, |a, b| {
+        centroid(a)[axis].total_cmp(&centroid(b)[axis])
+    });
+
+    let (left, right) = triangles.split_at_mut(middle);
+    build(nodes, left, offset);
+    let right_index = nodes.len();
+    build(nodes, right, offset + middle);
+
+    nodes[index].kind = BvhNodeKind::Branch { right: right_index };
+}
+
+/// Returns `true` if the triangle contains the given point.
+pub(crate) fn triangle_contains([a, b, c]: [Vec2; 3], point: Vec2) -> bool {
+    let area = (b - a).perp_dot(c - a);
+    if area == 0.0 {
+        return false;
+    }
+
+    // The point is on the same side of the three edges as the triangle.
+    let sides = [
+        (b - a).perp_dot(point - a),
+        (c - b).perp_dot(point - b),
+        (a - c).perp_dot(point - c),
+    ];
+    sides.iter().all(|&side| side * area >= 0.0)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The [`MeshBvh`] of the meshes of the `HoverShape::Mesh` shapes.
+///
+/// A hierarchy is built when a shape of its mesh is indexed, and is dropped when its
+/// mesh is modified or removed.
+#[derive(Resource, Default)]
+pub(crate) struct HoverMeshBvhs(HashMap<AssetId<Mesh>, MeshBvh>);
+
+impl HoverMeshBvhs {
+    /// The hierarchy of the mesh, if it has been built.
+    #[inline]
+    pub(crate) fn get(&self, mesh: AssetId<Mesh>) -> Option<&MeshBvh> {
+        self.0.get(&mesh)
+    }
+
+    /// The hierarchy of the mesh, which is built if needed.
+    ///
+    /// Returns [`None`] if the mesh isn't loaded or has no triangle.
+    pub(crate) fn get_or_build(
+        &mut self,
+        mesh: AssetId<Mesh>,
+        meshes: &Assets<Mesh>,
+    ) -> Option<&MeshBvh> {
+        if !self.0.contains_key(&mesh) {
+            let bvh = MeshBvh::new(meshes.get(mesh)?)?;
+            self.0.insert(mesh, bvh);
+        }
+        self.0.get(&mesh)
+    }
+
+    /// Drops the hierarchy of the mesh.
+    #[inline]
+    pub(crate) fn remove(&mut self, mesh: AssetId<Mesh>) {
+        self.0.remove(&mesh);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
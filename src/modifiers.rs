@@ -0,0 +1,85 @@
+//! State of the keyboard modifiers attached to the cursor events.
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// The keyboard modifiers held when a cursor event has been sent.
+///
+/// The cursor events carry the modifiers held at the moment they have been sent, so
+/// the selection logic (additive selection, constrained drag, ...) doesn't have to
+/// read the [`ButtonInput<KeyCode>`] at a different time.
+///
+/// The left and right keys are not distinguished.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn select(mut drags: EventReader<CursorDragEnd>) {
+///     for drag in drags.read() {
+///         if drag.modifiers.shift {
+///             info!("Add to the selection");
+///         } else {
+///             info!("Replace the selection");
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(select);
+/// ```
+///
+/// [`ButtonInput<KeyCode>`]: https://docs.rs/bevy/0.15.0/bevy/input/struct.ButtonInput.html
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct KeyModifiers {
+    /// Whether a shift key is held.
+    pub shift: bool,
+    /// Whether a control key is held.
+    pub control: bool,
+    /// Whether an alt key is held.
+    pub alt: bool,
+    /// Whether a super key (Windows, Command, ...) is held.
+    pub super_key: bool,
+}
+
+impl KeyModifiers {
+    /// Returns `true` if no modifier is held.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns `true` if all the modifiers held in `other` are also held in `self`.
+    #[inline]
+    pub fn contains(&self, other: KeyModifiers) -> bool {
+        (self.shift || !other.shift)
+            && (self.control || !other.control)
+            && (self.alt || !other.alt)
+            && (self.super_key || !other.super_key)
+    }
+
+    /// Reads the modifiers held on the keyboard.
+    ///
+    /// Returns no modifiers if the keyboard input isn't available.
+    pub(crate) fn from_keys(keys: Option<&ButtonInput<KeyCode>>) -> Self {
+        let Some(keys) = keys else {
+            return Self::default();
+        };
+
+        Self {
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            control: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            super_key: keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,169 @@
+//! Orbit of 3D cameras with the cursor.
+
+use bevy::input::mouse::MouseScrollUnit;
+use bevy::prelude::*;
+
+use crate::input::OptionalEventReader;
+use crate::{CursorDragMove, ScrollAt};
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that orbits a 3D camera around a focus point with the drags of the
+/// cursor, and zooms it with the mouse wheel.
+///
+/// Dragging with the [`button`](Self::button) over the camera rotates it around the
+/// [`focus`](Self::focus): the horizontal movement changes the yaw and the vertical
+/// movement changes the pitch. Scrolling over the camera zooms toward the point
+/// under the cursor, on the plane that faces the camera through the focus.
+///
+/// The orbit relies on the drags and the scrolls of the default [`TrackCursorPlugin`],
+/// so the [`CursorDragSettings`] resource must exist and allow the button to drag.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorDragSettings::default());
+///     commands.spawn((
+///         Camera3d::default(),
+///         Transform::from_xyz(0.0, 5.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+///         CursorOrbitCamera::new(Vec3::ZERO),
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+/// [`CursorDragSettings`]: crate::CursorDragSettings
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorOrbitCamera {
+    /// The point, in world coordinates, around which the camera orbits.
+    pub focus: Vec3,
+
+    /// The mouse button that orbits the camera.
+    pub button: MouseButton,
+
+    /// How much the camera rotates for each logical pixel dragged, in radians.
+    pub sensitivity: f32,
+
+    /// How much the distance to the focus changes for each line scrolled.
+    ///
+    /// A pixel-based scroll of 100 pixels counts as a line.
+    pub zoom_speed: f32,
+
+    /// The smallest distance between the camera and the focus.
+    pub min_distance: f32,
+
+    /// The largest distance between the camera and the focus.
+    pub max_distance: f32,
+}
+
+impl Default for CursorOrbitCamera {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            focus: Vec3::ZERO,
+            button: MouseButton::Left,
+            sensitivity: 0.005,
+            zoom_speed: 0.1,
+            min_distance: 0.1,
+            max_distance: 1000.0,
+        }
+    }
+}
+
+impl CursorOrbitCamera {
+    /// Creates a new [`CursorOrbitCamera`] that orbits around `focus`.
+    #[inline]
+    pub fn new(focus: Vec3) -> Self {
+        Self { focus, ..default() }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The largest pitch of an orbit camera, just before the camera looks straight up or down.
+const MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+/// Orbits the [`CursorOrbitCamera`] cameras with the drags.
+pub(crate) fn orbit_cursor_cameras(
+    mut drag_move: OptionalEventReader<CursorDragMove>,
+    mut camera_q: Query<(&CursorOrbitCamera, &mut Transform)>,
+) {
+    for drag in drag_move.read() {
+        let Ok((orbit, mut transform)) = camera_q.get_mut(drag.location.camera) else {
+            continue;
+        };
+
+        if drag.button != orbit.button {
+            continue;
+        }
+
+        let offset = transform.translation - orbit.focus;
+        let radius = offset.length();
+        if radius == 0.0 {
+            continue;
+        }
+
+        let yaw = offset.x.atan2(offset.z) - drag.delta.x * orbit.sensitivity;
+        let pitch = ((offset.y / radius).asin() + drag.delta.y * orbit.sensitivity)
+            .clamp(-MAX_PITCH, MAX_PITCH);
+
+        let direction = Vec3::new(
+            pitch.cos() * yaw.sin(),
+            pitch.sin(),
+            pitch.cos() * yaw.cos(),
+        );
+
+        transform.translation = orbit.focus + direction * radius;
+        transform.look_at(orbit.focus, Vec3::Y);
+    }
+}
+
+/// Zooms the [`CursorOrbitCamera`] cameras under the cursor.
+pub(crate) fn zoom_cursor_orbit_cameras(
+    mut scroll_at: OptionalEventReader<ScrollAt>,
+    mut camera_q: Query<(&mut CursorOrbitCamera, &mut Transform)>,
+) {
+    for scroll in scroll_at.read() {
+        let Ok((mut orbit, mut transform)) = camera_q.get_mut(scroll.location.camera) else {
+            continue;
+        };
+
+        let lines = match scroll.unit {
+            MouseScrollUnit::Line => scroll.delta.y,
+            MouseScrollUnit::Pixel => scroll.delta.y / 100.0,
+        };
+
+        let distance = transform.translation.distance(orbit.focus);
+        if lines == 0.0 || distance == 0.0 {
+            continue;
+        }
+
+        // Scrolling up zooms in.
+        let ratio = (distance * (1.0 - orbit.zoom_speed).powf(lines))
+            .clamp(orbit.min_distance, orbit.max_distance)
+            / distance;
+
+        // The point under the cursor, on the plane that faces the camera through the focus.
+        let ray = scroll.location.ray;
+        let anchor = ray
+            .intersect_plane(orbit.focus, InfinitePlane3d::new(transform.forward()))
+            .map_or(orbit.focus, |distance| ray.get_point(distance));
+
+        // Scale the scene around the anchor, so it stays under the cursor.
+        transform.translation = anchor + (transform.translation - anchor) * ratio;
+        orbit.focus = anchor + (orbit.focus - anchor) * ratio;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
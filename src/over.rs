@@ -0,0 +1,87 @@
+//! Marker components on the window and the camera under the cursor.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{CameraFilter, CursorLocation};
+
+/* -------------------------------------------------------------------------- */
+
+/// A marker component on the window and the camera on which the cursor currently is.
+///
+/// The component is inserted and removed by the plugin according to the
+/// [`CursorLocation<F>`] resource, so it can be used as a query filter or with change
+/// detection.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn highlight_hovered_camera(camera_q: Query<&Camera, Added<CursorOver>>) {
+///     for camera in &camera_q {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(highlight_hovered_camera);
+/// ```
+#[derive(Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct CursorOver<F: CameraFilter = ()>(#[reflect(ignore)] PhantomData<fn() -> F>);
+
+impl<F: CameraFilter> Default for CursorOver<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorOver<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CursorOver")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Moves the [`CursorOver<F>`] markers to the window and the camera of the cursor.
+pub(crate) fn update_cursor_over<F: CameraFilter>(
+    cursor: Res<CursorLocation<F>>,
+    mut commands: Commands,
+    mut previous: Local<Option<(Entity, Entity)>>,
+) {
+    let current = cursor
+        .get()
+        .filter(|location| !location.is_stale)
+        .map(|location| (location.window, location.camera));
+
+    if current == *previous {
+        return;
+    }
+
+    let previous = std::mem::replace(&mut *previous, current);
+    let (previous_window, previous_camera) = previous.unzip();
+    let (current_window, current_camera) = current.unzip();
+
+    for (previous, current) in [
+        (previous_window, current_window),
+        (previous_camera, current_camera),
+    ] {
+        if previous == current {
+            continue;
+        }
+
+        // The entity may have been despawned in the meantime.
+        if let Some(mut entity) = previous.and_then(|previous| commands.get_entity(previous)) {
+            entity.remove::<CursorOver<F>>();
+        }
+
+        if let Some(mut entity) = current.and_then(|current| commands.get_entity(current)) {
+            entity.try_insert(CursorOver::<F>::default());
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
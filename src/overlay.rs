@@ -0,0 +1,195 @@
+//! A debug overlay that shows the location of the cursor.
+
+use std::fmt::Write;
+
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::color::palettes;
+use bevy::prelude::*;
+
+use crate::CursorLocation;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use crate::CursorMeasurement;
+
+/* -------------------------------------------------------------------------- */
+
+/// A plugin that draws the location of the cursor, for debug purpose.
+///
+/// It draws, with [gizmos]:
+/// - the world position of the cursor and the viewport of the camera under it
+///   (if the feature `2d` is enabled);
+/// - the ray emitted by the cursor and its projected position (if the feature
+///   `3d` is enabled);
+/// - the points of the [`CursorMeasurement`](crate::CursorMeasurement), if this
+///   resource exists (if the feature `2d` or `3d` is enabled);
+///
+/// and displays the fields of the [`Location`](crate::Location) in a text at the
+/// top-left corner of the screen.
+///
+/// This plugin uses the [`CursorLocation`] of the default [`TrackCursorPlugin`],
+/// and requires the `GizmoPlugin` (part of the `DefaultPlugins`).
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         TrackCursorPlugin::default(),
+///         CursorDebugOverlayPlugin,
+///     ))
+///     .run();
+/// ```
+///
+/// [gizmos]: https://docs.rs/bevy/0.15.0/bevy/gizmos/index.html
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CursorDebugOverlayPlugin;
+
+impl Plugin for CursorDebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_debug_text).add_systems(
+            Update,
+            (
+                draw_cursor_gizmos,
+                update_debug_text.run_if(resource_changed::<CursorLocation>),
+            ),
+        );
+
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        app.add_systems(
+            Update,
+            draw_measurement_gizmos.run_if(resource_exists::<CursorMeasurement>),
+        );
+    }
+}
+
+/// A marker for the text of the [`CursorDebugOverlayPlugin`].
+#[derive(Component)]
+struct CursorDebugText;
+
+/* -------------------------------------------------------------------------- */
+
+/// Spawns the text that displays the fields of the [`CursorLocation`].
+fn spawn_debug_text(mut commands: Commands) {
+    commands.spawn((
+        Text::default(),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(8.0),
+            ..default()
+        },
+        GlobalZIndex(i32::MAX),
+        CursorDebugText,
+    ));
+}
+
+/// Updates the text that displays the fields of the [`CursorLocation`].
+fn update_debug_text(
+    cursor: Res<CursorLocation>,
+    mut text_q: Query<&mut Text, With<CursorDebugText>>,
+) {
+    let mut content = String::new();
+
+    match cursor.get() {
+        None => content.push_str("No cursor"),
+        Some(location) => {
+            let _ = writeln!(content, "window: {}", location.window);
+            let _ = writeln!(content, "camera: {}", location.camera);
+            let _ = writeln!(content, "position: {}", location.position);
+            let _ = writeln!(content, "grabbed: {}", location.grabbed);
+            let _ = writeln!(content, "outside window: {}", location.outside_window);
+            let _ = writeln!(content, "blocked: {}", location.blocked);
+
+            #[cfg(feature = "2d")]
+            {
+                let _ = writeln!(content, "world position: {}", location.world_position);
+                if let Some(cell) = location.snapped_cell {
+                    let _ = writeln!(content, "snapped cell: {cell}");
+                }
+            }
+
+            #[cfg(feature = "3d")]
+            {
+                let _ = writeln!(content, "ray origin: {}", location.ray.origin);
+                let _ = writeln!(content, "ray direction: {}", *location.ray.direction);
+                if let Some(position) = location.projected_position {
+                    let _ = writeln!(content, "projected position: {position}");
+                }
+            }
+        }
+    }
+
+    for mut text in &mut text_q {
+        text.0.clone_from(&content);
+    }
+}
+
+/// Draws the location of the cursor with gizmos.
+fn draw_cursor_gizmos(
+    cursor: Res<CursorLocation>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    mut gizmos: Gizmos,
+) {
+    let Some(location) = cursor.get() else {
+        return;
+    };
+
+    let _ = (location, &camera_q, &mut gizmos); // Note: disable the `unused_variables` warning in no-default-feature.
+
+    #[cfg(feature = "2d")]
+    {
+        gizmos.circle_2d(location.world_position, 4.0, palettes::css::RED);
+
+        if let Ok((camera, cam_t)) = camera_q.get(location.camera) {
+            if let Some(viewport) = camera.logical_viewport_rect() {
+                let corners = [
+                    viewport.min,
+                    Vec2::new(viewport.max.x, viewport.min.y),
+                    viewport.max,
+                    Vec2::new(viewport.min.x, viewport.max.y),
+                    viewport.min,
+                ]
+                .map(|corner| corner - viewport.min)
+                .map(|corner| camera.viewport_to_world_2d(cam_t, corner).ok());
+
+                if corners.iter().all(Option::is_some) {
+                    gizmos.linestrip_2d(corners.into_iter().flatten(), palettes::css::YELLOW);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "3d")]
+    {
+        gizmos.ray(
+            location.ray.origin,
+            *location.ray.direction * 1000.0,
+            palettes::css::RED,
+        );
+
+        if let Some(position) = location.projected_position {
+            gizmos.sphere(
+                Isometry3d::from_translation(position),
+                0.1,
+                palettes::css::RED,
+            );
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Draws the points of the [`CursorMeasurement`] with gizmos.
+#[cfg(any(feature = "2d", feature = "3d"))]
+fn draw_measurement_gizmos(measurement: Res<CursorMeasurement>, mut gizmos: Gizmos) {
+    gizmos.linestrip(measurement.points().iter().copied(), palettes::css::LIME);
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,104 @@
+//! Pan of 2D cameras with the cursor.
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+
+use crate::CursorLocations;
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that pans a 2D camera while a mouse button is held, keeping the
+/// world point grabbed by the cursor under it.
+///
+/// The pan starts when the [`button`](Self::button) is pressed while the camera is
+/// under the cursor, according to the [`CursorLocations`] of the default
+/// [`TrackCursorPlugin`]. This is a lightweight alternative to a dedicated pan camera
+/// crate for the simple cases.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Camera2d, CursorPanCamera::default(), ZoomToCursor::default()));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component, Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorPanCamera {
+    /// The mouse button that pans the camera.
+    pub button: MouseButton,
+}
+
+impl Default for CursorPanCamera {
+    /// Pans the camera with the middle button.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Middle,
+        }
+    }
+}
+
+impl CursorPanCamera {
+    /// Creates a new [`CursorPanCamera`] that pans the camera while `button` is held.
+    #[inline]
+    pub fn new(button: MouseButton) -> Self {
+        Self { button }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Pans the [`CursorPanCamera`] cameras.
+pub(crate) fn pan_cursor_cameras(
+    buttons: Option<Res<ButtonInput<MouseButton>>>,
+    locations: Option<Res<CursorLocations>>,
+    mut camera_q: Query<(Entity, &CursorPanCamera, &mut Transform)>,
+    // The world positions grabbed by the cursor, per camera.
+    mut anchors: Local<EntityHashMap<Vec2>>,
+) {
+    let Some((locations, buttons)) = locations.zip(buttons) else {
+        anchors.clear();
+        return;
+    };
+
+    anchors.retain(|&camera_ref, _| {
+        camera_q
+            .get(camera_ref)
+            .is_ok_and(|(_, pan, _)| buttons.pressed(pan.button))
+    });
+
+    for (camera_ref, pan, mut transform) in &mut camera_q {
+        let Some(location) = locations.get(camera_ref) else {
+            continue;
+        };
+
+        if buttons.just_pressed(pan.button) {
+            anchors.insert(camera_ref, location.world_position);
+            continue;
+        }
+
+        let Some(&anchor) = anchors.get(&camera_ref) else {
+            continue;
+        };
+
+        // Move the camera so the grabbed point comes back under the cursor.
+        let offset = anchor - location.world_position;
+        if offset != Vec2::ZERO {
+            transform.translation += offset.extend(0.0);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,88 @@
+//! Offset of entities driven by the cursor position.
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+
+use crate::CursorLocation;
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that offsets the [`Transform`] of an entity proportionally to the
+/// position of the cursor on its window.
+///
+/// The cursor position is normalized to `[-1, 1]` from the center of the window,
+/// with X pointing to the right and Y pointing up, then multiplied by the
+/// [`strength`](Self::strength) to get the offset of the entity.
+/// The entity goes back to its rest position when the cursor leaves the windows.
+///
+/// The cursor position is read from the [`CursorLocation`] of the default
+/// [`TrackCursorPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::default(),
+///         CursorParallax {
+///             strength: Vec2::new(20.0, 10.0),
+///         },
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+#[require(Transform)]
+pub struct CursorParallax {
+    /// The offset of the entity when the cursor is at the top-right corner of the window.
+    pub strength: Vec2,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Offsets the [`CursorParallax`] entities.
+pub(crate) fn update_cursor_parallax(
+    cursor: Option<Res<CursorLocation>>,
+    window_q: Query<&Window>,
+    mut parallax_q: Query<(Entity, &CursorParallax, &mut Transform)>,
+    mut applied: Local<EntityHashMap<Vec2>>,
+) {
+    let position = cursor
+        .as_ref()
+        .and_then(|cursor| cursor.get())
+        .and_then(|location| {
+            let window = window_q.get(location.window).ok()?;
+            let size = window.size();
+            if size.x <= 0.0 || size.y <= 0.0 {
+                return None;
+            }
+            let position = location.position / size * 2.0 - 1.0;
+            // The window Y axis points down.
+            Some(Vec2::new(position.x, -position.y).clamp(Vec2::NEG_ONE, Vec2::ONE))
+        })
+        .unwrap_or(Vec2::ZERO);
+
+    applied.retain(|entity, _| parallax_q.contains(*entity));
+
+    for (entity, parallax, mut transform) in &mut parallax_q {
+        let offset = position * parallax.strength;
+        let previous = applied.insert(entity, offset).unwrap_or(Vec2::ZERO);
+
+        if offset != previous {
+            transform.translation += (offset - previous).extend(0.0);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,79 @@
+//! Freezing of the world location of the cursor while the virtual time is paused.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::Location;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that freezes the world location of the cursor while the [`Time<Virtual>`]
+/// is paused.
+///
+/// When this resource exists and the virtual time is paused, the
+/// [`position`](Location::position) of the cursor in the window is still updated,
+/// but its world location (the `world_position` with the feature `2d`, the `ray`
+/// with the feature `3d`) is kept as it was for the same camera. This way the
+/// hovered point doesn't move when a camera is animated behind a pause menu.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(PauseAwareTracking);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`Time<Virtual>`]: https://docs.rs/bevy/0.15.0/bevy/time/struct.Virtual.html
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PauseAwareTracking;
+
+/* -------------------------------------------------------------------------- */
+
+/// Whether the world location of the cursor is frozen.
+#[derive(SystemParam)]
+pub(crate) struct PauseState<'w> {
+    tracking: Option<Res<'w, PauseAwareTracking>>,
+    time: Option<Res<'w, Time<Virtual>>>,
+}
+
+impl PauseState<'_> {
+    /// Returns `true` if the world location of the cursor must not be recomputed.
+    pub(crate) fn is_frozen(&self) -> bool {
+        self.tracking.is_some() && self.time.as_ref().is_some_and(|time| time.is_paused())
+    }
+}
+
+impl Location {
+    /// Restores the world location of `previous`, if it has been computed with the
+    /// same camera.
+    pub(crate) fn freeze_world(&mut self, previous: Option<&Location>) {
+        let Some(previous) = previous.filter(|previous| previous.camera == self.camera) else {
+            return;
+        };
+
+        let _ = previous; // Note: disable the `unused_variables` warning in no-default-feature.
+
+        #[cfg(feature = "2d")]
+        {
+            self.world_position = previous.world_position;
+        }
+
+        #[cfg(feature = "3d")]
+        {
+            self.ray = previous.ray;
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
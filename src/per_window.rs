@@ -0,0 +1,184 @@
+//! Locations of the cursor on every window that contains it.
+
+use std::marker::PhantomData;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::ecs::query::Has;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::position::WindowPositions;
+use crate::stamp::LocationClock;
+use crate::{
+    resolve_location, CameraFilter, CameraQuery, CameraSelection, CursorLocation,
+    FocusedWindowOnly, Location,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the [`Location`] of the cursor on every window that
+/// contains it.
+///
+/// Unlike the [`CursorLocation`], which only holds the location on the first window
+/// that contains the cursor, this resource resolves the cursor for each window the
+/// platform reports a position on (for instance with multiple pointers or synthetic
+/// input). This is useful for multi-window dashboards.
+///
+/// The location on the window of the [`CursorLocation`] is the same as the one it
+/// provides.
+///
+/// This resource is only updated if it exists.
+/// See [`TrackCursorPlugin::with_per_window_locations`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_cursors(cursors: Res<CursorLocationsPerWindow>) {
+///     for (window, location) in cursors.iter() {
+///         info!("Window {window:?}: {:?}", location.position);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_cursors);
+/// ```
+///
+/// [`TrackCursorPlugin::with_per_window_locations`]: crate::TrackCursorPlugin::with_per_window_locations
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorLocationsPerWindow<F: CameraFilter = ()>(
+    EntityHashMap<Location>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
+
+impl<F: CameraFilter> Default for CursorLocationsPerWindow<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(EntityHashMap::default(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorLocationsPerWindow<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorLocationsPerWindow<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorLocationsPerWindow<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorLocationsPerWindow")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<F: CameraFilter> CursorLocationsPerWindow<F> {
+    /// The [`Location`] of the cursor on the given window.
+    ///
+    /// Returns [`None`] if the window doesn't contain the cursor.
+    #[inline]
+    pub fn get(&self, window: Entity) -> Option<&Location> {
+        self.0.get(&window)
+    }
+
+    /// Iterates over the windows that contain the cursor and their [`Location`].
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Entity, &Location)> {
+        self.0.iter().map(|(&window, location)| (window, location))
+    }
+
+    /// Returns `true` if no window contains the cursor.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates mutably over the [`Location`]s of the cursor.
+    #[cfg(any(feature = "2d", feature = "3d", feature = "ui"))]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Location> {
+        self.0.values_mut()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Resolves the cursor on every window and updates the [`CursorLocationsPerWindow`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_cursor_locations_per_window<F: CameraFilter>(
+    per_window: Option<ResMut<CursorLocationsPerWindow<F>>>,
+    window_q: Query<(Entity, &Window, Has<PrimaryWindow>)>,
+    camera_q: CameraQuery<F::Filter>,
+    positions: WindowPositions,
+    focused_only: Option<Res<FocusedWindowOnly>>,
+    selection: CameraSelection<F>,
+    cursor: Res<CursorLocation<F>>,
+    clock: LocationClock,
+) {
+    let Some(mut per_window) = per_window else {
+        return;
+    };
+
+    let now = clock.now();
+    let cursor = cursor.get().filter(|location| !location.is_stale);
+
+    let mut locations = EntityHashMap::default();
+
+    for (win_ref, window, is_primary) in &window_q {
+        if focused_only.is_some() && !window.focused {
+            continue;
+        }
+
+        // The location on the window of the `CursorLocation` is already resolved.
+        if let Some(location) = cursor.filter(|location| location.window == win_ref) {
+            locations.insert(win_ref, location.clone());
+            continue;
+        }
+
+        let Some(position) = positions.get(win_ref, window) else {
+            continue;
+        };
+
+        let previous = per_window.get(win_ref);
+
+        let Some(mut location) = resolve_location(
+            win_ref,
+            window,
+            is_primary,
+            position.position,
+            &camera_q,
+            &selection,
+            previous.map(|location| location.camera),
+        ) else {
+            continue;
+        };
+
+        location.raw_position = position.raw_position;
+        location.kind = position.kind;
+        location.grabbed = position.grabbed;
+        location.stamp(previous, now);
+        locations.insert(win_ref, location);
+    }
+
+    if per_window.0 != locations {
+        per_window.0 = locations;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,124 @@
+//! Alignment of the cursor to the pixel grid.
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that aligns the position of the cursor to the center of the physical
+/// pixel under it.
+///
+/// In pixel-art games, a sub-pixel position of the cursor results in sub-pixel world
+/// positions, so the sprites that follow the cursor shimmer as it moves inside a
+/// pixel. When this resource exists, the [`position`] of the locations is the center
+/// of the physical pixel under the cursor, and their world position is computed from
+/// it. The position reported by the window is kept in the [`raw_position`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(PixelAlignment);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`position`]: crate::Location::position
+/// [`raw_position`]: crate::Location::raw_position
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PixelAlignment;
+
+impl PixelAlignment {
+    /// Returns the center of the physical pixel that contains the given logical
+    /// position, in logical pixels.
+    #[inline]
+    pub fn align(position: Vec2, scale_factor: f32) -> Vec2 {
+        ((position * scale_factor).floor() + 0.5) / scale_factor
+    }
+}
+
+/// A component for the 2D cameras with a pixel-perfect setup, that quantizes the
+/// [`world_position`] of the cursor to the texel grid of the camera.
+///
+/// By default, the texels are the physical pixels of the render target of the
+/// camera, like a low resolution image upscaled to the window, and the world
+/// position is the center of the texel under the cursor. With a
+/// [`texel_size`](Self::texel_size), the world position is the center of the cell of
+/// this size under the cursor, for the cameras that zoom the pixel art with their
+/// projection instead.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     // The sprites are drawn at 1 texel per world unit and zoomed 4 times.
+///     commands.spawn((
+///         Camera2d,
+///         OrthographicProjection {
+///             scale: 0.25,
+///             ..OrthographicProjection::default_2d()
+///         },
+///         CursorPixelSnap::new(1.0),
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`world_position`]: crate::Location::world_position
+#[cfg(feature = "2d")]
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorPixelSnap {
+    /// The size of a texel in world units, or [`None`] for the physical pixels of the
+    /// render target of the camera.
+    pub texel_size: Option<f32>,
+}
+
+#[cfg(feature = "2d")]
+impl CursorPixelSnap {
+    /// Creates a new [`CursorPixelSnap`] with texels of the given size, in world units.
+    #[inline]
+    pub fn new(texel_size: f32) -> Self {
+        Self {
+            texel_size: Some(texel_size),
+        }
+    }
+
+    /// Aligns a position on the render target of the camera, in logical pixels, when
+    /// the texels are its physical pixels.
+    #[inline]
+    pub(crate) fn align_target(&self, position: Vec2, scale_factor: f32) -> Vec2 {
+        match self.texel_size {
+            None => PixelAlignment::align(position, scale_factor),
+            Some(_) => position,
+        }
+    }
+
+    /// Snaps a world position to the center of its texel, when the texels have a size
+    /// in world units.
+    #[inline]
+    pub(crate) fn snap_world(&self, world_position: Vec2) -> Vec2 {
+        match self.texel_size {
+            Some(size) if size > 0.0 => ((world_position / size).floor() + 0.5) * size,
+            _ => world_position,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
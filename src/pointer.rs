@@ -0,0 +1,195 @@
+//! The kind of pointer that drives the cursor.
+
+use bevy::input::gamepad::GamepadEvent;
+use bevy::input::mouse::{MouseButtonInput, MouseWheel};
+use bevy::input::touch::{ForceTouch, TouchInput};
+use bevy::prelude::*;
+
+use crate::input::OptionalEventReader;
+
+/* -------------------------------------------------------------------------- */
+
+/// The kind of pointer that drives a [`Location`].
+///
+/// The systems can adapt their behavior to the pointer, like using larger hit areas
+/// for a touch, without having to track a separate resource for each kind of input.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn hit_radius(cursor: Res<CursorLocation>) -> f32 {
+///     match cursor.get().map(|location| location.kind) {
+///         Some(PointerKind::Touch(_)) => 24.0,
+///         _ => 8.0,
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(hit_radius);
+/// ```
+///
+/// [`Location`]: crate::Location
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[reflect(Debug, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum PointerKind {
+    /// The mouse cursor of the window.
+    #[default]
+    Mouse,
+    /// A finger on a touch screen, identified by the id of the touch.
+    Touch(u64),
+    /// A pen on a touch screen, like an Apple Pencil.
+    ///
+    /// The pens are told apart from the fingers by the altitude of the stylus that
+    /// their touches report. The graphics tablets that drive the mouse cursor are
+    /// reported as [`Mouse`](Self::Mouse).
+    Pen,
+    /// A cursor that isn't driven by a physical pointer, like a gamepad or a remote
+    /// cursor, identified by an id chosen by its source.
+    Virtual(u64),
+}
+
+impl PointerKind {
+    /// Returns `true` if the pointer is a mouse.
+    #[inline]
+    pub fn is_mouse(&self) -> bool {
+        matches!(self, Self::Mouse)
+    }
+
+    /// Returns `true` if the pointer is a touch.
+    #[inline]
+    pub fn is_touch(&self) -> bool {
+        matches!(self, Self::Touch(_))
+    }
+
+    /// Returns `true` if the pointer is a pen.
+    #[inline]
+    pub fn is_pen(&self) -> bool {
+        matches!(self, Self::Pen)
+    }
+
+    /// Returns `true` if the pointer is virtual.
+    #[inline]
+    pub fn is_virtual(&self) -> bool {
+        matches!(self, Self::Virtual(_))
+    }
+
+    /// The kind of pointer that made a touch.
+    pub(crate) fn of_touch(touch: &TouchInput) -> Self {
+        // Only the styluses report their altitude.
+        match touch.force {
+            Some(ForceTouch::Calibrated {
+                altitude_angle: Some(_),
+                ..
+            }) => Self::Pen,
+            _ => Self::Touch(touch.id),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the kind of input device the user interacted with last.
+///
+/// The UI can switch its prompts or the visual of its cursor when the player changes
+/// of input device, like hiding the hover effects while the game is played with a
+/// touch screen.
+///
+/// It's [`None`] until the first input. A touch made by a stylus, as detected for the
+/// [`PointerKind::Pen`] kind, is reported as [`PointerSource::Pen`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn update_prompts(source: Res<LastPointerSource>) {
+///     if source.is_changed() && source.get() == Some(PointerSource::Gamepad) {
+///         info!("Show the gamepad prompts");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(update_prompts);
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct LastPointerSource(Option<PointerSource>);
+
+impl LastPointerSource {
+    /// The kind of input device the user interacted with last.
+    #[inline]
+    pub fn get(&self) -> Option<PointerSource> {
+        self.0
+    }
+}
+
+/// A kind of input device, as reported by the [`LastPointerSource`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum PointerSource {
+    /// A mouse.
+    Mouse,
+    /// A touch screen.
+    Touch,
+    /// A pen on a graphics tablet.
+    Pen,
+    /// A gamepad, which usually drives a virtual cursor.
+    Gamepad,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the [`LastPointerSource`] with the input events of this frame.
+///
+/// When several devices are used in the same frame, the touch screen wins over the
+/// mouse, as some platforms emulate the mouse events from the touches.
+pub(crate) fn update_last_pointer_source(
+    mut cursor_moved: OptionalEventReader<CursorMoved>,
+    mut mouse_buttons: OptionalEventReader<MouseButtonInput>,
+    mut mouse_wheel: OptionalEventReader<MouseWheel>,
+    mut touches: OptionalEventReader<TouchInput>,
+    mut gamepads: OptionalEventReader<GamepadEvent>,
+    mut last: ResMut<LastPointerSource>,
+) {
+    // Note: `|` reads all the readers, so they don't report these events on the next frame.
+    let mouse = cursor_moved.any() | mouse_buttons.any() | mouse_wheel.any();
+    let touch = touches.read().map(PointerKind::of_touch).last();
+    let gamepad = gamepads
+        .read()
+        .filter(|event| !matches!(event, GamepadEvent::Connection(_)))
+        .count()
+        != 0;
+
+    let source = if let Some(kind) = touch {
+        if kind.is_pen() {
+            PointerSource::Pen
+        } else {
+            PointerSource::Touch
+        }
+    } else if mouse {
+        PointerSource::Mouse
+    } else if gamepad {
+        PointerSource::Gamepad
+    } else {
+        return;
+    };
+
+    last.set_if_neq(LastPointerSource(Some(source)));
+}
+
+/* -------------------------------------------------------------------------- */
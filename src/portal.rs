@@ -0,0 +1,236 @@
+//! Locations seen through the render of another camera, like a minimap.
+
+#[cfg(feature = "ui")]
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+#[cfg(feature = "ui")]
+use bevy::render::view::RenderLayers;
+#[cfg(feature = "ui")]
+use bevy::ui::{DefaultUiCamera, UiStack};
+#[cfg(feature = "ui")]
+use bevy::window::PrimaryWindow;
+
+#[cfg(feature = "ui")]
+use crate::target_window;
+use crate::{camera_location, Location};
+
+/* -------------------------------------------------------------------------- */
+
+impl Location {
+    /// Returns the location seen through a portal: the render of another camera,
+    /// like a minimap, displayed on the given area of the window of the cursor.
+    ///
+    /// `rect` is the area of the window, in logical pixels, where the render target of
+    /// `camera` is displayed. The position of the cursor in this area is mapped to the
+    /// render target of the camera, and the world position and the ray are computed
+    /// through it. The [`position`](Self::position) stays the position on the window.
+    ///
+    /// Returns [`None`] if the cursor is outside the area or the viewport of the
+    /// camera, or if the world conversions fail.
+    ///
+    /// The [`render_layers`](Self::render_layers) of the location are the default
+    /// ones, as the components of the camera are unknown.
+    ///
+    /// When the portal is a `bevy_ui` node, the [`CursorPortals`] find the portal
+    /// under the cursor. A camera that renders directly into a viewport of the window
+    /// doesn't need a portal, as the cursor is already resolved through it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// #[derive(Component)]
+    /// struct MinimapCamera;
+    ///
+    /// // The minimap is displayed at the top-right corner of a 1280x720 window.
+    /// fn minimap_click(
+    ///     cursor: Res<CursorLocation>,
+    ///     buttons: Res<ButtonInput<MouseButton>>,
+    ///     minimap: Single<(Entity, &GlobalTransform, &Camera), With<MinimapCamera>>,
+    /// ) {
+    ///     let (camera_ref, cam_t, camera) = *minimap;
+    ///     let rect = Rect::new(1080.0, 0.0, 1280.0, 200.0);
+    ///
+    ///     if buttons.just_pressed(MouseButton::Left) {
+    ///         if let Some(location) = cursor
+    ///             .get()
+    ///             .and_then(|location| location.through_portal(rect, camera_ref, cam_t, camera))
+    ///         {
+    ///             /* move the main camera toward `location` */
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # let _ = IntoSystem::into_system(minimap_click);
+    /// ```
+    ///
+    /// [`CursorPortals`]: crate::CursorPortals
+    pub fn through_portal(
+        &self,
+        rect: Rect,
+        camera_ref: Entity,
+        cam_t: &GlobalTransform,
+        camera: &Camera,
+    ) -> Option<Location> {
+        if !rect.contains(self.position) || rect.size().cmple(Vec2::ZERO).any() {
+            return None;
+        }
+
+        let target_size = camera.logical_target_size().unwrap_or(rect.size());
+        let target_position = (self.position - rect.min) / rect.size() * target_size;
+
+        if let Some(viewport) = camera.logical_viewport_rect() {
+            if !viewport.contains(target_position) {
+                return None;
+            }
+        }
+
+        let location =
+            camera_location(self.window, camera_ref, cam_t, camera, target_position).ok()?;
+
+        Some(location.carry_pointer(self))
+    }
+
+    /// Returns this location, resolved through another camera, with the state of the
+    /// pointer of `outer`: its position on the window, its kind, its flags and its
+    /// stamps.
+    pub(crate) fn carry_pointer(self, outer: &Location) -> Location {
+        Location {
+            position: outer.position,
+            raw_position: outer.raw_position,
+            kind: outer.kind,
+            grabbed: outer.grabbed,
+            outside_window: outer.outside_window,
+            scale_factor: outer.scale_factor,
+            stabilized_position: outer.stabilized_position,
+            #[cfg(feature = "ui")]
+            blocked: outer.blocked,
+            is_stale: outer.is_stale,
+            timestamp: outer.timestamp,
+            frame: outer.frame,
+            ..self
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A component for the `bevy_ui` nodes that display the render of a camera, like a
+/// minimap, so the cursor can be seen through them with the [`CursorPortals`].
+///
+/// The render target of the camera is stretched over the whole node.
+#[cfg(feature = "ui")]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct CursorPortal {
+    /// The entity id of the camera whose render is displayed by the node.
+    pub camera: Entity,
+}
+
+#[cfg(feature = "ui")]
+impl CursorPortal {
+    /// Creates a new [`CursorPortal`] for the given camera.
+    #[inline]
+    pub fn new(camera: Entity) -> Self {
+        Self { camera }
+    }
+}
+
+/// A [`SystemParam`] that sees the cursor through the [`CursorPortal`] nodes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn minimap_click(
+///     cursor: Res<CursorLocation>,
+///     buttons: Res<ButtonInput<MouseButton>>,
+///     portals: CursorPortals,
+/// ) {
+///     if buttons.just_pressed(MouseButton::Left) {
+///         if let Some(location) = cursor.get().and_then(|location| portals.through(location)) {
+///             /* move the main camera toward `location` */
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(minimap_click);
+/// ```
+///
+/// [`SystemParam`]: https://docs.rs/bevy/0.15.0/bevy/ecs/system/trait.SystemParam.html
+#[cfg(feature = "ui")]
+#[derive(SystemParam)]
+pub struct CursorPortals<'w, 's> {
+    ui_stack: Option<Res<'w, UiStack>>,
+    portal_q: Query<
+        'w,
+        's,
+        (
+            &'static CursorPortal,
+            &'static ComputedNode,
+            &'static GlobalTransform,
+            &'static ViewVisibility,
+            Option<&'static TargetCamera>,
+        ),
+    >,
+    camera_q: Query<'w, 's, (&'static GlobalTransform, &'static Camera)>,
+    layers_q: Query<'w, 's, &'static RenderLayers>,
+    primary_window_q: Query<'w, 's, Entity, With<PrimaryWindow>>,
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+}
+
+#[cfg(feature = "ui")]
+impl CursorPortals<'_, '_> {
+    /// Returns the location seen through the topmost [`CursorPortal`] under the given
+    /// location, or [`None`] if there is no portal under it.
+    ///
+    /// See [`Location::through_portal`].
+    pub fn through(&self, location: &Location) -> Option<Location> {
+        let primary_window = self.primary_window_q.get_single().ok();
+
+        // Note: the `UiStack` is only provided by the `UiPlugin`.
+        let ui_stack = self.ui_stack.as_ref()?;
+
+        ui_stack.uinodes.iter().rev().find_map(|&entity| {
+            let (portal, node, node_t, visibility, target_camera) =
+                self.portal_q.get(entity).ok()?;
+            if !visibility.get() {
+                return None;
+            }
+
+            // The nodes are positioned relative to the viewport of their camera.
+            let ui_camera_ref = target_camera
+                .map(TargetCamera::entity)
+                .or(self.default_ui_camera.get())?;
+            let (_, ui_camera) = self.camera_q.get(ui_camera_ref).ok()?;
+            if target_window(&ui_camera.target, primary_window) != Some(location.window) {
+                return None;
+            }
+            let viewport_min = ui_camera
+                .physical_viewport_rect()
+                .map_or(Vec2::ZERO, |viewport| viewport.min.as_vec2());
+
+            let center = node_t.translation().truncate() + viewport_min;
+            let rect = Rect::from_center_size(center, node.size());
+            let rect = Rect {
+                min: rect.min / location.scale_factor,
+                max: rect.max / location.scale_factor,
+            };
+
+            let (cam_t, camera) = self.camera_q.get(portal.camera).ok()?;
+            let location = location.through_portal(rect, portal.camera, cam_t, camera)?;
+            Some(Location {
+                render_layers: self
+                    .layers_q
+                    .get(portal.camera)
+                    .cloned()
+                    .unwrap_or_default(),
+                ..location
+            })
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,93 @@
+//! The position of the cursor on the windows.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::click_through::DesktopCursorPosition;
+use crate::grab::GrabbedCursors;
+use crate::touch::TouchCursor;
+use crate::{FocusedWindowOnly, PixelAlignment, PointerKind};
+
+/* -------------------------------------------------------------------------- */
+
+/// The position of the cursor on a window, before it's resolved into a
+/// [`Location`](crate::Location).
+pub(crate) struct WindowPosition {
+    /// The position in logical pixels, aligned by the [`PixelAlignment`].
+    pub(crate) position: Vec2,
+    /// The position in logical pixels, as reported by its source.
+    pub(crate) raw_position: Vec2,
+    /// Whether the cursor is locked.
+    pub(crate) grabbed: bool,
+    /// The kind of pointer that drives the cursor.
+    pub(crate) kind: PointerKind,
+}
+
+/// A [`SystemParam`] that provides the position of the cursor on the windows, from
+/// the locked cursors, the touches, the windows themselves or the
+/// [`DesktopCursorPosition`].
+#[derive(SystemParam)]
+pub(crate) struct WindowPositions<'w> {
+    grabbed: Res<'w, GrabbedCursors>,
+    touch: Res<'w, TouchCursor>,
+    alignment: Option<Res<'w, PixelAlignment>>,
+    focused_only: Option<Res<'w, FocusedWindowOnly>>,
+    desktop: Option<Res<'w, DesktopCursorPosition>>,
+}
+
+impl WindowPositions<'_> {
+    /// Returns the position of the cursor on the given window, if any.
+    pub(crate) fn get(&self, win_ref: Entity, window: &Window) -> Option<WindowPosition> {
+        let (raw_position, grabbed, kind) = if let Some(position) = self.grabbed.get(win_ref) {
+            (
+                position,
+                self.grabbed.is_locked(win_ref),
+                PointerKind::Mouse,
+            )
+        } else if let Some((position, kind)) = self.touch.get(win_ref) {
+            (position, false, kind)
+        } else {
+            let position = window.cursor_position().or_else(|| {
+                let desktop = self.desktop.as_ref()?;
+                desktop.on_window(window)
+            })?;
+            (position, false, PointerKind::Mouse)
+        };
+
+        Some(WindowPosition {
+            position: self.align(raw_position, window),
+            raw_position,
+            grabbed,
+            kind,
+        })
+    }
+
+    /// Returns the first window that contains the cursor and the position of the
+    /// cursor on it, skipping the unfocused windows with the [`FocusedWindowOnly`].
+    pub(crate) fn find<'a>(
+        &self,
+        windows: impl IntoIterator<Item = (Entity, &'a Window)>,
+    ) -> Option<(Entity, &'a Window, WindowPosition)> {
+        windows
+            .into_iter()
+            .filter(|(_, window)| self.focused_only.is_none() || window.focused)
+            .find_map(|(win_ref, window)| Some((win_ref, window, self.get(win_ref, window)?)))
+    }
+
+    /// Aligns a position reported by the window with the [`PixelAlignment`].
+    #[inline]
+    pub(crate) fn align(&self, position: Vec2, window: &Window) -> Vec2 {
+        match self.alignment {
+            Some(_) => PixelAlignment::align(position, window.scale_factor()),
+            None => position,
+        }
+    }
+
+    /// Returns `true` if the cursor is locked or was locked on the given window.
+    #[inline]
+    pub(crate) fn is_grabbed(&self, win_ref: Entity) -> bool {
+        self.grabbed.get(win_ref).is_some()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
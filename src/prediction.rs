@@ -0,0 +1,211 @@
+//! Extrapolation of the cursor location.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+#[cfg(feature = "3d")]
+use bevy::render::camera::RenderTarget;
+
+use crate::{CameraFilter, CursorLocation, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the prediction of the cursor location.
+///
+/// When this resource exists, the [`PredictedCursorLocation`] extrapolates the
+/// [`CursorLocation`] [`lead`](Self::lead) ahead, from the velocity of the cursor
+/// during the last frame. This compensates the render latency for fast drag
+/// interactions.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorPrediction::new(Duration::from_millis(16)));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorPrediction {
+    /// How far ahead the location is extrapolated.
+    pub lead: Duration,
+}
+
+impl CursorPrediction {
+    /// Creates a new [`CursorPrediction`] that extrapolates the location `lead` ahead.
+    #[inline]
+    pub fn new(lead: Duration) -> Self {
+        Self { lead }
+    }
+}
+
+/// A resource that provides the [`CursorLocation`] extrapolated by the
+/// [`CursorPrediction`].
+///
+/// The [`position`](Location::position), the world position (with the feature `2d`),
+/// and the ray and the projected position (with the feature `3d`) are extrapolated.
+/// The ray of a camera that renders into a window is recomputed from the extrapolated
+/// position; the ray of a camera that renders into an image is extrapolated from its
+/// origin and its direction. The other fields are the ones of the
+/// [`CursorLocation`], which still provides the raw values.
+///
+/// The location isn't extrapolated on the frame the window or the camera of the
+/// cursor changes. If the [`CursorPrediction`] resource doesn't exist, this resource
+/// is the same as the [`CursorLocation`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn move_dragged_item(cursor: Res<PredictedCursorLocation>) {
+///     if let Some(position) = cursor.position() {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(move_dragged_item);
+/// ```
+#[derive(Resource, Reflect, Deref)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PredictedCursorLocation<F: CameraFilter = ()>(CursorLocation<F>);
+
+impl<F: CameraFilter> Default for PredictedCursorLocation<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(CursorLocation::default())
+    }
+}
+
+impl<F: CameraFilter> Clone for PredictedCursorLocation<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: CameraFilter> PartialEq for PredictedCursorLocation<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for PredictedCursorLocation<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PredictedCursorLocation")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Location {
+    /// Extrapolates the location from its `previous` value, `ratio` times the
+    /// motion between them.
+    fn extrapolate(&self, previous: &Location, ratio: f32) -> Location {
+        let mut predicted = self.clone();
+
+        predicted.position += (self.position - previous.position) * ratio;
+
+        #[cfg(feature = "2d")]
+        {
+            predicted.world_position += (self.world_position - previous.world_position) * ratio;
+        }
+
+        #[cfg(feature = "3d")]
+        {
+            predicted.ray.origin += (self.ray.origin - previous.ray.origin) * ratio;
+            predicted.ray.direction = Dir3::new(
+                *self.ray.direction + (*self.ray.direction - *previous.ray.direction) * ratio,
+            )
+            .unwrap_or(self.ray.direction);
+
+            if let (Some(current), Some(previous)) =
+                (self.projected_position, previous.projected_position)
+            {
+                predicted.projected_position = Some(current + (current - previous) * ratio);
+            }
+        }
+
+        predicted
+    }
+}
+
+/// Updates the [`PredictedCursorLocation`].
+pub(crate) fn predict_cursor_location<F: CameraFilter>(
+    prediction: Option<Res<CursorPrediction>>,
+    cursor: Res<CursorLocation<F>>,
+    predicted: ResMut<PredictedCursorLocation<F>>,
+    mut previous: Local<Option<Location>>,
+    time: Res<Time<Real>>,
+    #[cfg(feature = "3d")] camera_q: Query<(&Camera, &GlobalTransform)>,
+) {
+    let mut predicted = predicted.map_unchanged(|predicted| &mut predicted.0 .0);
+
+    let (Some(prediction), Some(location)) = (prediction, cursor.get()) else {
+        *previous = cursor.get().cloned();
+        predicted.set_if_neq(cursor.0.clone());
+        return;
+    };
+
+    let dt = time.delta_secs();
+
+    let predicted_location = match previous.as_ref() {
+        Some(previous)
+            if previous.window == location.window
+                && previous.camera == location.camera
+                && dt > 0.0 =>
+        {
+            let predicted = location.extrapolate(previous, prediction.lead.as_secs_f32() / dt);
+
+            #[cfg(feature = "3d")]
+            let predicted = with_window_ray(predicted, &camera_q);
+
+            predicted
+        }
+        _ => location.clone(),
+    };
+
+    *previous = Some(location.clone());
+    predicted.set_if_neq(Some(predicted_location));
+}
+
+/// Recomputes the ray of the location from its position, when its camera renders
+/// into a window.
+#[cfg(feature = "3d")]
+fn with_window_ray(
+    mut location: Location,
+    camera_q: &Query<(&Camera, &GlobalTransform)>,
+) -> Location {
+    // Note: the position is on the render target of the camera only when it renders
+    // into a window.
+    if let Ok((camera, cam_t)) = camera_q.get(location.camera) {
+        if let RenderTarget::Window(_) = camera.target {
+            if let Ok(ray) = camera.viewport_to_world(cam_t, location.position) {
+                location.ray = ray;
+            }
+        }
+    }
+
+    location
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,52 @@
+//! Priority of the cameras for the cursor resolution.
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that overrides the [`Camera::order`] of a camera when resolving the cursor.
+///
+/// Cameras are considered from the highest priority to the lowest. A camera without
+/// this component has the priority of its [`Camera::order`]. This is useful when the
+/// render order doesn't match the interaction order, for instance a skybox camera
+/// rendered last that must never receive the cursor.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         Camera {
+///             order: 10,
+///             ..default()
+///         },
+///         // Rendered last, but considered after every other camera.
+///         CursorPriority(-1),
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`Camera::order`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/struct.Camera.html#structfield.order
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[reflect(Component, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorPriority(pub i32);
+
+impl CursorPriority {
+    /// The priority of a camera, given its optional [`CursorPriority`].
+    #[inline]
+    pub(crate) fn of(priority: Option<&CursorPriority>, camera: &Camera) -> isize {
+        priority.map_or(camera.order, |priority| priority.0 as isize)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,193 @@
+//! Projection of the cursor ray to a 3D point.
+
+use bevy::prelude::*;
+use bevy::render::primitives::Frustum;
+
+use crate::derived::LocationsMut;
+use crate::ground::{CursorNavMeshes, CursorSurfaceSamplers};
+use crate::CameraFilter;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that defines where the cursor [ray] is projected to compute the
+/// [`Location::projected_position`].
+///
+/// Without this resource, no position is projected. This is useful for 2.5D games
+/// that need a 3D world position without intersecting the ray manually.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorProjection::CameraDepth(10.0));
+/// }
+///
+/// fn print_projected_position(cursor: Res<CursorLocation>) {
+///     if let Some(position) = cursor.projected_position() {
+///         info!("Cursor at {position:?}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(print_projected_position);
+/// ```
+///
+/// [ray]: crate::Location::ray
+/// [`Location::projected_position`]: crate::Location::projected_position
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorProjection {
+    /// The point at this distance along the ray.
+    Distance(f32),
+    /// The intersection of the ray with the plane perpendicular to the view direction
+    /// of the camera, at this depth in front of the camera.
+    CameraDepth(f32),
+}
+
+impl CursorProjection {
+    /// Projects the ray of a location through the camera with the given transform.
+    fn project(self, ray: Ray3d, cam_t: &GlobalTransform) -> Option<Vec3> {
+        match self {
+            CursorProjection::Distance(distance) => Some(ray.get_point(distance)),
+            CursorProjection::CameraDepth(depth) => {
+                let forward = cam_t.forward();
+                let plane_origin = cam_t.translation() + depth * forward;
+                let distance = ray.intersect_plane(plane_origin, InfinitePlane3d::new(forward))?;
+                Some(ray.get_point(distance))
+            }
+        }
+    }
+}
+
+/// The part of the cursor [ray] inside the view volume of the camera, between its
+/// near and its far planes.
+///
+/// The distances are along the ray, from its origin. They bound the raycasts to what
+/// the camera actually renders, instead of an arbitrary maximal distance.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_far_point(cursor: Res<CursorLocation>) {
+///     let Some(location) = cursor.get() else {
+///         return;
+///     };
+///     if let Some(segment) = location.ray_segment {
+///         let far_point = location.ray.get_point(segment.end);
+///         info!("The cursor points toward {far_point:?}");
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_far_point);
+/// ```
+///
+/// [ray]: crate::Location::ray
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RaySegment {
+    /// The distance along the ray at which it enters the view volume.
+    pub start: f32,
+    /// The distance along the ray at which it exits the view volume.
+    pub end: f32,
+}
+
+impl RaySegment {
+    /// Clips the ray to the near and the far planes of the frustum.
+    ///
+    /// Returns [`None`] if the ray doesn't cross the space between the planes.
+    pub fn from_frustum(ray: Ray3d, frustum: &Frustum) -> Option<Self> {
+        let mut start = 0.0_f32;
+        let mut end = f32::INFINITY;
+
+        // The half spaces are ordered left, right, top, bottom, near, far, and their
+        // normals point inward.
+        for half_space in &frustum.half_spaces[4..] {
+            let normal = Vec3::from(half_space.normal());
+            let signed_distance = normal.dot(ray.origin) + half_space.d();
+            let speed = normal.dot(*ray.direction);
+
+            if speed.abs() <= f32::EPSILON {
+                if signed_distance < 0.0 {
+                    return None;
+                }
+            } else if speed > 0.0 {
+                start = start.max(-signed_distance / speed);
+            } else {
+                end = end.min(-signed_distance / speed);
+            }
+        }
+
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The length of the segment.
+    #[inline]
+    pub fn length(&self) -> f32 {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the given distance along the ray is inside the segment.
+    #[inline]
+    pub fn contains(&self, distance: f32) -> bool {
+        (self.start..=self.end).contains(&distance)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the `projected_position`, the `ray_segment`, the `ground_hit` and the
+/// `nav_target` of every location.
+pub(crate) fn project_cursor_location<F: CameraFilter>(
+    projection: Option<Res<CursorProjection>>,
+    samplers: Res<CursorSurfaceSamplers>,
+    nav_meshes: Res<CursorNavMeshes>,
+    camera_q: Query<(&GlobalTransform, Option<&Frustum>)>,
+    mut locations: LocationsMut<F>,
+) {
+    let projection = projection.map(|projection| *projection);
+
+    locations.for_each_location_mut(|location| {
+        let camera = camera_q.get(location.camera).ok();
+
+        let projected = projection.and_then(|projection| {
+            let (cam_t, _) = camera?;
+            projection.project(location.ray, cam_t)
+        });
+
+        let segment = camera
+            .and_then(|(_, frustum)| frustum)
+            .and_then(|frustum| RaySegment::from_frustum(location.ray, frustum));
+
+        let ground_hit = samplers.sample(location.ray);
+
+        let nav_target = ground_hit
+            .or(projected)
+            .and_then(|point| nav_meshes.nearest_walkable(point));
+
+        let changed = location.projected_position != projected
+            || location.ray_segment != segment
+            || location.ground_hit != ground_hit
+            || location.nav_target != nav_target;
+        location.projected_position = projected;
+        location.ray_segment = segment;
+        location.ground_hit = ground_hit;
+        location.nav_target = nav_target;
+        changed
+    });
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,238 @@
+//! Named regions of the screen that track whether they contain the cursor.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::position::WindowPositions;
+use crate::{target_window, KeyModifiers};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that holds named regions of the screen and tracks which of them
+/// contain the cursor.
+///
+/// A [`CursorRegionEnter`] event is sent when the cursor enters a region and a
+/// [`CursorRegionLeave`] event is sent when it leaves it. This is useful for
+/// edge-scroll zones, HUD hotspots, or drop targets that aren't entities.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut regions: ResMut<CursorRegions>) {
+///     regions.insert(
+///         "minimap",
+///         CursorRegion::Window {
+///             window: None,
+///             rect: Rect::new(0.0, 0.0, 200.0, 200.0),
+///         },
+///     );
+/// }
+///
+/// fn show_minimap_tooltip(regions: Res<CursorRegions>) {
+///     if regions.contains_cursor("minimap") {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(show_minimap_tooltip);
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorRegions {
+    regions: Vec<(String, CursorRegion)>,
+    current: Vec<String>,
+}
+
+/// A region of the screen.
+///
+/// See [`CursorRegions`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorRegion {
+    /// A rectangle of a window, in logical pixels.
+    Window {
+        /// The entity id of the window, or [`None`] for any window.
+        window: Option<Entity>,
+        /// The rectangle in logical pixels, relative to the top-left corner of the window.
+        rect: Rect,
+    },
+    /// A rectangle of the viewport of a camera, in logical pixels.
+    Viewport {
+        /// The entity id of the camera.
+        camera: Entity,
+        /// The rectangle in logical pixels, relative to the top-left corner of the viewport.
+        rect: Rect,
+    },
+}
+
+/// An event sent when the cursor enters a region of the [`CursorRegions`].
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorRegionEnter {
+    /// The name of the region.
+    pub name: String,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+}
+
+/// An event sent when the cursor leaves a region of the [`CursorRegions`].
+///
+/// This event is also sent when a region that contains the cursor is removed.
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorRegionLeave {
+    /// The name of the region.
+    pub name: String,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+}
+
+impl CursorRegions {
+    /// Adds a region, or replaces the region with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, region: CursorRegion) {
+        let name = name.into();
+        match self.regions.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, r)) => *r = region,
+            None => self.regions.push((name, region)),
+        }
+    }
+
+    /// Removes a region and returns it.
+    pub fn remove(&mut self, name: &str) -> Option<CursorRegion> {
+        let index = self.regions.iter().position(|(n, _)| n == name)?;
+        Some(self.regions.remove(index).1)
+    }
+
+    /// The region with the given name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&CursorRegion> {
+        self.regions
+            .iter()
+            .find_map(|(n, region)| (n == name).then_some(region))
+    }
+
+    /// Iterates over the names of the regions that contain the cursor.
+    #[inline]
+    pub fn current_regions(&self) -> impl Iterator<Item = &str> {
+        self.current.iter().map(String::as_str)
+    }
+
+    /// Returns `true` if the region with the given name contains the cursor.
+    #[inline]
+    pub fn contains_cursor(&self, name: &str) -> bool {
+        self.current.iter().any(|n| n == name)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the regions that contain the cursor and sends the enter and leave events.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_cursor_regions(
+    window_q: Query<(Entity, &Window)>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    camera_q: Query<&Camera>,
+    positions: WindowPositions,
+    mut regions: ResMut<CursorRegions>,
+    mut enter: EventWriter<CursorRegionEnter>,
+    mut leave: EventWriter<CursorRegionLeave>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+) {
+    if regions.regions.is_empty() && regions.current.is_empty() {
+        return;
+    }
+
+    let modifiers = KeyModifiers::from_keys(keys.as_deref());
+
+    // The window that contains the cursor and the position of the cursor on it.
+    let cursor = positions
+        .find(&window_q)
+        .map(|(win_ref, _, position)| (win_ref, position.position));
+
+    let primary_window = primary_window_q.get_single().ok();
+
+    let current = regions
+        .regions
+        .iter()
+        .filter(|(_, region)| {
+            let Some((win_ref, position)) = cursor else {
+                return false;
+            };
+            match *region {
+                CursorRegion::Window { window, rect } => {
+                    window.is_none_or(|window| window == win_ref) && rect.contains(position)
+                }
+                CursorRegion::Viewport { camera, rect } => camera_q
+                    .get(camera)
+                    .ok()
+                    .and_then(|camera| viewport_on_window(camera, win_ref, primary_window))
+                    .is_some_and(|viewport| rect.contains(position - viewport.min)),
+            }
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    if current == regions.current {
+        return;
+    }
+
+    for name in &regions.current {
+        if !current.contains(name) {
+            leave.send(CursorRegionLeave {
+                name: name.clone(),
+                modifiers,
+            });
+        }
+    }
+
+    for name in &current {
+        if !regions.current.contains(name) {
+            enter.send(CursorRegionEnter {
+                name: name.clone(),
+                modifiers,
+            });
+        }
+    }
+
+    regions.current = current;
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the logical viewport of the camera on the given window, or [`None`] if
+/// the camera doesn't render to this window.
+pub(crate) fn viewport_on_window(
+    camera: &Camera,
+    win_ref: Entity,
+    primary_window: Option<Entity>,
+) -> Option<Rect> {
+    if target_window(&camera.target, primary_window) != Some(win_ref) {
+        return None;
+    }
+    camera.logical_viewport_rect()
+}
+
+/* -------------------------------------------------------------------------- */
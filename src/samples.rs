@@ -0,0 +1,258 @@
+//! Every cursor position reported during a frame.
+
+use std::marker::PhantomData;
+
+use bevy::ecs::query::Has;
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use bevy::window::{CursorMoved, PrimaryWindow};
+
+use crate::input::OptionalEventReader;
+use crate::position::WindowPositions;
+use crate::stamp::LocationClock;
+use crate::{
+    resolve_location, CameraFilter, CameraQuery, CameraSelection, CursorLocation,
+    FocusedWindowOnly, Location,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the [`Location`] of every position of the cursor
+/// reported since the last frame, in the order they have been received.
+///
+/// The [`CursorLocation`] only holds the last position of the cursor. With a high
+/// polling rate mouse, the cursor moves several times per frame: drawing apps can
+/// use the samples to draw smooth strokes instead of frame-rate-limited segments.
+///
+/// The samples are resolved from the [`CursorMoved`] events, with the camera of the
+/// [`CursorLocation`] when it's still under the cursor. The positions reported while
+/// the cursor is locked are ignored. With the [`HighRateSampling`], they are
+/// reconstructed from the raw mouse motion instead.
+///
+/// This resource is only updated if it exists.
+/// See [`TrackCursorPlugin::with_cursor_samples`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn draw_stroke(samples: Res<CursorSamples>) {
+///     for location in samples.iter() {
+///         info!("Brush at {:?}", location.position);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(draw_stroke);
+/// ```
+///
+/// [`CursorMoved`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.CursorMoved.html
+/// [`TrackCursorPlugin::with_cursor_samples`]: crate::TrackCursorPlugin::with_cursor_samples
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorSamples<F: CameraFilter = ()>(
+    Vec<Location>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    PhantomData<fn() -> F>,
+);
+
+impl<F: CameraFilter> Default for CursorSamples<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> Clone for CursorSamples<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<F: CameraFilter> PartialEq for CursorSamples<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for CursorSamples<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CursorSamples").field(&self.0).finish()
+    }
+}
+
+impl<F: CameraFilter> CursorSamples<F> {
+    /// Iterates over the [`Location`]s of the cursor reported since the last frame,
+    /// from the oldest to the newest.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Location> {
+        self.0.iter()
+    }
+
+    /// The number of samples.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the cursor hasn't moved since the last frame.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates mutably over the [`Location`]s of the cursor.
+    #[cfg(any(feature = "2d", feature = "3d", feature = "ui"))]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Location> {
+        self.0.iter_mut()
+    }
+}
+
+/// A resource that reconstructs the [`CursorSamples`] from the raw mouse motion.
+///
+/// Some platforms coalesce the [`CursorMoved`] events, so a mouse with a high polling
+/// rate reports fewer positions than it could. When this resource exists, the
+/// samples are rebuilt from the [`MouseMotion`] events, which are reported at the
+/// polling rate of the mouse where the platform provides the raw input.
+///
+/// The raw motion isn't affected by the pointer acceleration of the OS, so the path
+/// it describes is scaled and corrected to end at the last position reported by the
+/// window. The [`CursorMoved`] events are used as is when the cursor entered the
+/// window during the frame, or when there are fewer motion events than positions.
+///
+/// See [`TrackCursorPlugin::with_high_rate_sampling`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// App::new().add_plugins(TrackCursorPlugin::default().with_high_rate_sampling());
+/// ```
+///
+/// [`CursorMoved`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.CursorMoved.html
+/// [`MouseMotion`]: https://docs.rs/bevy/0.15.0/bevy/input/mouse/struct.MouseMotion.html
+/// [`TrackCursorPlugin::with_high_rate_sampling`]: crate::TrackCursorPlugin::with_high_rate_sampling
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HighRateSampling;
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the positions along the path described by the raw motion `deltas` from
+/// `start`, scaled and corrected so the last one is `end`.
+fn raw_path(start: Vec2, end: Vec2, deltas: &[Vec2]) -> impl Iterator<Item = Vec2> + '_ {
+    let raw_length = deltas.iter().map(|delta| delta.length()).sum::<f32>();
+    let scale = if raw_length > 0.0 {
+        start.distance(end) / raw_length
+    } else {
+        0.0
+    };
+    let drift = end - start - deltas.iter().sum::<Vec2>() * scale;
+    let count = deltas.len() as f32;
+
+    deltas
+        .iter()
+        .enumerate()
+        .scan(Vec2::ZERO, move |raw, (i, delta)| {
+            *raw += *delta;
+            Some(start + *raw * scale + drift * ((i + 1) as f32 / count))
+        })
+}
+
+/// Resolves the [`CursorMoved`] events into the [`CursorSamples`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_cursor_samples<F: CameraFilter>(
+    samples: Option<ResMut<CursorSamples<F>>>,
+    high_rate: Option<Res<HighRateSampling>>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut mouse_motion: OptionalEventReader<MouseMotion>,
+    mut last_position: Local<Option<(Entity, Vec2)>>,
+    window_q: Query<(Entity, &Window, Has<PrimaryWindow>)>,
+    camera_q: CameraQuery<F::Filter>,
+    window_positions: WindowPositions,
+    focused_only: Option<Res<FocusedWindowOnly>>,
+    selection: CameraSelection<F>,
+    cursor: Res<CursorLocation<F>>,
+    clock: LocationClock,
+) {
+    let Some(mut samples) = samples else {
+        cursor_moved.clear();
+        mouse_motion.clear();
+        return;
+    };
+
+    let now = clock.now();
+    let current_camera = cursor.camera();
+
+    let positions = cursor_moved
+        .read()
+        .filter(|event| !window_positions.is_grabbed(event.window))
+        .map(|event| (event.window, event.position))
+        .collect::<Vec<_>>();
+    let deltas = mouse_motion
+        .read()
+        .map(|motion| motion.delta)
+        .collect::<Vec<_>>();
+
+    let start = last_position.take();
+    *last_position = positions.last().copied().or(start);
+
+    let positions = match (high_rate, start, positions.last()) {
+        (Some(_), Some((start_window, start)), Some(&(end_window, end)))
+            if deltas.len() > positions.len()
+                && positions.iter().all(|&(window, _)| window == start_window)
+                && end_window == start_window =>
+        {
+            raw_path(start, end, &deltas)
+                .map(|position| (end_window, position))
+                .collect()
+        }
+        _ => positions,
+    };
+
+    let locations = positions
+        .into_iter()
+        .filter_map(|(win_ref, position)| {
+            let (win_ref, window, is_primary) = window_q.get(win_ref).ok()?;
+
+            if focused_only.is_some() && !window.focused {
+                return None;
+            }
+
+            let mut location = resolve_location(
+                win_ref,
+                window,
+                is_primary,
+                window_positions.align(position, window),
+                &camera_q,
+                &selection,
+                current_camera,
+            )?;
+
+            location.raw_position = position;
+            location.stamp(None, now);
+            Some(location)
+        })
+        .collect::<Vec<_>>();
+
+    if samples.0 != locations {
+        samples.0 = locations;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,140 @@
+//! Position of the cursor on the desktop.
+
+use bevy::prelude::*;
+use bevy::window::{Monitor, WindowPosition};
+
+use crate::FocusedWindowOnly;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the position of the cursor on the desktop, and the
+/// monitor it is on.
+///
+/// This is useful to position window-management tools or tooltips that escape
+/// the window in screen space.
+///
+/// The position is computed from the [`WindowPosition`] of the window that contains
+/// the cursor, so it's only available when the windowing backend reports it
+/// (it doesn't on Wayland or on the web). The position of a window is the position
+/// of its outer frame, so the decorations of the window offset the result.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_screen_position(screen: Res<CursorScreenLocation>) {
+///     if let Some(location) = screen.get() {
+///         info!("Cursor at {:?} on monitor {:?}", location.position, location.monitor);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_screen_position);
+/// ```
+///
+/// [`WindowPosition`]: https://docs.rs/bevy/0.15.0/bevy/window/enum.WindowPosition.html
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorScreenLocation(Option<ScreenLocation>);
+
+/// The location of the cursor on the desktop.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ScreenLocation {
+    /// The position of the cursor on the desktop, in physical pixels.
+    pub position: IVec2,
+
+    /// The entity id of the window that contains the cursor.
+    pub window: Entity,
+
+    /// The entity id of the [`Monitor`] the cursor is on.
+    ///
+    /// This is [`None`] if the position isn't on any known monitor.
+    ///
+    /// [`Monitor`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Monitor.html
+    pub monitor: Option<Entity>,
+}
+
+impl CursorScreenLocation {
+    /// The [`ScreenLocation`] of the cursor.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if the position
+    /// of its window is unknown.
+    #[inline]
+    pub fn get(&self) -> Option<&ScreenLocation> {
+        self.0.as_ref()
+    }
+
+    /// The position of the cursor on the desktop, in physical pixels.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, or if the position
+    /// of its window is unknown.
+    #[inline]
+    pub fn position(&self) -> Option<IVec2> {
+        self.get().map(|location| location.position)
+    }
+
+    /// The entity id of the [`Monitor`] the cursor is on.
+    ///
+    /// Returns [`None`] if the cursor is outside any window area, if the position
+    /// of its window is unknown or if it isn't on any known monitor.
+    ///
+    /// [`Monitor`]: https://docs.rs/bevy/0.15.0/bevy/window/struct.Monitor.html
+    #[inline]
+    pub fn monitor(&self) -> Option<Entity> {
+        self.get().and_then(|location| location.monitor)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Updates the [`CursorScreenLocation`].
+pub(crate) fn update_cursor_screen_location(
+    window_q: Query<(Entity, &Window)>,
+    monitor_q: Query<(Entity, &Monitor)>,
+    focused_only: Option<Res<FocusedWindowOnly>>,
+    mut screen: ResMut<CursorScreenLocation>,
+) {
+    let location = window_q
+        .iter()
+        .filter(|(_, window)| focused_only.is_none() || window.focused)
+        .find_map(|(win_ref, window)| {
+            let cursor_position = window.physical_cursor_position()?;
+            let WindowPosition::At(window_position) = window.position else {
+                return None;
+            };
+
+            let position = window_position + cursor_position.as_ivec2();
+
+            let monitor = monitor_q
+                .iter()
+                .find(|(_, monitor)| {
+                    IRect::from_corners(
+                        monitor.physical_position,
+                        monitor.physical_position + monitor.physical_size().as_ivec2(),
+                    )
+                    .contains(position)
+                })
+                .map(|(monitor_ref, _)| monitor_ref);
+
+            Some(ScreenLocation {
+                position,
+                window: win_ref,
+                monitor,
+            })
+        });
+
+    screen.set_if_neq(CursorScreenLocation(location));
+}
+
+/* -------------------------------------------------------------------------- */
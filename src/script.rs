@@ -0,0 +1,218 @@
+//! Scripted cursor movements and clicks.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::ButtonState;
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+
+use crate::CursorSimulator;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that plays back a sequence of cursor movements and clicks.
+///
+/// The cursor is moved with the [`CursorSimulator`] and the clicks are sent as
+/// [`MouseButtonInput`] events, so they go through the same pipeline as a real
+/// mouse. This is useful for trailers, tutorials or end-to-end tests.
+///
+/// The clicks are skipped in the apps without the `InputPlugin`, which registers the
+/// [`MouseButtonInput`] events.
+///
+/// Each step starts on the frame after the previous one has finished, so a
+/// [`click`](Self::click) presses the button on a frame and releases it on the next.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// # use std::time::Duration;
+/// fn play_tutorial(mut script: ResMut<CursorScript>, window: Single<Entity, With<Window>>) {
+///     script
+///         .move_to(*window, Vec2::new(100.0, 100.0), Duration::ZERO, EaseFunction::Linear)
+///         .move_to(
+///             *window,
+///             Vec2::new(400.0, 250.0),
+///             Duration::from_secs(1),
+///             EaseFunction::QuadraticInOut,
+///         )
+///         .click(MouseButton::Left);
+/// }
+///
+/// # let _ = IntoSystem::into_system(play_tutorial);
+/// ```
+///
+/// [`MouseButtonInput`]: https://docs.rs/bevy/0.15.0/bevy/input/mouse/struct.MouseButtonInput.html
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorScript {
+    steps: VecDeque<ScriptStep>,
+    /// The time elapsed since the start of the current step.
+    elapsed: Duration,
+    /// The position of the cursor at the start of the current move.
+    from: Option<Vec2>,
+    /// The window of the last move, which receives the clicks.
+    window: Option<Entity>,
+}
+
+/// A step of a [`CursorScript`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+enum ScriptStep {
+    MoveTo {
+        window: Entity,
+        position: Vec2,
+        duration: Duration,
+        easing: EaseFunction,
+    },
+    Press(MouseButton),
+    Release(MouseButton),
+    Wait(Duration),
+}
+
+impl CursorScript {
+    /// Moves the cursor to the given logical position on the window, over `duration`.
+    pub fn move_to(
+        &mut self,
+        window: Entity,
+        position: Vec2,
+        duration: Duration,
+        easing: EaseFunction,
+    ) -> &mut Self {
+        self.steps.push_back(ScriptStep::MoveTo {
+            window,
+            position,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Presses a button on the window of the last move.
+    pub fn press(&mut self, button: MouseButton) -> &mut Self {
+        self.steps.push_back(ScriptStep::Press(button));
+        self
+    }
+
+    /// Releases a button on the window of the last move.
+    pub fn release(&mut self, button: MouseButton) -> &mut Self {
+        self.steps.push_back(ScriptStep::Release(button));
+        self
+    }
+
+    /// Presses then releases a button on the window of the last move.
+    pub fn click(&mut self, button: MouseButton) -> &mut Self {
+        self.press(button).release(button)
+    }
+
+    /// Does nothing for `duration`.
+    pub fn wait(&mut self, duration: Duration) -> &mut Self {
+        self.steps.push_back(ScriptStep::Wait(duration));
+        self
+    }
+
+    /// Returns `true` if all the steps have been played.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Removes the steps that haven't been played yet.
+    pub fn clear(&mut self) {
+        self.steps.clear();
+        self.elapsed = Duration::ZERO;
+        self.from = None;
+    }
+
+    /// Moves to the next step.
+    fn next_step(&mut self) {
+        self.steps.pop_front();
+        self.elapsed = Duration::ZERO;
+        self.from = None;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Plays the current step of the [`CursorScript`].
+pub(crate) fn play_cursor_script(
+    mut script: ResMut<CursorScript>,
+    mut simulator: ResMut<CursorSimulator>,
+    buttons: Option<ResMut<Events<MouseButtonInput>>>,
+    window_q: Query<&Window>,
+    time: Res<Time>,
+) {
+    let Some(&step) = script.steps.front() else {
+        return;
+    };
+
+    match step {
+        ScriptStep::MoveTo {
+            window,
+            position,
+            duration,
+            easing,
+        } => {
+            if script.from.is_none() {
+                let from = window_q
+                    .get(window)
+                    .ok()
+                    .and_then(Window::cursor_position)
+                    .unwrap_or(position);
+                script.from = Some(from);
+                script.window = Some(window);
+            } else {
+                script.elapsed += time.delta();
+            }
+
+            let t = if duration.is_zero() {
+                1.0
+            } else {
+                script.elapsed.as_secs_f32() / duration.as_secs_f32()
+            };
+
+            let from = script.from.unwrap_or(position);
+            let current = EasingCurve::new(from, position, easing).sample_clamped(t);
+            simulator.set_position(window, current);
+
+            if t >= 1.0 {
+                script.next_step();
+            }
+        }
+        ScriptStep::Press(button) | ScriptStep::Release(button) => {
+            if let (Some(window), Some(mut buttons)) = (script.window, buttons) {
+                let state = match step {
+                    ScriptStep::Press(_) => ButtonState::Pressed,
+                    _ => ButtonState::Released,
+                };
+                buttons.send(MouseButtonInput {
+                    button,
+                    state,
+                    window,
+                });
+            }
+            script.next_step();
+        }
+        ScriptStep::Wait(duration) => {
+            script.elapsed += time.delta();
+            if script.elapsed >= duration {
+                script.next_step();
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,210 @@
+//! Mouse wheel events paired with the cursor location.
+
+use std::marker::PhantomData;
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+
+use crate::input::OptionalEventReader;
+use crate::{CameraFilter, CursorLocation, KeyModifiers, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// An event sent for each scroll of the mouse wheel, with the location of the cursor
+/// in the same frame.
+///
+/// The scrolls over a window that doesn't contain the cursor location are ignored.
+/// This way, a zoom anchored on the cursor or a scroll routed to the viewport under
+/// the cursor doesn't have to join the [`MouseWheel`] events with the
+/// [`CursorLocation`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn zoom(mut scrolls: EventReader<ScrollAt>) {
+///     for scroll in scrolls.read() {
+///         info!(
+///             "Zoom the camera {:?} by {}",
+///             scroll.location.camera, scroll.delta.y
+///         );
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(zoom);
+/// ```
+///
+/// [`MouseWheel`]: https://docs.rs/bevy/0.15.0/bevy/input/mouse/struct.MouseWheel.html
+#[derive(Event, Reflect, Debug, Clone, PartialEq)]
+pub struct ScrollAt<F: CameraFilter = ()> {
+    /// How much the wheel scrolled horizontally and vertically.
+    pub delta: Vec2,
+    /// The unit of the [`delta`](Self::delta).
+    pub unit: MouseScrollUnit,
+    /// The location of the cursor.
+    pub location: Location,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/// A resource that provides the scroll of the mouse wheel during this frame, routed
+/// to the camera under the cursor.
+///
+/// This way, each viewport of a split-screen or an editor receives its own scroll
+/// without a global handler. The scroll is given in lines: a pixel-based scroll of
+/// 100 pixels counts as a line.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// #[derive(Component)]
+/// struct Zoom(f32);
+///
+/// fn zoom(scroll: Res<HoveredCameraScroll>, mut camera_q: Query<(Entity, &mut Zoom)>) {
+///     for (camera, mut zoom) in &mut camera_q {
+///         if let Some(lines) = scroll.get(camera) {
+///             zoom.0 *= 1.1_f32.powf(lines.y);
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(zoom);
+/// ```
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredCameraScroll<F: CameraFilter = ()> {
+    camera: Option<Entity>,
+    lines: Vec2,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    marker: PhantomData<fn() -> F>,
+}
+
+impl<F: CameraFilter> Default for HoveredCameraScroll<F> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            camera: None,
+            lines: Vec2::ZERO,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> Clone for HoveredCameraScroll<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            camera: self.camera,
+            lines: self.lines,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F: CameraFilter> PartialEq for HoveredCameraScroll<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.camera == other.camera && self.lines == other.lines
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for HoveredCameraScroll<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HoveredCameraScroll")
+            .field("camera", &self.camera)
+            .field("lines", &self.lines)
+            .finish()
+    }
+}
+
+impl<F: CameraFilter> HoveredCameraScroll<F> {
+    /// The entity id of the camera that received the scroll during this frame.
+    ///
+    /// Returns [`None`] if the wheel didn't scroll over a camera.
+    #[inline]
+    pub fn camera(&self) -> Option<Entity> {
+        self.camera
+    }
+
+    /// How many lines the wheel scrolled horizontally and vertically during this frame.
+    #[inline]
+    pub fn lines(&self) -> Vec2 {
+        self.lines
+    }
+
+    /// How many lines the wheel scrolled over the given camera during this frame.
+    ///
+    /// Returns [`None`] if the wheel didn't scroll over this camera.
+    #[inline]
+    pub fn get(&self, camera: Entity) -> Option<Vec2> {
+        (self.camera == Some(camera)).then_some(self.lines)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Pairs the [`MouseWheel`] events with the cursor location.
+pub(crate) fn send_scroll_at<F: CameraFilter>(
+    mut wheel: OptionalEventReader<MouseWheel>,
+    cursor: Res<CursorLocation<F>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut scroll_at: EventWriter<ScrollAt<F>>,
+) {
+    let Some(location) = cursor.get().filter(|location| !location.is_stale) else {
+        wheel.clear();
+        return;
+    };
+
+    let modifiers = KeyModifiers::from_keys(keys.as_deref());
+
+    for event in wheel.read() {
+        if event.window != location.window {
+            continue;
+        }
+
+        scroll_at.send(ScrollAt {
+            delta: Vec2::new(event.x, event.y),
+            unit: event.unit,
+            location: location.clone(),
+            modifiers,
+            marker: PhantomData,
+        });
+    }
+}
+
+/// Accumulates the [`ScrollAt`] events of this frame into the [`HoveredCameraScroll`].
+pub(crate) fn update_hovered_camera_scroll<F: CameraFilter>(
+    mut scroll_at: EventReader<ScrollAt<F>>,
+    mut scroll: ResMut<HoveredCameraScroll<F>>,
+) {
+    let mut camera = None;
+    let mut lines = Vec2::ZERO;
+
+    for event in scroll_at.read() {
+        camera = Some(event.location.camera);
+        lines += match event.unit {
+            MouseScrollUnit::Line => event.delta,
+            MouseScrollUnit::Pixel => event.delta / 100.0,
+        };
+    }
+
+    scroll.set_if_neq(HoveredCameraScroll {
+        camera,
+        lines,
+        marker: PhantomData,
+    });
+}
+
+/* -------------------------------------------------------------------------- */
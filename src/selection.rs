@@ -0,0 +1,177 @@
+//! Selection of the 2D entities with the cursor.
+
+use bevy::prelude::*;
+
+use crate::{CursorDragEnd, CursorLocation, HoverShape, HoveredEntities, KeyModifiers};
+
+/* -------------------------------------------------------------------------- */
+
+/// A plugin that selects the entities with a [`HoverShape`] with the cursor.
+///
+/// - Clicking an entity selects it, and clicking an empty area clears the selection;
+/// - Shift-clicking an entity adds it to the selection;
+/// - Dragging a marquee selects the entities whose center is inside it, or adds them
+///   to the selection if shift is held. This requires the [`CursorDragSettings`]
+///   resource to allow the button to drag.
+///
+/// The selected entities have the [`Selected`] marker, and each change of the
+/// selection sends a [`SelectionChanged`] event. The clicked entity is the topmost
+/// one of the [`HoveredEntities`].
+///
+/// This plugin uses the [`CursorLocation`] of the default [`TrackCursorPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// App::new().add_plugins((TrackCursorPlugin::default(), SelectionPlugin::default()));
+///
+/// fn highlight_selection(selected_q: Query<&mut Sprite, Added<Selected>>) {
+///     /* ... */
+/// }
+///
+/// # let _ = IntoSystem::into_system(highlight_selection);
+/// ```
+///
+/// [`CursorDragSettings`]: crate::CursorDragSettings
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionPlugin {
+    /// The mouse button that selects the entities.
+    pub button: MouseButton,
+}
+
+impl Default for SelectionPlugin {
+    /// Selects the entities with the left button.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+        }
+    }
+}
+
+impl Plugin for SelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Selected>()
+            .register_type::<SelectionChanged>()
+            .add_event::<SelectionChanged>()
+            .insert_resource(SelectionButton(self.button))
+            .add_systems(Update, update_selection);
+    }
+}
+
+/// A marker component on the entities selected by the [`SelectionPlugin`].
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct Selected;
+
+/// An event sent when the selection of the [`SelectionPlugin`] changes.
+#[derive(Event, Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct SelectionChanged {
+    /// The entities that have been selected.
+    pub added: Vec<Entity>,
+    /// The entities that have been deselected.
+    pub removed: Vec<Entity>,
+    /// The keyboard modifiers held when the event has been sent.
+    pub modifiers: KeyModifiers,
+}
+
+/// The button of the [`SelectionPlugin`].
+#[derive(Resource)]
+struct SelectionButton(MouseButton);
+
+/* -------------------------------------------------------------------------- */
+
+/// Selects the entities clicked or inside the dragged marquee.
+#[allow(clippy::too_many_arguments)]
+fn update_selection(
+    button: Res<SelectionButton>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    cursor: Option<Res<CursorLocation>>,
+    hovered: Option<Res<HoveredEntities>>,
+    mut drag_end: EventReader<CursorDragEnd>,
+    shape_q: Query<(Entity, &GlobalTransform), With<HoverShape>>,
+    selected_q: Query<Entity, With<Selected>>,
+    mut changed: EventWriter<SelectionChanged>,
+    mut commands: Commands,
+) {
+    let button = button.0;
+    let modifiers = KeyModifiers::from_keys(keys.as_deref());
+
+    // The entities picked by a marquee or a click, if any.
+    let mut picked = None;
+
+    for drag in drag_end.read() {
+        if drag.button != button {
+            continue;
+        }
+
+        let area = Rect::from_corners(drag.start.world_position, drag.location.world_position);
+        picked = Some(
+            shape_q
+                .iter()
+                .filter(|(_, transform)| area.contains(transform.translation().truncate()))
+                .map(|(entity, _)| entity)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    if picked.is_none() && buttons.just_released(button) {
+        let location = cursor
+            .as_ref()
+            .and_then(|cursor| cursor.get())
+            .filter(|location| !location.is_stale);
+
+        #[cfg(feature = "ui")]
+        let location = location.filter(|location| !location.blocked);
+
+        if location.is_some() {
+            let top = hovered.as_ref().and_then(|hovered| hovered.top());
+            picked = Some(top.into_iter().collect());
+        }
+    }
+
+    let Some(picked) = picked else {
+        return;
+    };
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+
+    if !modifiers.shift {
+        removed.extend(selected_q.iter().filter(|entity| !picked.contains(entity)));
+    }
+
+    added.extend(
+        picked
+            .into_iter()
+            .filter(|&entity| !selected_q.contains(entity)),
+    );
+
+    if added.is_empty() && removed.is_empty() {
+        return;
+    }
+
+    for &entity in &removed {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.remove::<Selected>();
+        }
+    }
+
+    for &entity in &added {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.try_insert(Selected);
+        }
+    }
+
+    changed.send(SelectionChanged {
+        added,
+        removed,
+        modifiers,
+    });
+}
+
+/* -------------------------------------------------------------------------- */
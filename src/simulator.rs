@@ -0,0 +1,164 @@
+//! Synthetic cursor positions, for tests.
+
+use bevy::prelude::*;
+use bevy::render::camera::CameraPlugin;
+
+/* -------------------------------------------------------------------------- */
+
+/// A plugin for the headless integration tests, that computes the render targets
+/// of the cameras without the `RenderPlugin`.
+///
+/// The cursor is only resolved through the cameras whose render target is known,
+/// which is usually computed by the `RenderPlugin`. This plugin adds the parts of
+/// it that the resolution needs, so the positions set with the [`CursorSimulator`]
+/// resolve into a [`CursorLocation`] in an app with the `MinimalPlugins` and the
+/// `WindowPlugin`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::window::PrimaryWindow;
+/// # use bevy_cursor::prelude::*;
+/// let mut app = App::new();
+/// app.add_plugins((
+///     MinimalPlugins,
+///     WindowPlugin::default(),
+///     TrackCursorPlugin::default(),
+///     CursorTestPlugin,
+/// ));
+///
+/// app.world_mut()
+///     .spawn((Camera::default(), OrthographicProjection::default_2d()));
+/// app.update();
+///
+/// let window = app
+///     .world_mut()
+///     .query_filtered::<Entity, With<PrimaryWindow>>()
+///     .single(app.world());
+/// app.world_mut()
+///     .resource_mut::<CursorSimulator>()
+///     .set_position(window, Vec2::new(100.0, 50.0));
+/// app.update();
+///
+/// let cursor = app.world().resource::<CursorLocation>();
+/// assert_eq!(cursor.position(), Some(Vec2::new(100.0, 50.0)));
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CursorTestPlugin;
+
+impl Plugin for CursorTestPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<AssetPlugin>() {
+            app.add_plugins(AssetPlugin::default());
+        }
+        if !app.is_plugin_added::<TransformPlugin>() {
+            app.add_plugins(TransformPlugin);
+        }
+        if !app.is_plugin_added::<CameraPlugin>() {
+            app.add_plugins(CameraPlugin);
+        }
+
+        // Note: the render targets of the cameras may be images.
+        app.init_asset::<Image>();
+    }
+}
+
+/// A resource that sets the position of the cursor on the windows, without any
+/// windowing backend.
+///
+/// When this resource changes, the cursor positions it holds are written to the
+/// [`Window`]s before the [`CursorLocation`] is resolved, so the cursor goes
+/// through the same pipeline as a real one. This is useful to test hover or click
+/// logic in headless integration tests, along with the [`CursorTestPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn hover_button(mut simulator: ResMut<CursorSimulator>, window: Single<Entity, With<Window>>) {
+///     simulator.set_position(*window, Vec2::new(100.0, 50.0));
+/// }
+///
+/// # let _ = IntoSystem::into_system(hover_button);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorSimulator {
+    positions: Vec<(Entity, SimulatedPosition)>,
+}
+
+/// A position of the cursor set by the [`CursorSimulator`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+enum SimulatedPosition {
+    Logical(Vec2),
+    Physical(Vec2),
+    Outside,
+}
+
+impl CursorSimulator {
+    /// Moves the cursor to the given logical position on the window.
+    #[inline]
+    pub fn set_position(&mut self, window: Entity, position: Vec2) {
+        self.set(window, SimulatedPosition::Logical(position));
+    }
+
+    /// Moves the cursor to the given physical position on the window.
+    #[inline]
+    pub fn set_physical_position(&mut self, window: Entity, position: Vec2) {
+        self.set(window, SimulatedPosition::Physical(position));
+    }
+
+    /// Moves the cursor out of the window.
+    #[inline]
+    pub fn leave(&mut self, window: Entity) {
+        self.set(window, SimulatedPosition::Outside);
+    }
+
+    fn set(&mut self, window: Entity, position: SimulatedPosition) {
+        match self.positions.iter_mut().find(|(w, _)| *w == window) {
+            Some((_, p)) => *p = position,
+            None => self.positions.push((window, position)),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Writes the positions of the [`CursorSimulator`] to the windows.
+pub(crate) fn apply_cursor_simulator(
+    simulator: Res<CursorSimulator>,
+    mut window_q: Query<&mut Window>,
+) {
+    for &(win_ref, position) in &simulator.positions {
+        let Ok(mut window) = window_q.get_mut(win_ref) else {
+            continue;
+        };
+
+        match position {
+            SimulatedPosition::Logical(position) => window.set_cursor_position(Some(position)),
+            SimulatedPosition::Physical(position) => {
+                window.set_physical_cursor_position(Some(position.as_dvec2()));
+            }
+            SimulatedPosition::Outside => window.set_cursor_position(None),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
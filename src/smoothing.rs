@@ -0,0 +1,312 @@
+//! Smoothing of the cursor location.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{CameraFilter, CursorLocation, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the smoothing of the cursor location, and defines the
+/// filter used to smooth it.
+///
+/// When this resource exists, the [`SmoothedCursorLocation`] follows the
+/// [`CursorLocation`] through the filter. This is useful for laser pointers, aim
+/// assists or presentations, where the raw cursor is too jittery.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorSmoothing::OneEuro {
+///         min_cutoff: 1.0,
+///         beta: 0.01,
+///         derivative_cutoff: 1.0,
+///     });
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorSmoothing {
+    /// An exponential moving average.
+    Ema {
+        /// The time, in seconds, for the smoothed location to cover about 63% of the
+        /// distance to the cursor.
+        time_constant: f32,
+    },
+    /// A [One-Euro filter], which smooths out the jitter when the cursor moves slowly
+    /// and reduces the lag when it moves fast.
+    ///
+    /// [One-Euro filter]: https://gery.casiez.net/1euro/
+    OneEuro {
+        /// The minimum cutoff frequency, in hertz. Lower values reduce the jitter.
+        min_cutoff: f32,
+        /// How much the cutoff frequency increases with the speed of the cursor.
+        /// Higher values reduce the lag.
+        beta: f32,
+        /// The cutoff frequency, in hertz, of the speed of the cursor.
+        derivative_cutoff: f32,
+    },
+    /// A critically damped spring, which follows the cursor without overshooting.
+    Spring {
+        /// The approximate time, in seconds, for the smoothed location to reach the cursor.
+        smooth_time: f32,
+    },
+}
+
+/// A resource that provides the [`CursorLocation`] smoothed by the [`CursorSmoothing`]
+/// filter.
+///
+/// The [`position`](Location::position), the world position (with the feature `2d`),
+/// and the ray and the projected position (with the feature `3d`) are smoothed.
+/// The other fields are the ones of the [`CursorLocation`]. The smoothing restarts
+/// when the window or the camera of the cursor changes.
+///
+/// If the [`CursorSmoothing`] resource doesn't exist, this resource is the same as
+/// the [`CursorLocation`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn move_laser_pointer(cursor: Res<SmoothedCursorLocation>) {
+///     if let Some(position) = cursor.position() {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(move_laser_pointer);
+/// ```
+#[derive(Resource, Reflect, Deref)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = ""),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SmoothedCursorLocation<F: CameraFilter = ()>(CursorLocation<F>);
+
+impl<F: CameraFilter> Default for SmoothedCursorLocation<F> {
+    #[inline]
+    fn default() -> Self {
+        Self(CursorLocation::default())
+    }
+}
+
+impl<F: CameraFilter> Clone for SmoothedCursorLocation<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: CameraFilter> PartialEq for SmoothedCursorLocation<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: CameraFilter> std::fmt::Debug for SmoothedCursorLocation<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SmoothedCursorLocation")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The state of the filter of a smoothed value.
+#[derive(Clone, Copy)]
+struct Filtered {
+    value: Vec3,
+    /// The last unfiltered value, for the One-Euro filter.
+    raw: Vec3,
+    /// The velocity of the value, for the spring and the One-Euro filter.
+    velocity: Vec3,
+}
+
+impl Filtered {
+    #[inline]
+    fn new(value: Vec3) -> Self {
+        Self {
+            value,
+            raw: value,
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Moves the value toward `target`, after `dt` seconds.
+    fn update(&mut self, target: Vec3, dt: f32, smoothing: CursorSmoothing) -> Vec3 {
+        match smoothing {
+            CursorSmoothing::Ema { time_constant } => {
+                let alpha = if time_constant > 0.0 {
+                    1.0 - (-dt / time_constant).exp()
+                } else {
+                    1.0
+                };
+                self.value = self.value.lerp(target, alpha);
+            }
+            CursorSmoothing::OneEuro {
+                min_cutoff,
+                beta,
+                derivative_cutoff,
+            } => {
+                let alpha = |cutoff: f32| {
+                    let tau = 1.0 / (TAU * cutoff);
+                    1.0 / (1.0 + tau / dt)
+                };
+
+                let velocity = (target - self.raw) / dt;
+                self.raw = target;
+                self.velocity = self.velocity.lerp(velocity, alpha(derivative_cutoff));
+
+                let cutoff = min_cutoff + beta * self.velocity.length();
+                self.value = self.value.lerp(target, alpha(cutoff));
+            }
+            CursorSmoothing::Spring { smooth_time } => {
+                if smooth_time > 0.0 {
+                    let omega = 2.0 / smooth_time;
+                    let x = omega * dt;
+                    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+                    let change = self.value - target;
+                    let temp = (self.velocity + omega * change) * dt;
+                    self.velocity = (self.velocity - omega * temp) * decay;
+                    self.value = target + (change + temp) * decay;
+                } else {
+                    self.value = target;
+                    self.velocity = Vec3::ZERO;
+                }
+            }
+        }
+
+        self.value
+    }
+}
+
+/// The state of the filters of the smoothed location.
+pub(crate) struct SmoothingState {
+    window: Entity,
+    camera: Entity,
+    position: Filtered,
+    #[cfg(feature = "2d")]
+    world_position: Filtered,
+    #[cfg(feature = "3d")]
+    ray_origin: Filtered,
+    #[cfg(feature = "3d")]
+    ray_direction: Filtered,
+    #[cfg(feature = "3d")]
+    projected_position: Option<Filtered>,
+}
+
+impl SmoothingState {
+    fn new(location: &Location) -> Self {
+        Self {
+            window: location.window,
+            camera: location.camera,
+            position: Filtered::new(location.position.extend(0.0)),
+            #[cfg(feature = "2d")]
+            world_position: Filtered::new(location.world_position.extend(0.0)),
+            #[cfg(feature = "3d")]
+            ray_origin: Filtered::new(location.ray.origin),
+            #[cfg(feature = "3d")]
+            ray_direction: Filtered::new(*location.ray.direction),
+            #[cfg(feature = "3d")]
+            projected_position: location.projected_position.map(Filtered::new),
+        }
+    }
+
+    /// Smooths the location, after `dt` seconds.
+    fn update(&mut self, location: &Location, dt: f32, smoothing: CursorSmoothing) -> Location {
+        let mut smoothed = location.clone();
+
+        smoothed.position = self
+            .position
+            .update(location.position.extend(0.0), dt, smoothing)
+            .truncate();
+
+        #[cfg(feature = "2d")]
+        {
+            smoothed.world_position = self
+                .world_position
+                .update(location.world_position.extend(0.0), dt, smoothing)
+                .truncate();
+        }
+
+        #[cfg(feature = "3d")]
+        {
+            let origin = self.ray_origin.update(location.ray.origin, dt, smoothing);
+            let direction = self
+                .ray_direction
+                .update(*location.ray.direction, dt, smoothing);
+            smoothed.ray = Ray3d::new(
+                origin,
+                Dir3::new(direction).unwrap_or(location.ray.direction),
+            );
+
+            smoothed.projected_position = match location.projected_position {
+                Some(target) => Some(
+                    self.projected_position
+                        .get_or_insert_with(|| Filtered::new(target))
+                        .update(target, dt, smoothing),
+                ),
+                None => {
+                    self.projected_position = None;
+                    None
+                }
+            };
+        }
+
+        smoothed
+    }
+}
+
+/// Updates the [`SmoothedCursorLocation`].
+pub(crate) fn smooth_cursor_location<F: CameraFilter>(
+    smoothing: Option<Res<CursorSmoothing>>,
+    cursor: Res<CursorLocation<F>>,
+    smoothed: ResMut<SmoothedCursorLocation<F>>,
+    mut state: Local<Option<SmoothingState>>,
+    time: Res<Time<Real>>,
+) {
+    let mut smoothed = smoothed.map_unchanged(|smoothed| &mut smoothed.0 .0);
+
+    let (Some(smoothing), Some(location)) = (smoothing, cursor.get()) else {
+        *state = None;
+        smoothed.set_if_neq(cursor.0.clone());
+        return;
+    };
+
+    let dt = time.delta_secs();
+
+    let smoothed_location = match state.as_mut() {
+        Some(state) if state.window == location.window && state.camera == location.camera => {
+            if dt <= 0.0 {
+                return;
+            }
+            state.update(location, dt, *smoothing)
+        }
+        _ => {
+            *state = Some(SmoothingState::new(location));
+            location.clone()
+        }
+    };
+
+    smoothed.set_if_neq(Some(smoothed_location));
+}
+
+/* -------------------------------------------------------------------------- */
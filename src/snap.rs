@@ -0,0 +1,334 @@
+//! Snapping of the cursor world position to a grid.
+
+use std::marker::PhantomData;
+
+use bevy::math::CompassOctant;
+use bevy::prelude::*;
+
+use crate::derived::LocationsMut;
+use crate::grid::SQRT_3;
+use crate::{CameraFilter, CursorLocation, HexGrid, HexOrientation, IsoGrid, IsoStagger};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that snaps the world position of the cursor to a grid.
+///
+/// When this resource exists, the [`Location::snapped_cell`] and
+/// [`Location::snapped_world_position`] are computed from the
+/// [`world_position`](crate::Location::world_position), and a [`CursorCellChanged`]
+/// event is sent each time the cursor moves to another cell.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorSnap::new(Vec2::splat(32.0)));
+/// }
+///
+/// #[derive(Component)]
+/// struct Ghost;
+///
+/// fn move_ghost_building(
+///     cursor: Res<CursorLocation>,
+///     mut ghost: Single<&mut Transform, With<Ghost>>,
+/// ) {
+///     if let Some(position) = cursor.snapped_world_position() {
+///         ghost.translation = position.extend(0.0);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(move_ghost_building);
+/// ```
+///
+/// [`Location::snapped_cell`]: crate::Location::snapped_cell
+/// [`Location::snapped_world_position`]: crate::Location::snapped_world_position
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorSnap {
+    /// The size of a cell in world units.
+    ///
+    /// For an isometric grid, this is the width and the height of a tile.
+    /// For a hexagonal grid, only the X component is used as the distance between
+    /// the center and a corner of a hexagon.
+    pub cell_size: Vec2,
+
+    /// The world position of the center of the cell `(0, 0)`.
+    pub offset: Vec2,
+
+    /// The shape of the grid.
+    pub mode: SnapMode,
+}
+
+/// The shape of the grid of a [`CursorSnap`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum SnapMode {
+    /// A grid of axis-aligned rectangles.
+    #[default]
+    Square,
+    /// A grid of diamond-shaped tiles.
+    ///
+    /// The X axis of the grid goes to the upper right and its Y axis goes to the
+    /// upper left. See [`IsoGrid`].
+    Isometric,
+    /// A grid of diamond-shaped tiles, whose rows or columns are shifted by half a
+    /// tile, like the staggered isometric maps of the tilemap editors.
+    ///
+    /// See [`IsoGrid::staggered_tile`].
+    Staggered(IsoStagger),
+    /// A grid of regular hexagons, whose cells are identified by their axial coordinates.
+    ///
+    /// See [`HexGrid`].
+    Hex(HexOrientation),
+}
+
+impl CursorSnap {
+    /// Creates a new [`CursorSnap`] for a square grid whose cell `(0, 0)` is centered
+    /// on the origin.
+    #[inline]
+    pub fn new(cell_size: Vec2) -> Self {
+        Self {
+            cell_size,
+            offset: Vec2::ZERO,
+            mode: SnapMode::Square,
+        }
+    }
+
+    /// Returns the cell that contains the world position.
+    pub fn cell(&self, world_position: Vec2) -> IVec2 {
+        match self.mode {
+            SnapMode::Square => {
+                let position = (world_position - self.offset) / self.cell_size;
+                (position + 0.5).floor().as_ivec2()
+            }
+            SnapMode::Isometric => self.iso_grid().tile(world_position),
+            SnapMode::Staggered(stagger) => self.iso_grid().staggered_tile(world_position, stagger),
+            SnapMode::Hex(orientation) => self.hex_grid(orientation).axial(world_position),
+        }
+    }
+
+    /// Returns the world position of the center of a cell.
+    pub fn cell_center(&self, cell: IVec2) -> Vec2 {
+        match self.mode {
+            SnapMode::Square => self.offset + cell.as_vec2() * self.cell_size,
+            SnapMode::Isometric => self.iso_grid().tile_center(cell),
+            SnapMode::Staggered(stagger) => self.iso_grid().staggered_tile_center(cell, stagger),
+            SnapMode::Hex(orientation) => self.hex_grid(orientation).axial_center(cell),
+        }
+    }
+
+    /// Returns where the world position is within the cell that contains it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::math::CompassOctant;
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// let snap = CursorSnap::new(Vec2::splat(32.0));
+    ///
+    /// let position = snap.cell_position(Vec2::new(12.0, -2.0));
+    /// assert_eq!(position.uv, Vec2::new(0.875, 0.4375));
+    /// assert_eq!(position.nearest_edge, CompassOctant::East);
+    /// assert_eq!(position.nearest_corner, CompassOctant::SouthEast);
+    /// ```
+    pub fn cell_position(&self, world_position: Vec2) -> CellPosition {
+        let center = self.cell_center(self.cell(world_position));
+        let local = world_position - center;
+
+        match self.mode {
+            SnapMode::Square => {
+                let local = local / self.cell_size;
+                // The distances to the vertical and the horizontal edges, in world units.
+                let distances = (0.5 - local.abs()) * self.cell_size;
+
+                let nearest_edge = match (distances.x < distances.y, local) {
+                    (true, local) if local.x >= 0.0 => CompassOctant::East,
+                    (true, _) => CompassOctant::West,
+                    (false, local) if local.y >= 0.0 => CompassOctant::North,
+                    (false, _) => CompassOctant::South,
+                };
+
+                let nearest_corner = match (local.x >= 0.0, local.y >= 0.0) {
+                    (true, true) => CompassOctant::NorthEast,
+                    (true, false) => CompassOctant::SouthEast,
+                    (false, false) => CompassOctant::SouthWest,
+                    (false, true) => CompassOctant::NorthWest,
+                };
+
+                CellPosition {
+                    uv: local + 0.5,
+                    nearest_edge,
+                    nearest_corner,
+                }
+            }
+            // Note: the staggered tiles have the same shape as the isometric tiles.
+            SnapMode::Isometric | SnapMode::Staggered(_) => {
+                // The position along the X and the Y axes of the grid.
+                let local = local / self.cell_size;
+                let axes = Vec2::new(local.x + local.y, local.y - local.x);
+                let distances = 0.5 - axes.abs();
+
+                let nearest_edge = match (distances.x < distances.y, axes) {
+                    (true, axes) if axes.x >= 0.0 => CompassOctant::NorthEast,
+                    (true, _) => CompassOctant::SouthWest,
+                    (false, axes) if axes.y >= 0.0 => CompassOctant::NorthWest,
+                    (false, _) => CompassOctant::SouthEast,
+                };
+
+                let nearest_corner = match (axes.x >= 0.0, axes.y >= 0.0) {
+                    (true, true) => CompassOctant::North,
+                    (true, false) => CompassOctant::East,
+                    (false, false) => CompassOctant::South,
+                    (false, true) => CompassOctant::West,
+                };
+
+                CellPosition {
+                    uv: axes + 0.5,
+                    nearest_edge,
+                    nearest_corner,
+                }
+            }
+            SnapMode::Hex(orientation) => {
+                let size = self.cell_size.x;
+
+                // The angles of the middle of the first edge and of the first corner.
+                let (bounds, edge_angle, corner_angle) = match orientation {
+                    HexOrientation::PointyTop => (Vec2::new(SQRT_3 * size, 2.0 * size), 0.0, 30.0),
+                    HexOrientation::FlatTop => (Vec2::new(2.0 * size, SQRT_3 * size), 30.0, 0.0),
+                };
+
+                let angle = local.to_angle().to_degrees();
+                let nearest = |first: f32| {
+                    let angle = first + ((angle - first) / 60.0).round() * 60.0;
+                    CompassOctant::from(Dir2::new_unchecked(Vec2::from_angle(angle.to_radians())))
+                };
+
+                CellPosition {
+                    uv: local / bounds + 0.5,
+                    nearest_edge: nearest(edge_angle),
+                    nearest_corner: nearest(corner_angle),
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn iso_grid(&self) -> IsoGrid {
+        IsoGrid {
+            tile_size: self.cell_size,
+            origin: self.offset,
+        }
+    }
+
+    #[inline]
+    fn hex_grid(&self, orientation: HexOrientation) -> HexGrid {
+        HexGrid {
+            size: self.cell_size.x,
+            orientation,
+            origin: self.offset,
+        }
+    }
+}
+
+/// The position of the cursor within a cell of the [`CursorSnap`] grid.
+///
+/// This is useful for the tools that place things on the edges or the corners of the
+/// cells, like roads or walls.
+///
+/// The edges and the corners are identified by their direction from the center of
+/// the cell, on screen: the north is upward.
+///
+/// See [`Location::snapped_cell_position`].
+///
+/// [`Location::snapped_cell_position`]: crate::Location::snapped_cell_position
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CellPosition {
+    /// The position within the cell, from `(0, 0)` to `(1, 1)`.
+    ///
+    /// For a square grid, it goes from the bottom-left corner to the top-right corner
+    /// of the cell. For an isometric grid, it goes from the bottom corner to the top
+    /// corner, along the X and the Y axes of the grid. For a hexagonal grid, it goes
+    /// from the bottom-left corner to the top-right corner of the bounding rectangle
+    /// of the hexagon.
+    pub uv: Vec2,
+    /// The direction of the edge of the cell nearest to the cursor.
+    pub nearest_edge: CompassOctant,
+    /// The direction of the corner of the cell nearest to the cursor.
+    pub nearest_corner: CompassOctant,
+}
+
+/// An event sent when the cursor moves to another cell of the [`CursorSnap`] grid.
+///
+/// The cell is [`None`] when the cursor left every window, or when the snapping
+/// has been disabled.
+#[derive(Event, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorCellChanged<F: CameraFilter = ()> {
+    /// The cell under the cursor.
+    pub cell: Option<IVec2>,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> F>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the `snapped_cell`, the `snapped_world_position` and the
+/// `snapped_cell_position` of every location.
+pub(crate) fn snap_cursor_location<F: CameraFilter>(
+    snap: Option<Res<CursorSnap>>,
+    mut locations: LocationsMut<F>,
+) {
+    let snap = snap.map(|snap| *snap);
+
+    locations.for_each_location_mut(|location| {
+        let cell = snap.map(|snap| snap.cell(location.world_position));
+        let position = snap.zip(cell).map(|(snap, cell)| snap.cell_center(cell));
+        let cell_position = snap.map(|snap| snap.cell_position(location.world_position));
+
+        let changed = location.snapped_cell != cell
+            || location.snapped_world_position != position
+            || location.snapped_cell_position != cell_position;
+        location.snapped_cell = cell;
+        location.snapped_world_position = position;
+        location.snapped_cell_position = cell_position;
+        changed
+    });
+}
+
+/// Sends a [`CursorCellChanged`] event when the snapped cell of the cursor changes.
+pub(crate) fn send_cell_changed<F: CameraFilter>(
+    cursor: Res<CursorLocation<F>>,
+    mut events: EventWriter<CursorCellChanged<F>>,
+    mut previous: Local<Option<IVec2>>,
+) {
+    let cell = cursor.get().and_then(|location| location.snapped_cell);
+
+    if cell != *previous {
+        *previous = cell;
+        events.send(CursorCellChanged {
+            cell,
+            marker: PhantomData,
+        });
+    }
+}
+
+/* -------------------------------------------------------------------------- */
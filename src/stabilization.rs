@@ -0,0 +1,103 @@
+//! Averaging of the cursor position over the last frames.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{CameraFilter, CursorLocation};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that enables the stabilization of the cursor position.
+///
+/// When this resource exists, the [`Location::stabilized_position`] is the average
+/// of the positions of the cursor over the last [`samples`](Self::samples) frames.
+/// This removes the single-pixel noise for precision tools like vertex editing.
+///
+/// The average restarts when the cursor moves to another window.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(CursorStabilization::new(4));
+/// }
+///
+/// fn move_vertex(cursor: Res<CursorLocation>) {
+///     if let Some(position) = cursor.get().and_then(|location| location.stabilized_position) {
+///         /* ... */
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// # let _ = IntoSystem::into_system(move_vertex);
+/// ```
+///
+/// [`Location::stabilized_position`]: crate::Location::stabilized_position
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorStabilization {
+    /// The number of frames the position is averaged over.
+    pub samples: usize,
+}
+
+impl CursorStabilization {
+    /// Creates a new [`CursorStabilization`] that averages the position over `samples` frames.
+    #[inline]
+    pub fn new(samples: usize) -> Self {
+        Self { samples }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the [`Location::stabilized_position`] of the [`CursorLocation`].
+///
+/// [`Location::stabilized_position`]: crate::Location::stabilized_position
+pub(crate) fn stabilize_cursor_location<F: CameraFilter>(
+    stabilization: Option<Res<CursorStabilization>>,
+    mut cursor: ResMut<CursorLocation<F>>,
+    mut history: Local<VecDeque<Vec2>>,
+    mut window: Local<Option<Entity>>,
+) {
+    let Some(location) = cursor.bypass_change_detection().0.as_mut() else {
+        history.clear();
+        *window = None;
+        return;
+    };
+
+    let stabilized = stabilization
+        .filter(|stabilization| stabilization.samples > 0)
+        .map(|stabilization| {
+            if *window != Some(location.window) {
+                history.clear();
+                *window = Some(location.window);
+            }
+
+            history.push_back(location.position);
+            while history.len() > stabilization.samples {
+                history.pop_front();
+            }
+
+            history.iter().sum::<Vec2>() / history.len() as f32
+        });
+
+    if stabilized.is_none() {
+        history.clear();
+        *window = None;
+    }
+
+    if location.stabilized_position != stabilized {
+        location.stabilized_position = stabilized;
+        cursor.set_changed();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,75 @@
+//! Retention of the last known location of the cursor.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::Location;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that keeps the last known location of the cursor when it leaves
+/// the windows.
+///
+/// When this resource exists, the [`CursorLocation`] isn't cleared when the cursor
+/// moves out of every window. Instead, it keeps the last [`Location`], with
+/// [`is_stale`](Location::is_stale) set to `true` and its
+/// [`timestamp`](Location::timestamp) and [`frame`](Location::frame) set to the
+/// moment the cursor left.
+///
+/// This is useful to keep showing the last hovered point while the user reaches
+/// for a menu outside of the window.
+///
+/// The [`CursorLocations`] are still cleared, and a stale location doesn't count
+/// as being over a window or a camera (see [`CursorOver`] and [`CursorOutWindow`]).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn highlight_last_point(cursor: Res<CursorLocation>) {
+///     if let Some(location) = cursor.get() {
+///         if location.is_stale {
+///             info!("The cursor left at {:?}", location.position);
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(highlight_last_point);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+/// [`CursorLocations`]: crate::CursorLocations
+/// [`CursorOver`]: crate::CursorOver
+/// [`CursorOutWindow`]: crate::CursorOutWindow
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct KeepStaleLocation;
+
+/* -------------------------------------------------------------------------- */
+
+impl Location {
+    /// Marks the location as stale since the given instant and frame.
+    ///
+    /// A location that is already stale is left unchanged.
+    pub(crate) fn into_stale(self, (timestamp, frame): (Duration, u32)) -> Location {
+        if self.is_stale {
+            return self;
+        }
+
+        Location {
+            is_stale: true,
+            timestamp,
+            frame,
+            ..self
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
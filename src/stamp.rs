@@ -0,0 +1,80 @@
+//! Timestamps of the cursor locations.
+
+use std::time::Duration;
+
+use bevy::core::FrameCount;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::Location;
+
+/* -------------------------------------------------------------------------- */
+
+/// The instant and the frame at which the locations are computed.
+#[derive(SystemParam)]
+pub(crate) struct LocationClock<'w> {
+    time: Option<Res<'w, Time<Real>>>,
+    frame: Option<Res<'w, FrameCount>>,
+}
+
+impl LocationClock<'_> {
+    /// Returns the current instant and frame.
+    pub(crate) fn now(&self) -> (Duration, u32) {
+        (
+            self.time
+                .as_ref()
+                .map_or(Duration::ZERO, |time| time.elapsed()),
+            self.frame.as_ref().map_or(0, |frame| frame.0),
+        )
+    }
+}
+
+impl Location {
+    /// Stamps the location with the given instant and frame.
+    ///
+    /// If the location has been computed from the same data as `previous`, it's
+    /// replaced by `previous` instead, so it keeps its stamps and doesn't trigger
+    /// change detection.
+    pub(crate) fn stamp(
+        &mut self,
+        previous: Option<&Location>,
+        (timestamp, frame): (Duration, u32),
+    ) {
+        if let Some(previous) = previous {
+            let mut same = previous.clone();
+            same.position = self.position;
+            same.raw_position = self.raw_position;
+            same.window = self.window;
+            same.camera = self.camera;
+            same.camera_order = self.camera_order;
+            same.viewport_rect = self.viewport_rect;
+            same.physical_viewport_rect = self.physical_viewport_rect;
+            same.render_layers = self.render_layers.clone();
+            same.kind = self.kind;
+            same.grabbed = self.grabbed;
+            same.outside_window = self.outside_window;
+            same.scale_factor = self.scale_factor;
+            same.is_stale = self.is_stale;
+
+            #[cfg(feature = "2d")]
+            {
+                same.world_position = self.world_position;
+            }
+
+            #[cfg(feature = "3d")]
+            {
+                same.ray = self.ray;
+            }
+
+            if same == *previous {
+                *self = same;
+                return;
+            }
+        }
+
+        self.timestamp = timestamp;
+        self.frame = frame;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
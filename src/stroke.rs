@@ -0,0 +1,255 @@
+//! Interpolation of the cursor positions into evenly-spaced stroke points.
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// The number of straight segments a curved segment of a stroke is flattened into.
+const CURVE_SUBDIVISIONS: usize = 8;
+
+/// A helper that turns the successive positions of the cursor into evenly-spaced
+/// points, for brush engines and line tools.
+///
+/// Push the positions of the cursor with [`push`](Self::push) (for instance the
+/// world positions of the [`CursorSamples`]) and place a dab of the brush at each
+/// returned point. Call [`finish`](Self::finish) when the stroke ends.
+///
+/// The stroke can be smoothed with a [`StrokeSmoothing`]. A smoothed stroke lags
+/// one position behind the cursor, since the curve between two positions depends
+/// on the next one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn paint(
+///     samples: Res<CursorSamples>,
+///     buttons: Res<ButtonInput<MouseButton>>,
+///     mut stroke: Local<Option<StrokeInterpolator>>,
+/// ) {
+///     let stroke = stroke.get_or_insert_with(|| {
+///         StrokeInterpolator::new(4.0).with_smoothing(StrokeSmoothing::CatmullRom)
+///     });
+///
+///     if buttons.pressed(MouseButton::Left) {
+///         for location in samples.iter() {
+///             for point in stroke.push(location.position) {
+///                 info!("Dab at {point:?}");
+///             }
+///         }
+///     } else {
+///         for point in stroke.finish() {
+///             info!("Dab at {point:?}");
+///         }
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(paint);
+/// ```
+///
+/// [`CursorSamples`]: crate::CursorSamples
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StrokeInterpolator {
+    spacing: f32,
+    smoothing: StrokeSmoothing,
+    /// The last positions pushed, used as the control points of the curve.
+    positions: Vec<Vec2>,
+    /// The end of the part of the stroke that has already been walked.
+    head: Option<Vec2>,
+    /// The distance walked since the last emitted point.
+    carry: f32,
+}
+
+/// How a [`StrokeInterpolator`] joins the positions of the cursor.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum StrokeSmoothing {
+    /// The positions are joined by straight lines.
+    #[default]
+    None,
+    /// The positions are joined by a Catmull-Rom spline, which passes through
+    /// every position.
+    CatmullRom,
+    /// The middles of the positions are joined by quadratic Bézier curves whose
+    /// control points are the positions, which smooths out the jitter of the cursor.
+    Bezier,
+}
+
+impl StrokeInterpolator {
+    /// Creates a new [`StrokeInterpolator`] that emits a point every `spacing` units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spacing` is not strictly positive.
+    #[inline]
+    pub fn new(spacing: f32) -> Self {
+        assert!(spacing > 0.0, "the spacing of a stroke must be positive");
+        Self {
+            spacing,
+            smoothing: StrokeSmoothing::None,
+            positions: Vec::new(),
+            head: None,
+            carry: 0.0,
+        }
+    }
+
+    /// Sets how the positions are joined.
+    #[inline]
+    pub fn with_smoothing(mut self, smoothing: StrokeSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// The distance between two emitted points.
+    #[inline]
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    /// How the positions are joined.
+    #[inline]
+    pub fn smoothing(&self) -> StrokeSmoothing {
+        self.smoothing
+    }
+
+    /// Returns `true` if a stroke has been started and not finished yet.
+    #[inline]
+    pub fn is_stroking(&self) -> bool {
+        self.head.is_some()
+    }
+
+    /// Adds a position to the stroke and returns the points emitted since the last
+    /// position.
+    ///
+    /// The first position of a stroke is always emitted.
+    pub fn push(&mut self, position: Vec2) -> Vec<Vec2> {
+        let mut points = Vec::new();
+
+        let Some(head) = self.head else {
+            // Start a new stroke.
+            points.push(position);
+            self.head = Some(position);
+            self.carry = 0.0;
+            self.positions.clear();
+            self.positions.push(position);
+            if self.smoothing == StrokeSmoothing::CatmullRom {
+                self.positions.push(position);
+            }
+            return points;
+        };
+
+        match self.smoothing {
+            StrokeSmoothing::None => {
+                self.walk_to(position, &mut points);
+            }
+            StrokeSmoothing::CatmullRom => {
+                self.positions.push(position);
+                if let [p0, p1, p2, p3] = self.positions[..] {
+                    self.walk_curve(|t| catmull_rom(p0, p1, p2, p3, t), &mut points);
+                    self.positions.remove(0);
+                }
+            }
+            StrokeSmoothing::Bezier => match self.positions[..] {
+                [start] => {
+                    self.walk_to(start.midpoint(position), &mut points);
+                    self.positions.push(position);
+                }
+                [_, control] => {
+                    let end = control.midpoint(position);
+                    self.walk_curve(|t| quadratic_bezier(head, control, end, t), &mut points);
+                    self.positions = vec![control, position];
+                }
+                _ => {}
+            },
+        }
+
+        points
+    }
+
+    /// Ends the stroke and returns the points of its remaining part.
+    pub fn finish(&mut self) -> Vec<Vec2> {
+        let mut points = Vec::new();
+
+        if self.head.is_some() {
+            match self.smoothing {
+                StrokeSmoothing::None => {}
+                StrokeSmoothing::CatmullRom => {
+                    if let [p0, p1, p2] = self.positions[..] {
+                        self.walk_curve(|t| catmull_rom(p0, p1, p2, p2, t), &mut points);
+                    }
+                }
+                StrokeSmoothing::Bezier => {
+                    if let [_, last] = self.positions[..] {
+                        self.walk_to(last, &mut points);
+                    }
+                }
+            }
+        }
+
+        self.head = None;
+        self.carry = 0.0;
+        self.positions.clear();
+
+        points
+    }
+
+    /// Walks the curve, from the head of the stroke at `t = 0` to `t = 1`.
+    fn walk_curve(&mut self, curve: impl Fn(f32) -> Vec2, points: &mut Vec<Vec2>) {
+        for i in 1..=CURVE_SUBDIVISIONS {
+            self.walk_to(curve(i as f32 / CURVE_SUBDIVISIONS as f32), points);
+        }
+    }
+
+    /// Walks in a straight line from the head of the stroke to `target`, emitting
+    /// a point every `spacing` units.
+    fn walk_to(&mut self, target: Vec2, points: &mut Vec<Vec2>) {
+        let Some(head) = self.head else {
+            return;
+        };
+
+        let length = head.distance(target);
+        if length <= 0.0 {
+            return;
+        }
+
+        // The distance from the head to the next point.
+        let mut next = self.spacing - self.carry;
+        while next <= length {
+            points.push(head.lerp(target, next / length));
+            next += self.spacing;
+        }
+
+        self.carry = length - (next - self.spacing);
+        self.head = Some(target);
+    }
+}
+
+/// Evaluates the uniform Catmull-Rom spline between `p1` and `p2`.
+fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// Evaluates the quadratic Bézier curve from `start` to `end`.
+fn quadratic_bezier(start: Vec2, control: Vec2, end: Vec2, t: f32) -> Vec2 {
+    let u = 1.0 - t;
+    u * u * start + 2.0 * u * t * control + t * t * end
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,230 @@
+//! Cursor resolution through the in-world surfaces that display a camera render.
+
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+use crate::{camera_location, CursorLocation, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// The maximum number of surfaces the cursor is seen through, so a surface that
+/// displays itself doesn't loop forever.
+const MAX_DEPTH: usize = 4;
+
+/// A component for the in-world screens or mirrors that display the render of
+/// another camera.
+///
+/// The surface is a rectangle in the local XY plane of the entity, centered on it,
+/// over which the render target of the camera is stretched. When the cursor hovers
+/// the surface, it is re-projected into the camera, and the nested location is
+/// provided by the [`NestedCursorLocations`].
+///
+/// The surface is hit with the ray of the cursor with the feature `3d`, and with
+/// its world position otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn spawn_screen(mut commands: Commands, security_camera: Single<Entity, With<Camera>>) {
+///     commands.spawn((
+///         Transform::from_xyz(0.0, 2.0, -5.0),
+///         PortalSurface::new(*security_camera, Vec2::new(1.6, 0.9)),
+///     ));
+/// }
+///
+/// # let _ = IntoSystem::into_system(spawn_screen);
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct PortalSurface {
+    /// The entity id of the camera whose render is displayed by the surface.
+    pub camera: Entity,
+    /// Half the width and the height of the surface, in local units.
+    pub half_size: Vec2,
+}
+
+impl PortalSurface {
+    /// Creates a new [`PortalSurface`] for the given camera.
+    #[inline]
+    pub fn new(camera: Entity, half_size: Vec2) -> Self {
+        Self { camera, half_size }
+    }
+
+    /// Returns the position of a local point of the surface on the render target of
+    /// size `target_size`, or [`None`] if the point is outside the surface.
+    fn target_position(&self, local: Vec2, target_size: Vec2) -> Option<Vec2> {
+        if local.abs().cmpgt(self.half_size).any() || self.half_size.cmple(Vec2::ZERO).any() {
+            return None;
+        }
+
+        let uv = Vec2::new(
+            (local.x + self.half_size.x) / (2.0 * self.half_size.x),
+            (self.half_size.y - local.y) / (2.0 * self.half_size.y),
+        );
+        Some(uv * target_size)
+    }
+}
+
+/// A resource that provides the locations of the cursor seen through the
+/// [`PortalSurface`]s it hovers, from the outermost to the innermost.
+///
+/// The nested locations keep the [`position`](Location::position), the window and
+/// the state of the pointer of the cursor, like [`Location::through_portal`] does,
+/// while their camera, world position and ray are the ones seen through the surfaces.
+/// The cursor is followed with the [`CursorLocation`] of the default
+/// [`TrackCursorPlugin`], so there is no nested location without it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_nested_camera(nested: Res<NestedCursorLocations>) {
+///     if let Some(location) = nested.innermost() {
+///         info!("The cursor is seen through the camera {}", location.camera);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_nested_camera);
+/// ```
+///
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct NestedCursorLocations(Vec<(Entity, Location)>);
+
+impl NestedCursorLocations {
+    /// The location seen through the innermost surface.
+    #[inline]
+    pub fn innermost(&self) -> Option<&Location> {
+        self.0.last().map(|(_, location)| location)
+    }
+
+    /// Iterates over the hovered surfaces and the locations seen through them, from
+    /// the outermost to the innermost.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Entity, &Location)> {
+        self.0
+            .iter()
+            .map(|(surface, location)| (*surface, location))
+    }
+
+    /// Returns `true` if the cursor doesn't hover any surface.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the nearest surface hit by the location, and the point hit in the local
+/// space of the surface.
+fn hit_surface<'a>(
+    location: &Location,
+    surface_q: &'a Query<(Entity, &PortalSurface, &GlobalTransform)>,
+) -> Option<(Entity, &'a PortalSurface, Vec2)> {
+    surface_q
+        .iter()
+        .filter_map(|(entity, surface, transform)| {
+            let inverse = transform.affine().inverse();
+
+            #[cfg(feature = "3d")]
+            let (local, distance) = {
+                let ray = location.ray;
+                let normal = transform.back();
+                let distance =
+                    ray.intersect_plane(transform.translation(), InfinitePlane3d::new(normal))?;
+                let local = inverse.transform_point3(ray.get_point(distance));
+                (local.truncate(), distance)
+            };
+
+            #[cfg(not(feature = "3d"))]
+            let (local, distance) = {
+                let point = location.world_position.extend(transform.translation().z);
+                (
+                    inverse.transform_point3(point).truncate(),
+                    -transform.translation().z,
+                )
+            };
+
+            (local.abs().cmple(surface.half_size).all())
+                .then_some((entity, surface, local, distance))
+        })
+        .min_by(|(_, _, _, a), (_, _, _, b)| a.total_cmp(b))
+        .map(|(entity, surface, local, _)| (entity, surface, local))
+}
+
+/// Updates the [`NestedCursorLocations`].
+pub(crate) fn update_nested_cursor_locations(
+    cursor: Option<Res<CursorLocation>>,
+    surface_q: Query<(Entity, &PortalSurface, &GlobalTransform)>,
+    camera_q: Query<(&GlobalTransform, &Camera, Option<&RenderLayers>)>,
+    mut nested: ResMut<NestedCursorLocations>,
+) {
+    let mut locations = Vec::new();
+
+    if !surface_q.is_empty() {
+        let mut current = cursor
+            .as_ref()
+            .and_then(|cursor| cursor.get())
+            .filter(|location| {
+                #[cfg(feature = "ui")]
+                if location.blocked {
+                    return false;
+                }
+
+                !location.is_stale
+            })
+            .cloned();
+
+        while let Some(location) = current.take() {
+            if locations.len() >= MAX_DEPTH {
+                break;
+            }
+
+            let Some((surface_ref, surface, local)) = hit_surface(&location, &surface_q) else {
+                break;
+            };
+            let Ok((cam_t, camera, render_layers)) = camera_q.get(surface.camera) else {
+                break;
+            };
+            let Some(target_size) = camera.logical_target_size() else {
+                break;
+            };
+            let Some(target_position) = surface.target_position(local, target_size) else {
+                break;
+            };
+            let Ok(inner) = camera_location(
+                location.window,
+                surface.camera,
+                cam_t,
+                camera,
+                target_position,
+            ) else {
+                break;
+            };
+
+            let inner = Location {
+                render_layers: render_layers.cloned().unwrap_or_default(),
+                ..inner.carry_pointer(&location)
+            };
+
+            current = Some(inner.clone());
+            locations.push((surface_ref, inner));
+        }
+    }
+
+    if nested.0 != locations {
+        nested.0 = locations;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
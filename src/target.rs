@@ -0,0 +1,150 @@
+//! Resolution of the render targets that are not windows.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+
+/* -------------------------------------------------------------------------- */
+
+/// Translates a [`RenderTarget`] that is not a window into the area of a window
+/// where it is displayed.
+///
+/// By default, the cameras that render into an image or a texture view are ignored
+/// when resolving the cursor. A resolver tells the plugin where such a target ends up
+/// on screen, for instance when a custom compositor or an XR plugin presents it, so
+/// these cameras take part in the resolution like the others.
+///
+/// The [`Location::position`] stays the position on the window, while the world
+/// position and the ray are computed through the camera at the matching position
+/// on its render target.
+///
+/// This trait is implemented for the closures with the same signature as
+/// [`resolve`](Self::resolve).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::camera::RenderTarget;
+/// # use bevy_cursor::prelude::*;
+/// // The render target image is displayed at the top-left corner of the window.
+/// fn resolver(window: Entity, image: Handle<Image>) -> impl CursorTargetResolver {
+///     move |target: &RenderTarget| match target {
+///         RenderTarget::Image(handle) if *handle == image => {
+///             Some((window, Rect::new(0.0, 0.0, 320.0, 240.0)))
+///         }
+///         _ => None,
+///     }
+/// }
+/// ```
+///
+/// [`RenderTarget`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/enum.RenderTarget.html
+/// [`Location::position`]: crate::Location::position
+pub trait CursorTargetResolver: Send + Sync + 'static {
+    /// Returns the entity id of the window on which `target` is displayed, and the
+    /// rectangle of that window it covers, in logical pixels.
+    ///
+    /// Returns [`None`] if this resolver doesn't handle `target`.
+    fn resolve(&self, target: &RenderTarget) -> Option<(Entity, Rect)>;
+}
+
+impl<T> CursorTargetResolver for T
+where
+    T: Fn(&RenderTarget) -> Option<(Entity, Rect)> + Send + Sync + 'static,
+{
+    #[inline]
+    fn resolve(&self, target: &RenderTarget) -> Option<(Entity, Rect)> {
+        self(target)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The registered [`CursorTargetResolver`]s.
+#[derive(Resource, Default)]
+pub(crate) struct CursorTargetResolvers(pub(crate) Vec<Arc<dyn CursorTargetResolver>>);
+
+impl CursorTargetResolvers {
+    /// Returns the window and the rectangle of that window on which `target` is displayed,
+    /// according to the first resolver that handles it.
+    pub(crate) fn resolve(&self, target: &RenderTarget) -> Option<(Entity, Rect)> {
+        self.0.iter().find_map(|resolver| resolver.resolve(target))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that tells where the images rendered by the cameras are displayed on
+/// the windows, when this changes from frame to frame.
+///
+/// This is the dynamic counterpart of the [`CursorTargetResolver`], for the editors
+/// that show the render of a camera in a panel of an immediate mode UI, like a
+/// docked viewport of `bevy_egui`: each frame, the UI reports the area of the
+/// window covered by the image widget, and the cameras that render into the image
+/// take part in the resolution of the cursor like the others.
+///
+/// The [`CursorTargetResolver`]s are consulted first.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Viewport { image: Handle<Image>, rect: Rect }
+/// fn report_viewport_panel(
+///     viewport: Res<Viewport>,
+///     window: Single<Entity, With<Window>>,
+///     mut displays: ResMut<TargetDisplays>,
+/// ) {
+///     // The rectangle of the image widget, as laid out by the UI this frame.
+///     displays.set(&viewport.image, *window, viewport.rect);
+/// }
+///
+/// # let _ = IntoSystem::into_system(report_viewport_panel);
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+pub struct TargetDisplays(Vec<(AssetId<Image>, Entity, Rect)>);
+
+impl TargetDisplays {
+    /// Sets the area of the window, in logical pixels, where the image is displayed.
+    ///
+    /// The image is identified by its [`AssetId`], so this doesn't keep it loaded.
+    ///
+    /// [`AssetId`]: https://docs.rs/bevy/0.15.0/bevy/asset/enum.AssetId.html
+    pub fn set(&mut self, image: impl Into<AssetId<Image>>, window: Entity, rect: Rect) {
+        let image = image.into();
+        match self.0.iter_mut().find(|(id, ..)| *id == image) {
+            Some(display) => *display = (image, window, rect),
+            None => self.0.push((image, window, rect)),
+        }
+    }
+
+    /// Removes the image, whose cameras are ignored again.
+    pub fn remove(&mut self, image: impl Into<AssetId<Image>>) {
+        let image = image.into();
+        self.0.retain(|(id, ..)| *id != image);
+    }
+
+    /// Removes all the images.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns the window and the rectangle of that window on which `target` is displayed.
+    pub(crate) fn resolve(&self, target: &RenderTarget) -> Option<(Entity, Rect)> {
+        let RenderTarget::Image(image) = target else {
+            return None;
+        };
+
+        self.0
+            .iter()
+            .find(|(id, ..)| *id == image.id())
+            .map(|&(_, window, rect)| (window, rect))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,165 @@
+//! Hit-testing of the glyphs of the texts under the cursor.
+
+use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use bevy::sprite::Anchor;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+#[cfg(feature = "2d")]
+use bevy::window::PrimaryWindow;
+
+#[cfg(feature = "2d")]
+use crate::CursorLocation;
+use crate::HoveredUiNode;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the glyph under the cursor.
+///
+/// The glyph is searched in the `bevy_ui` [`Text`] of the [`HoveredUiNode`], and
+/// in the [`Text2d`] entities when the feature `2d` is enabled. This is useful to
+/// implement text selection or hyperlinks.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_hovered_glyph(hovered: Res<HoveredGlyph>) {
+///     if let Some(glyph) = hovered.get() {
+///         info!("Hovering the glyph #{} of {}", glyph.glyph_index, glyph.entity);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_hovered_glyph);
+/// ```
+///
+/// [`Text`]: https://docs.rs/bevy/0.15.0/bevy/ui/widget/struct.Text.html
+/// [`Text2d`]: https://docs.rs/bevy/0.15.0/bevy/text/struct.Text2d.html
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredGlyph(Option<GlyphLocation>);
+
+/// A glyph of a text.
+///
+/// See [`HoveredGlyph`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GlyphLocation {
+    /// The entity id of the text.
+    pub entity: Entity,
+    /// The index of the glyph in the [`TextLayoutInfo::glyphs`] of the text.
+    ///
+    /// [`TextLayoutInfo::glyphs`]: https://docs.rs/bevy/0.15.0/bevy/text/struct.TextLayoutInfo.html#structfield.glyphs
+    pub glyph_index: usize,
+    /// The index of the span of the text that contains the glyph.
+    pub span_index: usize,
+}
+
+impl HoveredGlyph {
+    /// The glyph under the cursor, if any.
+    #[inline]
+    pub fn get(&self) -> Option<&GlyphLocation> {
+        self.0.as_ref()
+    }
+
+    /// The entity id of the text under the cursor, if any.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.0.map(|glyph| glyph.entity)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the index of the glyph that contains the position, expressed in the
+/// same space as the glyph positions.
+fn glyph_at(layout: &TextLayoutInfo, position: Vec2) -> Option<(usize, &PositionedGlyph)> {
+    layout
+        .glyphs
+        .iter()
+        .enumerate()
+        .find(|(_, glyph)| Rect::from_center_size(glyph.position, glyph.size).contains(position))
+}
+
+fn glyph_location(
+    entity: Entity,
+    (glyph_index, glyph): (usize, &PositionedGlyph),
+) -> GlyphLocation {
+    GlyphLocation {
+        entity,
+        glyph_index,
+        span_index: glyph.span_index,
+    }
+}
+
+/// Updates the [`HoveredGlyph`].
+pub(crate) fn update_hovered_glyph(
+    hovered_node: Res<HoveredUiNode>,
+    node_q: Query<(&ComputedNode, &TextLayoutInfo)>,
+    #[cfg(feature = "2d")] cursor: Option<Res<CursorLocation>>,
+    #[cfg(feature = "2d")] primary_window_q: Query<&Window, With<PrimaryWindow>>,
+    #[cfg(feature = "2d")] text2d_q: Query<
+        (
+            Entity,
+            &TextLayoutInfo,
+            &Anchor,
+            &GlobalTransform,
+            &ViewVisibility,
+        ),
+        With<Text2d>,
+    >,
+    mut hovered: ResMut<HoveredGlyph>,
+) {
+    let ui_glyph = hovered_node.get().and_then(|location| {
+        let (node, layout) = node_q.get(location.entity).ok()?;
+        // The glyphs are positioned in physical pixels from the top-left corner of the node.
+        let position = location.position / node.inverse_scale_factor();
+        glyph_at(layout, position).map(|glyph| glyph_location(location.entity, glyph))
+    });
+
+    #[cfg(feature = "2d")]
+    let glyph = ui_glyph.or_else(|| {
+        let world_position = cursor.as_ref()?.get()?.world_position;
+        let scale_factor = primary_window_q
+            .get_single()
+            .map_or(1.0, |window| window.resolution.scale_factor());
+
+        text2d_q
+            .iter()
+            .filter(|(_, _, _, _, visibility)| visibility.get())
+            .filter_map(|(entity, layout, anchor, text_t, _)| {
+                let local = text_t
+                    .affine()
+                    .inverse()
+                    .transform_point3(world_position.extend(text_t.translation().z))
+                    .truncate();
+                // The glyphs are positioned in physical pixels from the bottom-left
+                // corner of the text, which is moved according to the anchor.
+                let alignment = layout.size * -(anchor.as_vec() + 0.5);
+                let position = (local - alignment) * scale_factor;
+                let glyph = glyph_at(layout, position)?;
+                Some((text_t.translation().z, glyph_location(entity, glyph)))
+            })
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, glyph)| glyph)
+    });
+
+    #[cfg(not(feature = "2d"))]
+    let glyph = ui_glyph;
+
+    if hovered.0 != glyph {
+        hovered.0 = glyph;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,144 @@
+//! Throttling of the hover resolution.
+
+use bevy::prelude::*;
+
+use crate::CursorLocation;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that throttles the resolution of the entities and the nodes under the
+/// cursor, so a big scene doesn't pay the hit tests for a stationary cursor.
+///
+/// When this resource exists, the [`HoveredEntities`] (with the feature `2d`), the
+/// [`HoveredUiNode`] and the [`UiHoverStack`] (with the feature `ui`) are resolved
+/// again only when:
+/// - the cursor moved farther than [`distance`](Self::distance) since the last resolution;
+/// - or [`frames`](Self::frames) frames elapsed since the last resolution;
+/// - or the cursor entered, left or changed window or camera.
+///
+/// The [`CursorLocation`] is still updated every frame. The cursor is followed with
+/// the [`CursorLocation`] of the default [`TrackCursorPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     // Resolve the hover when the cursor moves, and twice per second at 60 FPS
+///     // to catch the entities moving under a stationary cursor.
+///     commands.insert_resource(HoverThrottle {
+///         frames: 30,
+///         distance: 0.0,
+///     });
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`HoveredEntities`]: crate::HoveredEntities
+/// [`HoveredUiNode`]: crate::HoveredUiNode
+/// [`UiHoverStack`]: crate::UiHoverStack
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoverThrottle {
+    /// The number of frames after which the hover is resolved again, even if the
+    /// cursor didn't move.
+    ///
+    /// With `0`, the hover is never resolved again while the cursor doesn't move.
+    pub frames: u32,
+
+    /// The distance, in logical pixels, the cursor must move for the hover to be
+    /// resolved again.
+    ///
+    /// With [`f32::INFINITY`], the movements of the cursor are ignored.
+    pub distance: f32,
+}
+
+impl HoverThrottle {
+    /// Resolves the hover every `frames` frames.
+    #[inline]
+    pub fn every(frames: u32) -> Self {
+        Self {
+            frames,
+            distance: f32::INFINITY,
+        }
+    }
+
+    /// Resolves the hover only when the cursor moved farther than `distance`, in
+    /// logical pixels.
+    #[inline]
+    pub fn on_move(distance: f32) -> Self {
+        Self {
+            frames: 0,
+            distance,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The state of the cursor when the hover has been resolved the last time.
+pub(crate) struct ThrottleState {
+    /// The window, the camera and the position of the cursor, if any.
+    cursor: Option<(Entity, Entity, Vec2)>,
+    /// The number of frames since the last resolution.
+    frames: u32,
+}
+
+/// A run condition that tells whether the hover should be resolved this frame,
+/// according to the [`HoverThrottle`].
+pub(crate) fn hover_throttle(
+    throttle: Option<Res<HoverThrottle>>,
+    cursor: Option<Res<CursorLocation>>,
+    mut state: Local<Option<ThrottleState>>,
+) -> bool {
+    let (Some(throttle), Some(cursor)) = (throttle, cursor) else {
+        *state = None;
+        return true;
+    };
+
+    let current = cursor
+        .get()
+        .filter(|location| !location.is_stale)
+        .map(|location| (location.window, location.camera, location.position));
+
+    let resolve = match state.as_mut() {
+        None => true,
+        Some(state) => {
+            state.frames += 1;
+
+            let moved = match (state.cursor, current) {
+                (None, None) => false,
+                (
+                    Some((prev_window, prev_camera, prev_position)),
+                    Some((window, camera, position)),
+                ) => {
+                    prev_window != window
+                        || prev_camera != camera
+                        || prev_position.distance(position) > throttle.distance
+                }
+                _ => true,
+            };
+
+            moved || (throttle.frames > 0 && state.frames >= throttle.frames)
+        }
+    };
+
+    if resolve {
+        *state = Some(ThrottleState {
+            cursor: current,
+            frames: 0,
+        });
+    }
+
+    resolve
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,72 @@
+//! Ordering of the cameras that share the same order.
+
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that defines which camera is considered on top when several cameras
+/// with the same [`Camera::order`], or the same [`CursorPriority`], render into the same window.
+///
+/// The camera on top is the one used to resolve the [`CursorLocation`], and comes
+/// first in the [`CursorLocations`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// App::new().add_plugins(
+///     TrackCursorPlugin::default().with_tie_break(CameraTieBreak::ViewportArea),
+/// );
+/// ```
+///
+/// [`Camera::order`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/struct.Camera.html#structfield.order
+/// [`CursorLocation`]: crate::CursorLocation
+/// [`CursorLocations`]: crate::CursorLocations
+/// [`CursorPriority`]: crate::CursorPriority
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CameraTieBreak {
+    /// The camera with the greatest [`Entity`] is on top.
+    ///
+    /// [`Entity`]: https://docs.rs/bevy/0.15.0/bevy/ecs/entity/struct.Entity.html
+    #[default]
+    EntityId,
+    /// The camera with the smallest viewport is on top, like an inset view drawn over
+    /// a fullscreen view. Cameras with the same viewport area are ordered by entity.
+    ViewportArea,
+}
+
+impl CameraTieBreak {
+    /// Compares two cameras that share the same order.
+    ///
+    /// [`Ordering::Less`] means that `a` is on top of `b`.
+    pub(crate) fn compare(
+        self,
+        (a_ref, a): (Entity, &Camera),
+        (b_ref, b): (Entity, &Camera),
+    ) -> Ordering {
+        let by_entity = b_ref.cmp(&a_ref);
+
+        match self {
+            CameraTieBreak::EntityId => by_entity,
+            CameraTieBreak::ViewportArea => viewport_area(a).cmp(&viewport_area(b)).then(by_entity),
+        }
+    }
+}
+
+/// The area of the viewport of a camera, in physical pixels.
+fn viewport_area(camera: &Camera) -> u64 {
+    camera
+        .physical_viewport_size()
+        .map_or(u64::MAX, |size| u64::from(size.x) * u64::from(size.y))
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,130 @@
+//! Touches mirrored as the cursor.
+
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::prelude::*;
+
+use crate::input::OptionalEventReader;
+use crate::PointerKind;
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that mirrors the most recent touch into the cursor, so the code based
+/// on the [`CursorLocation`] runs unchanged on touch screens.
+///
+/// While a finger touches a window, its position is used as the position of the
+/// cursor on that window, and the locations have the [`PointerKind::Touch`] kind, or
+/// the [`PointerKind::Pen`] kind for a stylus. The value of this resource defines
+/// what happens when the finger is released.
+///
+/// The mouse takes over again as soon as it moves without any touch in the same
+/// frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(TouchAsCursor::Persist);
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`CursorLocation`]: crate::CursorLocation
+/// [`PointerKind::Touch`]: crate::PointerKind::Touch
+/// [`PointerKind::Pen`]: crate::PointerKind::Pen
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum TouchAsCursor {
+    /// The cursor leaves the window when the finger is released.
+    #[default]
+    Clear,
+    /// The cursor stays where the finger has been released, until the next touch or
+    /// mouse motion.
+    Persist,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The touch mirrored as the cursor.
+#[derive(Resource, Default)]
+pub(crate) struct TouchCursor(Option<TouchPoint>);
+
+/// The position of a touch on a window.
+#[derive(Clone, Copy)]
+struct TouchPoint {
+    /// The entity id of the window.
+    window: Entity,
+    /// The position in logical pixels.
+    position: Vec2,
+    /// The id of the touch.
+    id: u64,
+    /// The kind of pointer that made the touch.
+    kind: PointerKind,
+}
+
+impl TouchCursor {
+    /// The position of the touch on the given window, in logical pixels, and the kind
+    /// of pointer that made it.
+    #[inline]
+    pub(crate) fn get(&self, window: Entity) -> Option<(Vec2, PointerKind)> {
+        self.0
+            .filter(|touch| touch.window == window)
+            .map(|touch| (touch.position, touch.kind))
+    }
+}
+
+/// Updates the [`TouchCursor`] with the touch events of this frame.
+pub(crate) fn update_touch_cursor(
+    mode: Res<TouchAsCursor>,
+    mut touches: OptionalEventReader<TouchInput>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut touch: ResMut<TouchCursor>,
+) {
+    let mouse_moved = cursor_moved.read().count() != 0;
+
+    let mut touched = false;
+
+    for event in touches.read() {
+        touched = true;
+
+        let point = TouchPoint {
+            window: event.window,
+            position: event.position,
+            id: event.id,
+            kind: PointerKind::of_touch(event),
+        };
+
+        match event.phase {
+            TouchPhase::Started | TouchPhase::Moved => touch.0 = Some(point),
+            TouchPhase::Ended if *mode == TouchAsCursor::Persist => {
+                if touch.0.is_some_and(|touch| touch.id == event.id) {
+                    touch.0 = Some(point);
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                if touch.0.is_some_and(|touch| touch.id == event.id) {
+                    touch.0 = None;
+                }
+            }
+        }
+    }
+
+    // Some platforms emulate the mouse events from the touches.
+    if mouse_moved && !touched {
+        touch.0 = None;
+    }
+}
+
+/// Clears the [`TouchCursor`] once the [`TouchAsCursor`] resource has been removed.
+pub(crate) fn clear_touch_cursor(mut touch: ResMut<TouchCursor>) {
+    touch.0 = None;
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,65 @@
+//! Runtime control of the cursor tracking.
+
+use bevy::prelude::*;
+
+use crate::{
+    CameraFilter, CursorLocation, CursorLocations, CursorLocationsPerWindow, CursorSamples,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that controls whether the cursor is tracked.
+///
+/// Menus, cutscenes and modal tools can switch the tracking off cheaply by
+/// changing the value of this resource.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn open_menu(mut tracking: ResMut<CursorTracking>) {
+///     *tracking = CursorTracking::Disabled;
+/// }
+///
+/// # let _ = IntoSystem::into_system(open_menu);
+/// ```
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CursorTracking {
+    /// The [`CursorLocation`] is updated every frame.
+    #[default]
+    Enabled,
+    /// The [`CursorLocation`] is no longer updated and keeps its last value.
+    Frozen,
+    /// The [`CursorLocation`] is no longer updated and is cleared.
+    Disabled,
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Clears the locations of the cursor while the tracking is disabled.
+pub(crate) fn clear_cursor_location<F: CameraFilter>(
+    cursor: ResMut<CursorLocation<F>>,
+    mut locations: ResMut<CursorLocations<F>>,
+    per_window: Option<ResMut<CursorLocationsPerWindow<F>>>,
+    samples: Option<ResMut<CursorSamples<F>>>,
+) {
+    let mut cursor = cursor.map_unchanged(|cursor| &mut cursor.0);
+    cursor.set_if_neq(None);
+    locations.set_if_neq(CursorLocations::default());
+
+    if let Some(mut per_window) = per_window {
+        per_window.set_if_neq(CursorLocationsPerWindow::default());
+    }
+    if let Some(mut samples) = samples {
+        samples.set_if_neq(CursorSamples::default());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
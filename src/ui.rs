@@ -0,0 +1,339 @@
+//! The `bevy_ui` node under the cursor.
+
+use bevy::ecs::query::QueryItem;
+use bevy::prelude::*;
+use bevy::ui::{CalculatedClip, DefaultUiCamera, FocusPolicy, UiStack};
+use bevy::window::PrimaryWindow;
+
+use crate::position::WindowPositions;
+use crate::{target_window, CursorBlocker, Location};
+
+/* -------------------------------------------------------------------------- */
+
+/// A resource that provides the topmost `bevy_ui` node under the cursor.
+///
+/// Unlike [`Interaction`], this doesn't require the node to be a button: any visible
+/// node counts, including the ones rendered with custom materials. The nodes with
+/// [`FocusPolicy::Pass`] are skipped so the hover falls through them, like it does
+/// for [`Interaction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_hovered_node(hovered: Res<HoveredUiNode>) {
+///     if let Some(node) = hovered.get() {
+///         info!("Hovering {} at {}", node.entity, node.position);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_hovered_node);
+/// ```
+///
+/// [`Interaction`]: https://docs.rs/bevy/0.15.0/bevy/ui/enum.Interaction.html
+/// [`FocusPolicy::Pass`]: https://docs.rs/bevy/0.15.0/bevy/ui/enum.FocusPolicy.html#variant.Pass
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredUiNode(Option<UiNodeLocation>);
+
+/// The location of the cursor on a `bevy_ui` node.
+///
+/// See [`HoveredUiNode`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiNodeLocation {
+    /// The entity id of the node.
+    pub entity: Entity,
+    /// The position of the cursor relative to the top-left corner of the node, in
+    /// logical pixels.
+    pub position: Vec2,
+    /// The entity id of the window that contains the node.
+    pub window: Entity,
+}
+
+impl HoveredUiNode {
+    /// The location of the cursor on the hovered node, if any.
+    #[inline]
+    pub fn get(&self) -> Option<&UiNodeLocation> {
+        self.0.as_ref()
+    }
+
+    /// The entity id of the hovered node, if any.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.0.map(|node| node.entity)
+    }
+
+    /// The position of the cursor relative to the top-left corner of the hovered node,
+    /// in logical pixels, if any.
+    #[inline]
+    pub fn position(&self) -> Option<Vec2> {
+        self.0.map(|node| node.position)
+    }
+
+    /// The entity id of the window that contains the hovered node, if any.
+    #[inline]
+    pub fn window(&self) -> Option<Entity> {
+        self.0.map(|node| node.window)
+    }
+}
+
+/// A resource that provides all the `bevy_ui` nodes under the cursor, sorted from
+/// the topmost to the bottommost.
+///
+/// Unlike the [`HoveredUiNode`], the nodes with [`FocusPolicy::Pass`] are included,
+/// and the nodes below a blocking node aren't hidden. This way, a radial menu or a
+/// "click through" interaction can cycle through the targets under the cursor.
+///
+/// The stack ends with the topmost [`CursorBlocker`] under the cursor, if any.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn print_hover_stack(stack: Res<UiHoverStack>) {
+///     for node in stack.iter() {
+///         info!("Under the cursor: {}", node.entity);
+///     }
+/// }
+///
+/// # let _ = IntoSystem::into_system(print_hover_stack);
+/// ```
+///
+/// [`FocusPolicy::Pass`]: https://docs.rs/bevy/0.15.0/bevy/ui/enum.FocusPolicy.html#variant.Pass
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiHoverStack(Vec<UiNodeLocation>);
+
+impl UiHoverStack {
+    /// Iterates over the nodes under the cursor, from the topmost to the bottommost.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &UiNodeLocation> {
+        self.0.iter()
+    }
+
+    /// Iterates over the entity ids of the nodes under the cursor, from the topmost
+    /// to the bottommost.
+    #[inline]
+    pub fn entities(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.0.iter().map(|node| node.entity)
+    }
+
+    /// The location of the cursor on the node at the given depth, `0` being the topmost.
+    #[inline]
+    pub fn get(&self, depth: usize) -> Option<&UiNodeLocation> {
+        self.0.get(depth)
+    }
+
+    /// The depth of the given node in the stack, `0` being the topmost.
+    ///
+    /// Returns [`None`] if the node isn't under the cursor.
+    #[inline]
+    pub fn depth(&self, entity: Entity) -> Option<usize> {
+        self.0.iter().position(|node| node.entity == entity)
+    }
+
+    /// The number of nodes under the cursor.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there is no node under the cursor.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the position, in logical pixels, of a physical position relative to the
+/// top-left corner of the node, or [`None`] if it's outside of the node.
+pub(crate) fn node_local_position(
+    physical_position: Vec2,
+    node: &ComputedNode,
+    node_t: &GlobalTransform,
+) -> Option<Vec2> {
+    let size = node.size();
+    if size.cmple(Vec2::ZERO).any() {
+        return None;
+    }
+
+    let rect = Rect::from_center_size(node_t.translation().truncate(), size);
+    rect.contains(physical_position)
+        .then(|| (physical_position - rect.min) * node.inverse_scale_factor())
+}
+
+pub(crate) type NodeQuery<'a> = (
+    Entity,
+    &'a ComputedNode,
+    &'a GlobalTransform,
+    &'a ViewVisibility,
+    Option<&'a CalculatedClip>,
+    Option<&'a TargetCamera>,
+    Option<&'a FocusPolicy>,
+    Has<CursorBlocker>,
+);
+
+/// Returns the location of the cursor on a node, from the physical position of the
+/// cursor on the given window, or [`None`] if the cursor isn't over the visible part
+/// of the node.
+pub(crate) fn ui_node_location(
+    (entity, node, node_t, visibility, clip, target_camera, _, _): QueryItem<NodeQuery>,
+    win_ref: Entity,
+    physical_position: Vec2,
+    primary_window: Option<Entity>,
+    camera_q: &Query<&Camera>,
+    default_ui_camera: &DefaultUiCamera,
+) -> Option<UiNodeLocation> {
+    if !visibility.get() {
+        return None;
+    }
+
+    let camera_ref = target_camera
+        .map(TargetCamera::entity)
+        .or(default_ui_camera.get())?;
+    let camera = camera_q.get(camera_ref).ok()?;
+    if target_window(&camera.target, primary_window) != Some(win_ref) {
+        return None;
+    }
+
+    // The nodes are positioned relative to the viewport of their camera.
+    let viewport_min = camera
+        .physical_viewport_rect()
+        .map_or(Vec2::ZERO, |viewport| viewport.min.as_vec2());
+    let position = physical_position - viewport_min;
+
+    if clip.is_some_and(|clip| !clip.clip.contains(position)) {
+        return None;
+    }
+
+    Some(UiNodeLocation {
+        entity,
+        position: node_local_position(position, node, node_t)?,
+        window: win_ref,
+    })
+}
+
+/// Updates the [`HoveredUiNode`] and the [`UiHoverStack`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_hovered_ui_node(
+    window_q: Query<(Entity, &Window)>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    camera_q: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    positions: WindowPositions,
+    ui_stack: Option<Res<UiStack>>,
+    node_q: Query<NodeQuery>,
+    mut hovered: ResMut<HoveredUiNode>,
+    mut stack: ResMut<UiHoverStack>,
+) {
+    // The window that contains the cursor and the physical position of the cursor on it.
+    let cursor = positions
+        .find(&window_q)
+        .map(|(win_ref, window, position)| (win_ref, position.position * window.scale_factor()));
+
+    let primary_window = primary_window_q.get_single().ok();
+
+    let mut node = None;
+    let mut nodes = Vec::new();
+
+    // Note: the `UiStack` is only provided by the `UiPlugin`.
+    if let (Some((win_ref, position)), Some(ui_stack)) = (cursor, ui_stack) {
+        let under_cursor = ui_stack.uinodes.iter().rev().filter_map(|&entity| {
+            let item = node_q.get(entity).ok()?;
+            let (_, _, _, _, _, _, focus_policy, is_blocker) = item;
+            let location = ui_node_location(
+                item,
+                win_ref,
+                position,
+                primary_window,
+                &camera_q,
+                &default_ui_camera,
+            )?;
+            Some((
+                location,
+                focus_policy == Some(&FocusPolicy::Pass),
+                is_blocker,
+            ))
+        });
+
+        for (location, pass, is_blocker) in under_cursor {
+            if node.is_none() && !pass {
+                node = Some(location);
+            }
+            nodes.push(location);
+
+            // Nothing is hovered beneath a blocker.
+            if is_blocker {
+                break;
+            }
+        }
+    }
+
+    if hovered.0 != node {
+        hovered.0 = node;
+    }
+
+    if stack.0 != nodes {
+        stack.0 = nodes;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Location {
+    /// Returns the position of the cursor relative to the top-left corner of a
+    /// `bevy_ui` node, in logical pixels, or [`None`] if the cursor is outside of it.
+    ///
+    /// This is useful for sliders, color pickers or canvases made with `bevy_ui`.
+    ///
+    /// The node is expected to be rendered by a camera whose viewport covers the
+    /// whole window. Otherwise, use the [`HoveredUiNode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_cursor::prelude::*;
+    /// #[derive(Component)]
+    /// struct Slider;
+    ///
+    /// fn slider_value(
+    ///     cursor: Res<CursorLocation>,
+    ///     slider: Single<(&ComputedNode, &GlobalTransform), With<Slider>>,
+    /// ) {
+    ///     let (node, transform) = *slider;
+    ///     if let Some(position) = cursor.get().and_then(|location| location.in_node(node, transform)) {
+    ///         let value = position.x / (node.size().x * node.inverse_scale_factor());
+    ///         info!("Slider value: {value}");
+    ///     }
+    /// }
+    ///
+    /// # let _ = IntoSystem::into_system(slider_value);
+    /// ```
+    pub fn in_node(&self, node: &ComputedNode, transform: &GlobalTransform) -> Option<Vec2> {
+        let physical_position = self.position * self.scale_factor;
+        node_local_position(physical_position, node, transform)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
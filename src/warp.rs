@@ -0,0 +1,187 @@
+//! Warping of the OS cursor.
+
+use bevy::ecs::query::Has;
+use bevy::ecs::system::SystemState;
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::stamp::LocationClock;
+use crate::{
+    resolve_locations, target_window, CameraFilter, CameraQuery, CameraSelection, CrosshairMode,
+    CursorLocation, CursorLocations,
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// An extension trait for [`Commands`] to warp the OS cursor.
+///
+/// The [`CursorLocation`] resource is updated as soon as the command is applied,
+/// so systems that run after it don't see a stale location.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// #[derive(Component)]
+/// struct Handle;
+///
+/// fn snap_to_handle(
+///     mut commands: Commands,
+///     camera_q: Query<Entity, With<Camera>>,
+///     handle_q: Query<&GlobalTransform, With<Handle>>,
+/// ) {
+///     let camera = camera_q.single();
+///     let handle = handle_q.single();
+///     commands.warp_cursor_to_world(camera, handle.translation());
+/// }
+///
+/// # let _ = IntoSystem::into_system(snap_to_handle);
+/// ```
+pub trait WarpCursorExt {
+    /// Moves the OS cursor to `position`, in logical pixels, on the given window.
+    fn warp_cursor(&mut self, window: Entity, position: Vec2);
+
+    /// Moves the OS cursor over the projection of `position` through the given camera.
+    ///
+    /// Nothing happens if the camera does not render into a window or if the world
+    /// position is not visible by the camera.
+    fn warp_cursor_to_world(&mut self, camera: Entity, position: Vec3);
+}
+
+impl WarpCursorExt for Commands<'_, '_> {
+    #[inline]
+    fn warp_cursor(&mut self, window: Entity, position: Vec2) {
+        self.queue(WarpCursor::Window { window, position });
+    }
+
+    #[inline]
+    fn warp_cursor_to_world(&mut self, camera: Entity, position: Vec3) {
+        self.queue(WarpCursor::World { camera, position });
+    }
+}
+
+/// A [`Command`] that moves the OS cursor and updates the [`CursorLocation`] resource.
+///
+/// See [`WarpCursorExt`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WarpCursor {
+    /// Moves the cursor to a position on a window.
+    Window {
+        /// The entity id of the window.
+        window: Entity,
+        /// The position on the window in logical pixels.
+        position: Vec2,
+    },
+    /// Moves the cursor over the projection of a world position through a camera.
+    World {
+        /// The entity id of the camera.
+        camera: Entity,
+        /// The position in world coordinates.
+        position: Vec3,
+    },
+}
+
+impl Command for WarpCursor {
+    fn apply(self, world: &mut World) {
+        let Some((win_ref, position)) = self.window_position(world) else {
+            return;
+        };
+
+        match world.get_mut::<Window>(win_ref) {
+            Some(mut window) => window.set_cursor_position(Some(position)),
+            None => return,
+        }
+
+        // Update the locations right away.
+        if world.contains_resource::<CrosshairMode>() {
+            return;
+        }
+
+        let Some(hooks) = world.get_resource::<WarpHooks>() else {
+            return;
+        };
+
+        for hook in hooks.0.clone() {
+            hook(world, win_ref, position);
+        }
+    }
+}
+
+impl WarpCursor {
+    /// Computes the window and the position on that window to warp the cursor to.
+    fn window_position(self, world: &mut World) -> Option<(Entity, Vec2)> {
+        match self {
+            WarpCursor::Window { window, position } => Some((window, position)),
+            WarpCursor::World { camera, position } => {
+                let primary_window = world
+                    .query_filtered::<Entity, With<PrimaryWindow>>()
+                    .get_single(world)
+                    .ok();
+
+                let entity = world.get_entity(camera).ok()?;
+                let camera = entity.get::<Camera>()?;
+                let cam_t = entity.get::<GlobalTransform>()?;
+
+                let window = target_window(&camera.target, primary_window)?;
+
+                let viewport_min = camera
+                    .logical_viewport_rect()
+                    .map_or(Vec2::ZERO, |viewport| viewport.min);
+                let position = camera.world_to_viewport(cam_t, position).ok()? + viewport_min;
+
+                Some((window, position))
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The functions that update each [`CursorLocation`] after the cursor has been warped.
+#[derive(Resource, Default)]
+pub(crate) struct WarpHooks(pub(crate) Vec<fn(&mut World, Entity, Vec2)>);
+
+/// Resolves the [`CursorLocation<F>`] and the [`CursorLocations<F>`] at the position
+/// the cursor has been warped to.
+pub(crate) fn resolve_warped_location<F: CameraFilter>(
+    world: &mut World,
+    win_ref: Entity,
+    position: Vec2,
+) {
+    let mut state = SystemState::<(
+        Query<(&Window, Has<PrimaryWindow>)>,
+        CameraQuery<F::Filter>,
+        ResMut<CursorLocation<F>>,
+        ResMut<CursorLocations<F>>,
+        CameraSelection<F>,
+        LocationClock,
+    )>::new(world);
+    let (window_q, camera_q, mut cursor, mut locations, selection, clock) = state.get_mut(world);
+
+    let Ok((window, is_primary)) = window_q.get(win_ref) else {
+        return;
+    };
+
+    let stack = resolve_locations(
+        win_ref,
+        window,
+        is_primary,
+        position,
+        &camera_q,
+        &selection,
+        cursor.camera(),
+    )
+    .filter_map(Result::ok)
+    .map(|mut location| {
+        location.stamp(locations.get(location.camera), clock.now());
+        location
+    })
+    .collect::<Vec<_>>();
+
+    cursor.0 = stack.first().cloned();
+    *locations = CursorLocations::resolved(stack);
+}
+
+/* -------------------------------------------------------------------------- */
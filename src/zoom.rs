@@ -0,0 +1,116 @@
+//! Zoom of 2D cameras anchored on the cursor.
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+
+use crate::input::OptionalEventReader;
+use crate::CursorLocations;
+
+/* -------------------------------------------------------------------------- */
+
+/// A component that zooms a 2D camera with the mouse wheel while keeping the world
+/// point under the cursor in place.
+///
+/// The camera must have an [`OrthographicProjection`]. It only zooms while it is
+/// under the cursor, according to the [`CursorLocations`] of the default
+/// [`TrackCursorPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_cursor::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Camera2d, ZoomToCursor::default()));
+/// }
+///
+/// # let _ = IntoSystem::into_system(setup);
+/// ```
+///
+/// [`OrthographicProjection`]: https://docs.rs/bevy/0.15.0/bevy/render/camera/struct.OrthographicProjection.html
+/// [`TrackCursorPlugin`]: crate::TrackCursorPlugin
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ZoomToCursor {
+    /// How much the scale of the projection changes for each line scrolled.
+    ///
+    /// A pixel-based scroll of 100 pixels counts as a line.
+    pub speed: f32,
+
+    /// The smallest scale of the projection, the closest zoom.
+    pub min_scale: f32,
+
+    /// The largest scale of the projection, the farthest zoom.
+    pub max_scale: f32,
+}
+
+impl Default for ZoomToCursor {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            speed: 0.1,
+            min_scale: 0.1,
+            max_scale: 10.0,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Zooms the [`ZoomToCursor`] cameras under the cursor.
+pub(crate) fn zoom_to_cursor(
+    mut wheel: OptionalEventReader<MouseWheel>,
+    locations: Option<Res<CursorLocations>>,
+    mut camera_q: Query<(
+        Entity,
+        &ZoomToCursor,
+        &GlobalTransform,
+        &mut Transform,
+        &mut OrthographicProjection,
+    )>,
+) {
+    let lines = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / 100.0,
+        })
+        .sum::<f32>();
+
+    if lines == 0.0 {
+        return;
+    }
+
+    let Some(locations) = locations else {
+        return;
+    };
+
+    for (camera_ref, zoom, cam_t, mut transform, mut projection) in &mut camera_q {
+        let Some(location) = locations.get(camera_ref) else {
+            continue;
+        };
+
+        // Scrolling up zooms in.
+        let scale = (projection.scale * (1.0 - zoom.speed).powf(lines))
+            .clamp(zoom.min_scale, zoom.max_scale);
+        let ratio = scale / projection.scale;
+
+        if ratio == 1.0 {
+            continue;
+        }
+
+        projection.scale = scale;
+
+        // The world offsets from the camera scale with the projection: move the camera
+        // so the point under the cursor stays in place.
+        let anchor = location.world_position.extend(cam_t.translation().z);
+        transform.translation += (anchor - cam_t.translation()) * (1.0 - ratio);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
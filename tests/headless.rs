@@ -0,0 +1,432 @@
+//! Tests of the plugin in headless apps, without `winit` nor the `InputPlugin`.
+
+use std::time::Duration;
+
+#[cfg(feature = "2d")]
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy_cursor::prelude::*;
+#[cfg(feature = "2d")]
+use bevy_cursor::{CursorBrush, CursorBrushQuery};
+
+/// Creates an app with a window, but without `winit` nor the `InputPlugin`.
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, WindowPlugin::default()));
+    app
+}
+
+#[test]
+fn runs_without_input_plugin() {
+    let mut app = headless_app();
+    app.add_plugins(TrackCursorPlugin::default());
+
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<CursorLocation>().get(), None);
+}
+
+/// A camera filter that isn't the default one.
+#[derive(TypePath)]
+struct MainCameras;
+
+impl CameraFilter for MainCameras {
+    type Filter = With<Camera>;
+}
+
+#[test]
+fn runs_with_a_camera_filter_only() {
+    let mut app = headless_app();
+    app.add_plugins(TrackCursorPlugin::<MainCameras>::new())
+        .insert_resource(PointerCapture)
+        .insert_resource(TouchAsCursor::default())
+        .insert_resource(WindowHitTest::default());
+
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    app.insert_resource(CursorMeasurement::default());
+
+    app.update();
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<CursorLocation<MainCameras>>().get(),
+        None
+    );
+}
+
+#[test]
+fn idle_hides_the_cursor() {
+    let mut app = headless_app();
+    app.add_plugins(
+        TrackCursorPlugin::default().with_idle(CursorIdleSettings::new(Duration::ZERO)),
+    );
+
+    app.update();
+
+    let idle = app.world().resource::<Events<CursorIdle>>();
+    assert_eq!(idle.len(), 1);
+
+    let mut window_q = app.world_mut().query::<&Window>();
+    let window = window_q.single(app.world());
+    assert!(!window.cursor_options.visible);
+}
+
+#[test]
+fn click_through_follows_the_desktop_cursor() {
+    let mut app = headless_app();
+    app.add_plugins(TrackCursorPlugin::default())
+        .insert_resource(
+            WindowHitTest::default().with_drag_region(Rect::new(0.0, 0.0, 400.0, 32.0)),
+        );
+
+    let window = app
+        .world_mut()
+        .spawn((
+            Window {
+                decorations: false,
+                position: WindowPosition::At(IVec2::new(100, 100)),
+                resolution: (400.0, 300.0).into(),
+                ..default()
+            },
+            ClickThrough,
+        ))
+        .id();
+
+    let hit_test = |app: &App| {
+        app.world()
+            .get::<Window>(window)
+            .unwrap()
+            .cursor_options
+            .hit_test
+    };
+
+    // The clicks don't pass through while the cursor can't be followed.
+    app.update();
+    assert!(hit_test(&app));
+
+    // Nothing is hovered in the middle of the window.
+    app.world_mut()
+        .resource_mut::<DesktopCursorPosition>()
+        .set(IVec2::new(300, 300));
+    app.update();
+    assert!(!hit_test(&app));
+
+    // The drag region is hovered while the clicks pass through.
+    app.world_mut()
+        .resource_mut::<DesktopCursorPosition>()
+        .set(IVec2::new(300, 110));
+    app.update();
+    assert!(hit_test(&app));
+}
+
+#[test]
+fn simulated_cursor_is_resolved() {
+    let mut app = headless_app();
+    app.add_plugins((TrackCursorPlugin::default(), CursorTestPlugin));
+
+    let camera = app
+        .world_mut()
+        .spawn((Camera::default(), OrthographicProjection::default_2d()))
+        .id();
+    app.update();
+
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<Window>>()
+        .single(app.world());
+    app.world_mut()
+        .resource_mut::<CursorSimulator>()
+        .set_position(window, Vec2::new(100.0, 50.0));
+    app.update();
+
+    let cursor = app.world().resource::<CursorLocation>();
+    assert_eq!(cursor.position(), Some(Vec2::new(100.0, 50.0)));
+    assert_eq!(cursor.window(), Some(window));
+    assert_eq!(cursor.camera(), Some(camera));
+
+    // The default window is 1280x720, and the camera is at the origin.
+    #[cfg(feature = "2d")]
+    assert_eq!(cursor.world_position(), Some(Vec2::new(-540.0, 310.0)));
+
+    app.world_mut()
+        .resource_mut::<CursorSimulator>()
+        .leave(window);
+    app.update();
+
+    assert_eq!(app.world().resource::<CursorLocation>().get(), None);
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn eye_ray_is_resolved() {
+    let mut app = headless_app();
+    app.add_plugins((TrackCursorPlugin::default(), CursorTestPlugin));
+
+    let camera = app
+        .world_mut()
+        .spawn((
+            Camera::default(),
+            PerspectiveProjection::default(),
+            Transform::from_xyz(1.0, 2.0, 3.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ))
+        .id();
+    app.update();
+
+    // The ray of an eye starts at the camera.
+    let transform = *app.world().get::<GlobalTransform>(camera).unwrap();
+    let ray = Ray3d::new(transform.translation(), transform.forward());
+    app.world_mut()
+        .resource_mut::<ExternalPointers>()
+        .set(0, ExternalPointer::Ray(ray));
+    app.update();
+
+    let locations = app.world().resource::<ExternalPointerLocations>();
+    let location = locations.get(0).expect("the ray should be resolved");
+    assert_eq!(location.camera, camera);
+    assert!(location.position.distance(Vec2::new(640.0, 360.0)) < 0.01);
+}
+
+#[test]
+fn edge_scroll_is_normalized_in_the_corners() {
+    let mut app = headless_app();
+    app.add_plugins((TrackCursorPlugin::default(), EdgeScrollPlugin::default()));
+
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<Window>>()
+        .single(app.world());
+    app.world_mut().get_mut::<Window>(window).unwrap().focused = true;
+    app.world_mut()
+        .resource_mut::<CursorSimulator>()
+        .set_position(window, Vec2::ZERO);
+    app.update();
+
+    let direction = **app.world().resource::<EdgeScroll>();
+    assert!((direction - Vec2::new(-1.0, 1.0).normalize()).length() < 1e-5);
+}
+
+#[cfg(feature = "2d")]
+#[test]
+fn brush_tests_the_hover_shapes() {
+    let mut app = headless_app();
+    app.add_plugins(TrackCursorPlugin::default());
+
+    // A square turned by 45°, whose bounds are larger than its shape.
+    let transform = Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4));
+    app.world_mut().spawn((
+        HoverShape::Rect {
+            half_size: Vec2::splat(10.0),
+        },
+        transform,
+        GlobalTransform::from(transform),
+    ));
+    app.update();
+
+    let center = Vec2::splat(12.0);
+    let brushes = [
+        (CursorBrush::Circle { radius: 3.0 }, false),
+        (CursorBrush::Circle { radius: 8.0 }, true),
+        (
+            CursorBrush::Rect {
+                half_size: Vec2::splat(3.0),
+            },
+            false,
+        ),
+        (
+            CursorBrush::Rect {
+                half_size: Vec2::splat(5.0),
+            },
+            true,
+        ),
+    ];
+
+    for (brush, expected) in brushes {
+        let found = app
+            .world_mut()
+            .run_system_once(move |query: CursorBrushQuery| {
+                !query.entities_at(center, brush).is_empty()
+            })
+            .unwrap();
+        assert_eq!(found, expected, "{brush:?}");
+    }
+}
+
+#[test]
+fn hysteresis_keeps_the_camera_past_the_seam() {
+    let mut app = headless_app();
+    app.add_plugins((
+        TrackCursorPlugin::default().with_viewport_bounds(ViewportBounds {
+            edges: ViewportEdges::HalfOpen,
+            hysteresis: 4.0,
+        }),
+        CursorTestPlugin,
+    ));
+
+    // A split screen, whose right camera is on top of the left camera at the seam.
+    let viewport = |x: u32| Camera {
+        viewport: Some(Viewport {
+            physical_position: UVec2::new(x, 0),
+            physical_size: UVec2::new(640, 720),
+            ..default()
+        }),
+        ..default()
+    };
+    let left = app
+        .world_mut()
+        .spawn((viewport(0), OrthographicProjection::default_2d()))
+        .id();
+    let right = app
+        .world_mut()
+        .spawn((viewport(640), OrthographicProjection::default_2d()))
+        .id();
+    app.update();
+
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<Window>>()
+        .single(app.world());
+    let mut move_to = |x: f32| {
+        app.world_mut()
+            .resource_mut::<CursorSimulator>()
+            .set_position(window, Vec2::new(x, 360.0));
+        app.update();
+        app.world().resource::<CursorLocation>().camera()
+    };
+
+    assert_eq!(move_to(600.0), Some(left));
+    assert_eq!(move_to(641.0), Some(left));
+    assert_eq!(move_to(650.0), Some(right));
+    assert_eq!(move_to(639.0), Some(right));
+    assert_eq!(move_to(630.0), Some(left));
+}
+
+#[cfg(feature = "mesh2d")]
+#[test]
+fn mesh_shape_is_hovered() {
+    use bevy::render::mesh::{Indices, PrimitiveTopology};
+    use bevy::render::render_asset::RenderAssetUsages;
+
+    // A grid of 4x4 squares of 16 units, without the squares in `holes`.
+    fn grid(holes: &[UVec2]) -> Mesh {
+        let mut positions = Vec::new();
+        let mut indices = Vec::new();
+        for x in 0..4 {
+            for y in 0..4 {
+                if holes.contains(&UVec2::new(x, y)) {
+                    continue;
+                }
+                let start = positions.len() as u32;
+                let (x, y) = (x as f32 * 16.0, y as f32 * 16.0);
+                positions.extend([
+                    [x, y, 0.0],
+                    [x + 16.0, y, 0.0],
+                    [x + 16.0, y + 16.0, 0.0],
+                    [x, y + 16.0, 0.0],
+                ]);
+                indices.extend([start, start + 1, start + 2, start, start + 2, start + 3]);
+            }
+        }
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_indices(Indices::U32(indices))
+    }
+
+    let mut app = headless_app();
+    app.add_plugins((
+        TrackCursorPlugin::default(),
+        CursorTestPlugin,
+        bevy::render::view::VisibilityPlugin,
+    ))
+    .init_asset::<Mesh>();
+
+    let mesh = app
+        .world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .add(grid(&[UVec2::new(1, 1)]));
+    app.world_mut()
+        .spawn((Camera::default(), OrthographicProjection::default_2d()));
+    let entity = app
+        .world_mut()
+        .spawn((HoverShape::Mesh, Mesh2d(mesh.clone()), Transform::default()))
+        .id();
+    app.update();
+
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<Window>>()
+        .single(app.world());
+    let hover = |app: &mut App, world_position: Vec2| {
+        // The default window is 1280x720, and the camera is at the origin.
+        let position = Vec2::new(640.0 + world_position.x, 360.0 - world_position.y);
+        app.world_mut()
+            .resource_mut::<CursorSimulator>()
+            .set_position(window, position);
+        app.update();
+        app.world().resource::<HoveredEntities>().contains(entity)
+    };
+
+    assert!(hover(&mut app, Vec2::new(8.0, 8.0)));
+    assert!(hover(&mut app, Vec2::new(56.0, 40.0)));
+    assert!(!hover(&mut app, Vec2::new(24.0, 24.0)));
+    assert!(!hover(&mut app, Vec2::new(70.0, 8.0)));
+
+    // The hierarchy of the mesh is rebuilt once the modification is notified.
+    *app.world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .get_mut(&mesh)
+        .unwrap() = grid(&[UVec2::new(0, 0)]);
+    app.update();
+    assert!(hover(&mut app, Vec2::new(24.0, 24.0)));
+    assert!(!hover(&mut app, Vec2::new(8.0, 8.0)));
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn predicted_ray_follows_the_cursor() {
+    let mut app = headless_app();
+    app.add_plugins((TrackCursorPlugin::default(), CursorTestPlugin))
+        .insert_resource(CursorPrediction::new(Duration::from_millis(100)));
+
+    let camera = app
+        .world_mut()
+        .spawn((Camera::default(), PerspectiveProjection::default()))
+        .id();
+    app.update();
+
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<Window>>()
+        .single(app.world());
+    for x in [600.0, 620.0, 640.0] {
+        app.world_mut()
+            .resource_mut::<CursorSimulator>()
+            .set_position(window, Vec2::new(x, 360.0));
+        std::thread::sleep(Duration::from_millis(10));
+        app.update();
+    }
+
+    let cursor = app.world().resource::<CursorLocation>().get().unwrap();
+    let predicted = app
+        .world()
+        .resource::<PredictedCursorLocation>()
+        .get()
+        .unwrap();
+    assert!(predicted.position.x > cursor.position.x);
+
+    // The predicted ray goes through the predicted position, not the current one.
+    let transform = *app.world().get::<GlobalTransform>(camera).unwrap();
+    let expected = app
+        .world()
+        .get::<Camera>(camera)
+        .unwrap()
+        .viewport_to_world(&transform, predicted.position)
+        .unwrap();
+    assert!(predicted.ray.direction.angle_between(*expected.direction) < 1e-4);
+    assert!(predicted.ray.direction.angle_between(*cursor.ray.direction) > 1e-4);
+}